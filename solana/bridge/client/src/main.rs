@@ -8,10 +8,13 @@ use std::{
 };
 
 use borsh::BorshDeserialize;
-use bridge::accounts::{
-    Bridge,
-    BridgeData,
-    FeeCollector,
+use bridge::{
+    accounts::{
+        Bridge,
+        BridgeData,
+        FeeCollector,
+    },
+    events::BridgeEvent,
 };
 use clap::{
     crate_description,
@@ -27,6 +30,7 @@ use solana_clap_utils::{
     input_parsers::{
         keypair_of,
         pubkey_of,
+        pubkeys_of,
         value_of,
         values_of,
     },
@@ -50,11 +54,13 @@ use solana_sdk::{
     signature::{
         read_keypair_file,
         Keypair,
+        Signature,
         Signer,
     },
     system_instruction::transfer,
     transaction::Transaction,
 };
+use solana_transaction_status::UiTransactionEncoding;
 use solitaire::{
     processors::seeded::Seeded,
     AccountState,
@@ -175,6 +181,47 @@ fn command_post_message(
     Ok(Some(transaction))
 }
 
+fn command_events(config: &Config, bridge: &Pubkey, signature: &Signature) -> CommmandResult {
+    let transaction = config
+        .rpc_client
+        .get_transaction(signature, UiTransactionEncoding::Json)?;
+    let logs = transaction
+        .transaction
+        .meta
+        .and_then(|meta| meta.log_messages)
+        .unwrap_or_default();
+
+    for event in bridge::events::decode_logs::<BridgeEvent>(bridge, &logs) {
+        println!("{:?}", event);
+    }
+
+    Ok(None)
+}
+
+fn command_vaa_status(
+    config: &Config,
+    bridge: &Pubkey,
+    vaa: &[u8],
+    signature_set: Option<Pubkey>,
+    modules: Vec<Pubkey>,
+) -> CommmandResult {
+    let source = |key: &Pubkey| -> Result<Option<Vec<u8>>, Error> {
+        Ok(config
+            .rpc_client
+            .get_account_with_commitment(key, config.commitment_config)?
+            .value
+            .map(|account| account.data))
+    };
+
+    let mut modules = modules;
+    modules.push(*bridge);
+    let status =
+        bridge::status::vaa_status(&source, bridge, vaa, signature_set.as_ref(), &modules)?;
+    println!("{:?}", status);
+
+    Ok(None)
+}
+
 fn main() {
     let matches = App::new(crate_name!())
         .about(crate_description!())
@@ -330,6 +377,68 @@ fn main() {
                         .help("CPI Proxy to use"),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("events")
+                .about("Decode the bridge events emitted by a transaction")
+                .arg(
+                    Arg::with_name("bridge")
+                        .long("bridge")
+                        .value_name("BRIDGE_KEY")
+                        .validator(is_pubkey_or_keypair)
+                        .takes_value(true)
+                        .index(1)
+                        .required(true)
+                        .help("Specify the bridge program address"),
+                )
+                .arg(
+                    Arg::with_name("signature")
+                        .value_name("SIGNATURE")
+                        .takes_value(true)
+                        .index(2)
+                        .required(true)
+                        .help("Signature of the transaction to decode"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("vaa-status")
+                .about("Check whether a VAA has been verified, posted or claimed")
+                .arg(
+                    Arg::with_name("bridge")
+                        .long("bridge")
+                        .value_name("BRIDGE_KEY")
+                        .validator(is_pubkey_or_keypair)
+                        .takes_value(true)
+                        .index(1)
+                        .required(true)
+                        .help("Specify the bridge program address"),
+                )
+                .arg(
+                    Arg::with_name("vaa")
+                        .validator(is_hex)
+                        .value_name("VAA")
+                        .takes_value(true)
+                        .index(2)
+                        .required(true)
+                        .help("Hex encoded VAA"),
+                )
+                .arg(
+                    Arg::with_name("signature_set")
+                        .long("signature-set")
+                        .validator(is_pubkey_or_keypair)
+                        .value_name("SIGNATURE_SET")
+                        .takes_value(true)
+                        .help("Signature set the VAA is being verified into"),
+                )
+                .arg(
+                    Arg::with_name("module")
+                        .long("module")
+                        .validator(is_pubkey_or_keypair)
+                        .value_name("MODULE")
+                        .takes_value(true)
+                        .multiple(true)
+                        .help("Module program whose claim should be checked, the bridge is always checked"),
+                ),
+        )
         .get_matches();
 
     let config = {
@@ -411,6 +520,24 @@ fn main() {
                 proxy,
             )
         }
+        ("events", Some(arg_matches)) => {
+            let bridge = pubkey_of(arg_matches, "bridge").unwrap();
+            let signature: Signature = value_of(arg_matches, "signature").unwrap_or_else(|| {
+                eprintln!("Invalid transaction signature");
+                exit(1);
+            });
+
+            command_events(&config, &bridge, &signature)
+        }
+        ("vaa-status", Some(arg_matches)) => {
+            let bridge = pubkey_of(arg_matches, "bridge").unwrap();
+            let vaa_str: String = value_of(arg_matches, "vaa").unwrap();
+            let vaa = hex::decode(vaa_str).unwrap();
+            let signature_set = pubkey_of(arg_matches, "signature_set");
+            let modules = pubkeys_of(arg_matches, "module").unwrap_or_default();
+
+            command_vaa_status(&config, &bridge, &vaa, signature_set, modules)
+        }
 
         _ => unreachable!(),
     }
@@ -426,6 +553,7 @@ fn main() {
                         preflight_commitment: None,
                         encoding: None,
                         max_retries: None,
+                        min_context_slot: None,
                     },
                 )?;
             println!("Signature: {}", signature);
@@ -1,5 +1,6 @@
 pub mod bridge;
 pub mod claim;
+pub mod deny_list;
 pub mod fee_collector;
 pub mod guardian_set;
 pub mod posted_message;
@@ -10,6 +11,7 @@ pub mod signature_set;
 pub use self::{
     bridge::*,
     claim::*,
+    deny_list::*,
     fee_collector::*,
     guardian_set::*,
     posted_message::*,
@@ -0,0 +1,60 @@
+//! The DenyList contains accounts the bridge refuses to process, such as compromised signature
+//! sets, expired guardian sets or posted VAAs. It is maintained through governance VAAs so entries
+//! can be added or removed without a program upgrade.
+
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use solana_program::pubkey::Pubkey;
+use solitaire::{
+    processors::seeded::Seeded,
+    AccountOwner,
+    AccountState,
+    Data,
+    Owned,
+};
+
+pub type DenyList<'b, const State: AccountState> = Data<'b, DenyListData, { State }>;
+
+#[derive(Clone, Default, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct DenyListData {
+    /// Accounts that are not allowed to be used with the bridge.
+    pub accounts: Vec<Pubkey>,
+}
+
+impl DenyListData {
+    /// Whether the given account has been denied by governance.
+    pub fn contains(&self, key: &Pubkey) -> bool {
+        self.accounts.contains(key)
+    }
+}
+
+/// There is a single DenyList per bridge. It is not a `Derive` as modules verify it against the
+/// bridge program rather than themselves, so it takes this empty derivation data instead.
+pub struct DenyListDerivationData;
+
+impl<'b, const State: AccountState> Seeded<&DenyListDerivationData> for DenyList<'b, { State }> {
+    fn seeds(_accs: &DenyListDerivationData) -> Vec<Vec<u8>> {
+        vec!["DenyList".as_bytes().to_vec()]
+    }
+}
+
+#[cfg(not(feature = "cpi"))]
+impl Owned for DenyListData {
+    fn owner(&self) -> AccountOwner {
+        AccountOwner::This
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Owned for DenyListData {
+    fn owner(&self) -> AccountOwner {
+        use std::str::FromStr;
+        AccountOwner::Other(Pubkey::from_str(env!("BRIDGE_ADDRESS")).unwrap())
+    }
+}
@@ -8,6 +8,7 @@ use serde::{
 };
 use solana_program::pubkey::Pubkey;
 use solitaire::{
+    processors::seeded::Seeded,
     AccountOwner,
     AccountState,
     Data,
@@ -42,6 +43,41 @@ pub struct PostedMessageUnreliableData {
     pub message: MessageData,
 }
 
+/// Bridge managed unreliable message accounts are PDAs that form a ring of `ring_size` slots per
+/// emitter. The slot a message is written to is selected by the emitter sequence, so slots are
+/// reused in order once the ring wraps around.
+pub struct UnreliableMessageSlotDerivationData<'a> {
+    pub emitter_key: &'a Pubkey,
+    pub slot: u16,
+}
+
+impl<'a> UnreliableMessageSlotDerivationData<'a> {
+    /// Map an emitter sequence onto its slot in a ring of `ring_size` accounts.
+    pub fn for_sequence(emitter_key: &'a Pubkey, sequence: u64, ring_size: u16) -> Self {
+        UnreliableMessageSlotDerivationData {
+            emitter_key,
+            slot: unreliable_message_slot(sequence, ring_size),
+        }
+    }
+}
+
+/// Slot index used by the message with the given `sequence` in a ring of `ring_size` accounts.
+pub fn unreliable_message_slot(sequence: u64, ring_size: u16) -> u16 {
+    (sequence % ring_size as u64) as u16
+}
+
+impl<'a, 'b, const State: AccountState> Seeded<&UnreliableMessageSlotDerivationData<'b>>
+    for PostedMessageUnreliable<'a, { State }>
+{
+    fn seeds(data: &UnreliableMessageSlotDerivationData) -> Vec<Vec<u8>> {
+        vec![
+            "UnreliableMessage".as_bytes().to_vec(),
+            data.emitter_key.to_bytes().to_vec(),
+            data.slot.to_be_bytes().to_vec(),
+        ]
+    }
+}
+
 #[derive(Debug, Default, BorshSerialize, BorshDeserialize, Clone, Serialize, Deserialize)]
 pub struct MessageData {
     /// Header of the posted VAA
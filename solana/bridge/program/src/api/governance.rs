@@ -16,6 +16,7 @@ use solitaire::{
 use crate::{
     accounts::{
         Bridge,
+        DenyListDerivationData,
         GuardianSet,
         GuardianSetDerivationData,
     },
@@ -25,10 +26,15 @@ use crate::{
         InvalidGovernanceWithdrawal,
         InvalidGuardianSetUpgrade,
     },
+    events::{
+        emit,
+        BridgeEvent,
+    },
     types::{
         GovernancePayloadGuardianSetChange,
         GovernancePayloadSetMessageFee,
         GovernancePayloadTransferFees,
+        GovernancePayloadUpdateDenyList,
         GovernancePayloadUpgrade,
     },
     vaa::ClaimableVAA,
@@ -191,6 +197,11 @@ pub fn upgrade_guardian_set(
     // Set guardian set index
     accs.bridge.guardian_set_index = accs.vaa.new_guardian_set_index;
 
+    emit(&BridgeEvent::GuardianSetUpgraded {
+        old_index: accs.guardian_set_old.index,
+        new_index: accs.guardian_set_new.index,
+    })?;
+
     Ok(())
 }
 
@@ -215,6 +226,10 @@ pub fn set_fees(ctx: &ExecutionContext, accs: &mut SetFees, _data: SetFeesData)
     accs.vaa.claim(ctx, accs.payer.key)?;
     accs.bridge.config.fee = accs.vaa.fee.as_u64();
 
+    emit(&BridgeEvent::FeesSet {
+        fee: accs.bridge.config.fee,
+    })?;
+
     Ok(())
 }
 
@@ -280,5 +295,52 @@ pub fn transfer_fees(
     let seeds = seeds.as_slice();
     invoke_signed(&transfer_ix, ctx.accounts, &[seeds])?;
 
+    emit(&BridgeEvent::FeesTransferred {
+        recipient: *accs.recipient.key,
+        amount: accs.vaa.amount.as_u64(),
+    })?;
+
+    Ok(())
+}
+
+#[derive(FromAccounts)]
+pub struct UpdateDenyList<'b> {
+    /// Payer for account creation (vaa-claim) and deny list growth
+    pub payer: Mut<Signer<Info<'b>>>,
+
+    /// Governance VAA, the deny list it carries is the one being updated
+    pub vaa: ClaimableVAA<'b, GovernancePayloadUpdateDenyList>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct UpdateDenyListData {}
+
+pub fn update_deny_list(
+    ctx: &ExecutionContext,
+    accs: &mut UpdateDenyList,
+    _data: UpdateDenyListData,
+) -> Result<()> {
+    verify_governance(&accs.vaa)?;
+    accs.vaa.verify(ctx.program_id)?;
+    accs.vaa.claim(ctx, accs.payer.key)?;
+
+    let account = accs.vaa.account;
+    let denied = accs.vaa.denied;
+    let deny_list = &mut accs.vaa.deny_list;
+    if denied {
+        if !deny_list.contains(&account) {
+            deny_list.accounts.push(account);
+        }
+    } else {
+        deny_list.accounts.retain(|v| *v != account);
+    }
+
+    // Size the account according to the new list length.
+    if deny_list.is_initialized() {
+        deny_list.resize_to_fit(ctx, &accs.payer, &accs.payer)?;
+    } else {
+        deny_list.create(&DenyListDerivationData, ctx, accs.payer.key, Exempt)?;
+    }
+
     Ok(())
 }
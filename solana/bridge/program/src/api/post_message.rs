@@ -6,17 +6,24 @@ use crate::{
         PostedMessageUnreliable,
         Sequence,
         SequenceDerivationData,
+        UnreliableMessageSlotDerivationData,
     },
     error::Error::{
         EmitterChanged,
         InsufficientFees,
         InvalidPayloadLength,
+        InvalidRingSize,
         MathOverflow,
     },
+    events::{
+        emit,
+        BridgeEvent,
+    },
     types::ConsistencyLevel,
     IsSigned::*,
     MessageData,
     CHAIN_ID_SOLANA,
+    MAX_UNRELIABLE_RING_SIZE,
 };
 use solana_program::{
     msg,
@@ -33,6 +40,7 @@ use solitaire::{
 pub type UninitializedMessage<'b> = PostedMessage<'b, { AccountState::Uninitialized }>;
 
 #[derive(FromAccounts)]
+#[cpi]
 pub struct PostMessage<'b> {
     /// Bridge config needed for fee calculation.
     pub bridge: Mut<Bridge<'b, { AccountState::Initialized }>>,
@@ -78,6 +86,29 @@ pub struct PostMessageUnreliable<'b> {
     pub clock: Sysvar<'b, Clock>,
 }
 
+#[derive(FromAccounts)]
+pub struct PostMessageUnreliableRing<'b> {
+    /// Bridge config needed for fee calculation.
+    pub bridge: Mut<Bridge<'b, { AccountState::Initialized }>>,
+
+    /// Bridge owned ring slot to store the posted message, derived from the emitter sequence
+    pub message: Mut<PostedMessageUnreliable<'b, { AccountState::MaybeInitialized }>>,
+
+    /// Emitter of the VAA
+    pub emitter: Signer<MaybeMut<Info<'b>>>,
+
+    /// Tracker for the emitter sequence
+    pub sequence: Mut<Sequence<'b>>,
+
+    /// Payer for account creation and resizing
+    pub payer: Mut<Signer<Info<'b>>>,
+
+    /// Account to collect tx fee
+    pub fee_collector: Mut<FeeCollector<'b>>,
+
+    pub clock: Sysvar<'b, Clock>,
+}
+
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct PostMessageData {
     /// Unique nonce for this message
@@ -174,7 +205,82 @@ pub fn post_message_unreliable(
     Ok(())
 }
 
-#[allow(unused_variables)] // message_key is used in `trace!`
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct PostMessageUnreliableRingData {
+    /// Unique nonce for this message
+    pub nonce: u32,
+
+    /// Message payload
+    pub payload: Vec<u8>,
+
+    /// Commitment Level required for an attestation to be produced
+    pub consistency_level: ConsistencyLevel,
+
+    /// Number of message slots the emitter cycles through
+    pub ring_size: u16,
+}
+
+/// Post an unreliable message into a bridge managed ring of message accounts. The slot is derived
+/// from the emitter and its current sequence, so the emitter does not need to manage any message
+/// accounts itself. Slots are created on first use and resized when the payload length changes,
+/// with missing rent paid by the payer and excess rent moved to the fee collector.
+/// The same delivery caveats as `post_message_unreliable` apply once a slot is overwritten.
+pub fn post_message_unreliable_ring(
+    ctx: &ExecutionContext,
+    accs: &mut PostMessageUnreliableRing,
+    data: PostMessageUnreliableRingData,
+) -> Result<()> {
+    if data.ring_size == 0 || data.ring_size > MAX_UNRELIABLE_RING_SIZE {
+        return Err(InvalidRingSize.into());
+    }
+
+    // The slot is selected by the sequence this message is going to be published with.
+    let emitter_key = *accs.emitter.key;
+    let next_sequence = if accs.sequence.is_initialized() {
+        accs.sequence.sequence
+    } else {
+        0
+    };
+    let derivation_data = UnreliableMessageSlotDerivationData::for_sequence(
+        &emitter_key,
+        next_sequence,
+        data.ring_size,
+    );
+    accs.message
+        .verify_derivation(ctx.program_id, &derivation_data)?;
+
+    post_message_internal(
+        ctx,
+        &mut accs.bridge,
+        accs.message.info().key,
+        &mut accs.message,
+        &mut accs.emitter,
+        &mut accs.sequence,
+        &mut accs.payer,
+        &mut accs.fee_collector,
+        &mut accs.clock,
+        PostMessageData {
+            nonce: data.nonce,
+            payload: data.payload,
+            consistency_level: data.consistency_level,
+        },
+    )?;
+
+    // Slot rent may have been paid by earlier payers, so the excess of a shrinking slot goes to
+    // the fee collector instead of the current payer. It is not a fee for the next message.
+    if accs.message.is_initialized() {
+        accs.message
+            .resize_to_fit(ctx, &accs.payer, &accs.fee_collector)?;
+        accs.bridge.last_lamports = accs.fee_collector.lamports();
+    } else {
+        accs.message
+            .create(&derivation_data, ctx, accs.payer.key, Exempt)?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 fn post_message_internal<'b>(
     ctx: &ExecutionContext,
     bridge: &mut Mut<Bridge<'b, { AccountState::Initialized }>>,
@@ -237,6 +343,14 @@ fn post_message_internal<'b>(
         ConsistencyLevel::Finalized => 32,
     };
 
+    emit(&BridgeEvent::MessagePosted {
+        message: *message_key,
+        emitter: *emitter.key,
+        sequence: message.sequence,
+        nonce: message.nonce,
+        consistency_level: message.consistency_level,
+    })?;
+
     // Bump sequence number
     trace!("New Sequence: {}", sequence.sequence + 1);
     sequence.sequence += 1;
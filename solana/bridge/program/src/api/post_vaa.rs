@@ -12,6 +12,9 @@ use solana_program::{
 use crate::{
     accounts::{
         Bridge,
+        DenyList,
+        DenyListData,
+        DenyListDerivationData,
         GuardianSet,
         GuardianSetDerivationData,
         PostedVAA,
@@ -19,11 +22,17 @@ use crate::{
         SignatureSet,
     },
     error::Error::{
+        AccountDenied,
         GuardianSetMismatch,
+        MissingAccount,
         PostVAAConsensusFailed,
         PostVAAGuardianSetExpired,
         VAAInvalid,
     },
+    events::{
+        emit,
+        BridgeEvent,
+    },
 };
 use byteorder::{
     BigEndian,
@@ -71,6 +80,10 @@ pub struct PostVAA<'b> {
 
     /// Clock used for timestamping.
     pub clock: Sysvar<'b, Clock>,
+
+    /// Accounts added after the original layout, found by key: the governed list of guardian sets,
+    /// signature sets and messages that are not allowed to post VAAs, see [`find_deny_list`].
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 #[derive(Default, BorshSerialize, BorshDeserialize)]
@@ -108,6 +121,12 @@ pub fn post_vaa(ctx: &ExecutionContext, accs: &mut PostVAA, vaa: PostVAAData) ->
         .verify_derivation(ctx.program_id, &msg_derivation)?;
     accs.guardian_set
         .verify_derivation(ctx.program_id, &(&vaa).into())?;
+    let deny_list = find_deny_list(ctx, &accs.trailing)?;
+
+    // Denied messages must not be posted, a message posted before it was denied stays readable.
+    if deny_list.contains(accs.message.info().key) {
+        return Err(AccountDenied.into());
+    }
 
     if accs.message.is_initialized() {
         return Ok(());
@@ -116,6 +135,7 @@ pub fn post_vaa(ctx: &ExecutionContext, accs: &mut PostVAA, vaa: PostVAAData) ->
     // Verify any required invariants before we process the instruction.
     check_active(&accs.guardian_set, &accs.clock)?;
     check_valid_sigs(&accs.guardian_set, &accs.signature_set)?;
+    check_not_denied(&deny_list, &accs.guardian_set, &accs.signature_set)?;
     check_integrity(&vaa, &accs.signature_set)?;
 
     // Count the number of signatures currently present.
@@ -150,6 +170,14 @@ pub fn post_vaa(ctx: &ExecutionContext, accs: &mut PostVAA, vaa: PostVAAData) ->
     accs.message
         .create(&msg_derivation, ctx, accs.payer.key, Exempt)?;
 
+    emit(&BridgeEvent::VAAPosted {
+        message: *accs.message.info().key,
+        emitter_chain: vaa.emitter_chain,
+        emitter_address: vaa.emitter_address,
+        sequence: vaa.sequence,
+        guardian_set_index: vaa.guardian_set_index,
+    })?;
+
     Ok(())
 }
 
@@ -172,7 +200,8 @@ fn check_active<'r>(
     Ok(())
 }
 
-// Static list of invalid signature accounts that are not allowed to post VAAs.
+// Static list of invalid signature accounts that are not allowed to post VAAs. Kept as a permanent
+// fallback to the governed deny list, which starts out empty.
 static INVALID_SIGNATURES: &[&str; 16] = &[
     "18eK1799CaNMGCUnnCt1Kq2uwKkax6T2WmtrDsZuVFQ",
     "2g6NCUUPaD6AxdHPQMVLpjpAvBfKMek6dDiGUe2A6T33",
@@ -210,6 +239,36 @@ fn check_valid_sigs<'r>(
     Ok(())
 }
 
+/// Governed deny list among the `trailing` accounts. It is looked up by key so that rent and the
+/// system program keep their position after the clock, but it is required: leaving it out would
+/// dodge the list. Checked in addition to the hardcoded lists below.
+fn find_deny_list(
+    ctx: &ExecutionContext,
+    trailing: &Remaining<MaybeMut<Info<'_>>>,
+) -> Result<DenyListData> {
+    let key = DenyList::<'_, { AccountState::MaybeInitialized }>::key(
+        &DenyListDerivationData,
+        ctx.program_id,
+    );
+    let deny_list: DenyList<'_, { AccountState::MaybeInitialized }> =
+        trailing.find(ctx.program_id, &key)?.ok_or(MissingAccount)?;
+    Ok(DenyListData::clone(&deny_list))
+}
+
+/// Neither the guardian set nor the signature set may have been denied by governance.
+#[inline(always)]
+fn check_not_denied<'r>(
+    deny_list: &DenyListData,
+    guardian_set: &GuardianSet<'r, { AccountState::Initialized }>,
+    signatures: &SignatureSet<'r, { AccountState::Initialized }>,
+) -> Result<()> {
+    if deny_list.contains(guardian_set.info().key) || deny_list.contains(signatures.info().key) {
+        return Err(AccountDenied.into());
+    }
+
+    Ok(())
+}
+
 #[inline(always)]
 fn check_integrity<'r>(
     vaa: &PostVAAData,
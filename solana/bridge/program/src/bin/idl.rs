@@ -0,0 +1,6 @@
+//! Print the IDL of the program as JSON, see `solitaire::idl`.
+
+fn main() {
+    let idl = bridge::instruction::idl().with_errors(bridge::error::Error::ERROR_CODES);
+    println!("{}", idl.to_json());
+}
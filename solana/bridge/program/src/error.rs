@@ -1,38 +1,56 @@
 //! Define application level errors that can be returned by the various instruction handlers that
 //! make up the wormhole bridge.
 
-use crate::trace;
-use solitaire::SolitaireError;
+use solitaire::SolitaireErrors;
 
-#[derive(Debug)]
+/// Errors are reported on chain as `ProgramError::Custom(code)` where the code is the discriminant
+/// below. Codes are part of the public interface: never reorder or reuse them.
+#[derive(Debug, SolitaireErrors)]
 pub enum Error {
-    GuardianSetMismatch,
-    InstructionAtWrongIndex,
-    InsufficientFees,
-    InvalidFeeRecipient,
-    InvalidGovernanceAction,
-    InvalidGovernanceChain,
-    InvalidGovernanceKey,
-    InvalidGovernanceModule,
-    InvalidGovernanceWithdrawal,
-    InvalidGuardianSetUpgrade,
-    InvalidHash,
-    InvalidSecpInstruction,
-    MathOverflow,
-    PostVAAConsensusFailed,
-    PostVAAGuardianSetExpired,
-    TooManyGuardians,
-    VAAAlreadyExecuted,
-    VAAInvalid,
-    InvalidPayloadLength,
-    EmitterChanged,
-}
-
-/// Errors thrown by the program will bubble up to the solitaire wrapper, which needs a way to
-/// translate these errors into something Solitaire can log and handle.
-impl From<Error> for SolitaireError {
-    fn from(e: Error) -> SolitaireError {
-        trace!("ProgramError: {:?}", e);
-        SolitaireError::Custom(e as u64)
-    }
+    /// Signatures were verified against a different guardian set
+    GuardianSetMismatch = 0,
+    /// Secp256k1 instruction is not directly before the signature verification
+    InstructionAtWrongIndex = 1,
+    /// Message fee was not paid to the fee collector
+    InsufficientFees = 2,
+    /// Fee recipient does not match the governance VAA
+    InvalidFeeRecipient = 3,
+    /// Governance VAA is for a different action
+    InvalidGovernanceAction = 4,
+    /// Governance VAA targets a different chain
+    InvalidGovernanceChain = 5,
+    /// Governance VAA was not emitted by the governance emitter
+    InvalidGovernanceKey = 6,
+    /// Governance VAA targets a different module
+    InvalidGovernanceModule = 7,
+    /// Fee withdrawal would leave the fee collector below rent exemption
+    InvalidGovernanceWithdrawal = 8,
+    /// Guardian set upgrade does not follow the current guardian set
+    InvalidGuardianSetUpgrade = 9,
+    /// Signed hash does not match the signature set
+    InvalidHash = 10,
+    /// Secp256k1 instruction is malformed
+    InvalidSecpInstruction = 11,
+    /// Arithmetic overflow
+    MathOverflow = 12,
+    /// VAA is not signed by a quorum of guardians
+    PostVAAConsensusFailed = 13,
+    /// Guardian set that signed the VAA has expired
+    PostVAAGuardianSetExpired = 14,
+    /// Guardian set exceeds the maximum number of guardians
+    TooManyGuardians = 15,
+    /// VAA has already been claimed
+    VAAAlreadyExecuted = 16,
+    /// VAA is invalid
+    VAAInvalid = 17,
+    /// Payload length does not match the reused message account
+    InvalidPayloadLength = 18,
+    /// Reused message account belongs to a different emitter
+    EmitterChanged = 19,
+    /// Unreliable message ring size is out of bounds
+    InvalidRingSize = 20,
+    /// Account is on the governance deny list
+    AccountDenied = 21,
+    /// Account the instruction requires was not passed
+    MissingAccount = 22,
 }
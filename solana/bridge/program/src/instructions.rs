@@ -16,6 +16,7 @@ use sha3::Digest;
 use solitaire::{
     processors::seeded::Seeded,
     AccountState,
+    ErrorCode,
 };
 use std::io::{
     Cursor,
@@ -27,20 +28,26 @@ use crate::{
         Bridge,
         Claim,
         ClaimDerivationData,
+        DenyList,
+        DenyListDerivationData,
         FeeCollector,
         GuardianSet,
         GuardianSetDerivationData,
+        PostedMessageUnreliable,
         PostedVAA,
         PostedVAADerivationData,
         Sequence,
         SequenceDerivationData,
+        UnreliableMessageSlotDerivationData,
     },
     types::ConsistencyLevel,
     InitializeData,
     PostMessageData,
+    PostMessageUnreliableRingData,
     PostVAAData,
     SetFeesData,
     TransferFeesData,
+    UpdateDenyListData,
     UpgradeContractData,
     UpgradeGuardianSetData,
     VerifySignaturesData,
@@ -170,6 +177,69 @@ pub fn post_message_unreliable(
     })
 }
 
+/// Address of the bridge managed unreliable message slot the message with `sequence` is written to
+/// in a ring of `ring_size` slots.
+pub fn unreliable_message_address(
+    program_id: Pubkey,
+    emitter: Pubkey,
+    sequence: u64,
+    ring_size: u16,
+) -> Pubkey {
+    PostedMessageUnreliable::<'_, { AccountState::MaybeInitialized }>::key(
+        &UnreliableMessageSlotDerivationData::for_sequence(&emitter, sequence, ring_size),
+        &program_id,
+    )
+}
+
+/// Post an unreliable message into the bridge managed ring of the emitter. `sequence` is the next
+/// sequence of the emitter as stored in its sequence account, it selects the slot to write to.
+#[allow(clippy::too_many_arguments)]
+pub fn post_message_unreliable_ring(
+    program_id: Pubkey,
+    payer: Pubkey,
+    emitter: Pubkey,
+    sequence: u64,
+    ring_size: u16,
+    nonce: u32,
+    payload: Vec<u8>,
+    commitment: ConsistencyLevel,
+) -> solitaire::Result<Instruction> {
+    let bridge = Bridge::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let fee_collector = FeeCollector::<'_>::key(None, &program_id);
+    let message = unreliable_message_address(program_id, emitter, sequence, ring_size);
+    let sequence = Sequence::<'_>::key(
+        &SequenceDerivationData {
+            emitter_key: &emitter,
+        },
+        &program_id,
+    );
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(bridge, false),
+            AccountMeta::new(message, false),
+            AccountMeta::new_readonly(emitter, true),
+            AccountMeta::new(sequence, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(fee_collector, false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: (
+            crate::instruction::Instruction::PostMessageUnreliableRing,
+            PostMessageUnreliableRingData {
+                nonce,
+                payload,
+                consistency_level: commitment,
+                ring_size,
+            },
+        )
+            .try_to_vec()?,
+    })
+}
+
 pub fn verify_signatures(
     program_id: Pubkey,
     payer: Pubkey,
@@ -214,12 +284,8 @@ pub fn post_vaa(
         &program_id,
     );
 
-    let msg_derivation_data = &PostedVAADerivationData {
-        payload_hash: hash_vaa(&vaa).to_vec(),
-    };
-
-    let message =
-        PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(msg_derivation_data, &program_id);
+    let message = posted_vaa_address(program_id, hash_vaa(&vaa));
+    let deny_list = deny_list_address(program_id);
 
     Instruction {
         program_id,
@@ -233,6 +299,7 @@ pub fn post_vaa(
             AccountMeta::new_readonly(sysvar::clock::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(deny_list, false),
         ],
 
         data: (crate::instruction::Instruction::PostVAA, vaa)
@@ -251,14 +318,8 @@ pub fn upgrade_contract(
     sequence: u64,
 ) -> Instruction {
     let bridge = Bridge::<'_, { AccountState::Initialized }>::key(None, &program_id);
-    let claim = Claim::<'_, { AccountState::Uninitialized }>::key(
-        &ClaimDerivationData {
-            emitter_address: emitter.to_bytes(),
-            emitter_chain: CHAIN_ID_SOLANA,
-            sequence,
-        },
-        &program_id,
-    );
+    let claim = claim_address(program_id, emitter.to_bytes(), CHAIN_ID_SOLANA, sequence);
+    let deny_list = deny_list_address(program_id);
 
     let (upgrade_authority, _) = Pubkey::find_program_address(&["upgrade".as_bytes()], &program_id);
 
@@ -275,6 +336,7 @@ pub fn upgrade_contract(
             AccountMeta::new(bridge, false),
             AccountMeta::new_readonly(payload_message, false),
             AccountMeta::new(claim, false),
+            AccountMeta::new_readonly(deny_list, false),
             AccountMeta::new_readonly(upgrade_authority, false),
             AccountMeta::new(spill, false),
             AccountMeta::new(new_contract, false),
@@ -305,14 +367,8 @@ pub fn upgrade_guardian_set(
     sequence: u64,
 ) -> Instruction {
     let bridge = Bridge::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let claim = Claim::<'_, { AccountState::Uninitialized }>::key(
-        &ClaimDerivationData {
-            emitter_address: emitter.to_bytes(),
-            emitter_chain: CHAIN_ID_SOLANA,
-            sequence,
-        },
-        &program_id,
-    );
+    let claim = claim_address(program_id, emitter.to_bytes(), CHAIN_ID_SOLANA, sequence);
+    let deny_list = deny_list_address(program_id);
 
     let guardian_set_old = GuardianSet::<'_, { AccountState::Initialized }>::key(
         &GuardianSetDerivationData { index: old_index },
@@ -332,6 +388,7 @@ pub fn upgrade_guardian_set(
             AccountMeta::new(bridge, false),
             AccountMeta::new_readonly(payload_message, false),
             AccountMeta::new(claim, false),
+            AccountMeta::new_readonly(deny_list, false),
             AccountMeta::new(guardian_set_old, false),
             AccountMeta::new(guardian_set_new, false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
@@ -354,14 +411,8 @@ pub fn set_fees(
     sequence: u64,
 ) -> Instruction {
     let bridge = Bridge::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let claim = Claim::<'_, { AccountState::Uninitialized }>::key(
-        &ClaimDerivationData {
-            emitter_address: emitter.to_bytes(),
-            emitter_chain: CHAIN_ID_SOLANA,
-            sequence,
-        },
-        &program_id,
-    );
+    let claim = claim_address(program_id, emitter.to_bytes(), CHAIN_ID_SOLANA, sequence);
+    let deny_list = deny_list_address(program_id);
 
     Instruction {
         program_id,
@@ -371,6 +422,7 @@ pub fn set_fees(
             AccountMeta::new(bridge, false),
             AccountMeta::new_readonly(message, false),
             AccountMeta::new(claim, false),
+            AccountMeta::new_readonly(deny_list, false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
         ],
 
@@ -389,14 +441,8 @@ pub fn transfer_fees(
     recipient: Pubkey,
 ) -> Instruction {
     let bridge = Bridge::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let claim = Claim::<'_, { AccountState::Uninitialized }>::key(
-        &ClaimDerivationData {
-            emitter_address: emitter.to_bytes(),
-            emitter_chain: CHAIN_ID_SOLANA,
-            sequence,
-        },
-        &program_id,
-    );
+    let claim = claim_address(program_id, emitter.to_bytes(), CHAIN_ID_SOLANA, sequence);
+    let deny_list = deny_list_address(program_id);
 
     let fee_collector = FeeCollector::key(None, &program_id);
 
@@ -408,6 +454,7 @@ pub fn transfer_fees(
             AccountMeta::new(bridge, false),
             AccountMeta::new_readonly(message, false),
             AccountMeta::new(claim, false),
+            AccountMeta::new_readonly(deny_list, false),
             AccountMeta::new(fee_collector, false),
             AccountMeta::new(recipient, false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
@@ -423,6 +470,69 @@ pub fn transfer_fees(
     }
 }
 
+pub fn update_deny_list(
+    program_id: Pubkey,
+    payer: Pubkey,
+    message: Pubkey,
+    emitter: Pubkey,
+    sequence: u64,
+) -> Instruction {
+    let claim = claim_address(program_id, emitter.to_bytes(), CHAIN_ID_SOLANA, sequence);
+    let deny_list = deny_list_address(program_id);
+
+    Instruction {
+        program_id,
+
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(message, false),
+            AccountMeta::new(claim, false),
+            AccountMeta::new(deny_list, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+
+        data: (
+            crate::instruction::Instruction::UpdateDenyList,
+            UpdateDenyListData {},
+        )
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Address of the governed deny list that `post_vaa` and VAA claims are checked against.
+pub fn deny_list_address(program_id: Pubkey) -> Pubkey {
+    DenyList::<'_, { AccountState::MaybeInitialized }>::key(&DenyListDerivationData, &program_id)
+}
+
+/// Address the VAA with the given body hash is posted to.
+pub fn posted_vaa_address(program_id: Pubkey, hash: [u8; 32]) -> Pubkey {
+    PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: hash.to_vec(),
+        },
+        &program_id,
+    )
+}
+
+/// Address of the claim `program_id` creates when it consumes the VAA identified by its emitter and
+/// sequence. Each module claims VAAs under its own program id.
+pub fn claim_address(
+    program_id: Pubkey,
+    emitter_address: [u8; 32],
+    emitter_chain: u16,
+    sequence: u64,
+) -> Pubkey {
+    Claim::<'_, { AccountState::MaybeInitialized }>::key(
+        &ClaimDerivationData {
+            emitter_address,
+            emitter_chain,
+            sequence,
+        },
+        &program_id,
+    )
+}
+
 // Convert a full VAA structure into the serialization of its unique components, this structure is
 // what is hashed and verified by Guardians.
 pub fn serialize_vaa(vaa: &PostVAAData) -> Vec<u8> {
@@ -444,3 +554,8 @@ pub fn hash_vaa(vaa: &PostVAAData) -> [u8; 32] {
     h.write_all(body.as_slice()).unwrap();
     h.finalize().into()
 }
+
+/// Decode the `ProgramError::Custom` code of a failed bridge instruction.
+pub fn decode_error(code: u32) -> Option<&'static ErrorCode> {
+    solitaire::decode_error(code, crate::error::Error::ERROR_CODES)
+}
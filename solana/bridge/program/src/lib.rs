@@ -7,9 +7,15 @@ use solitaire::*;
 pub const MAX_LEN_GUARDIAN_KEYS: usize = 19;
 pub const CHAIN_ID_SOLANA: u16 = 1;
 
+/// Upper bound on the number of bridge managed unreliable message slots per emitter.
+pub const MAX_UNRELIABLE_RING_SIZE: u16 = 256;
+
 #[cfg(feature = "instructions")]
 pub mod instructions;
 
+#[cfg(feature = "instructions")]
+pub mod status;
+
 #[cfg(feature = "wasm")]
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 extern crate wasm_bindgen;
@@ -26,6 +32,9 @@ pub use accounts::{
     Claim,
     ClaimData,
     ClaimDerivationData,
+    DenyList,
+    DenyListData,
+    DenyListDerivationData,
     FeeCollector,
     GuardianSet,
     GuardianSetData,
@@ -42,6 +51,7 @@ pub use accounts::{
     SequenceTracker,
     SignatureSet,
     SignatureSetData,
+    UnreliableMessageSlotDerivationData,
 };
 
 pub mod api;
@@ -50,9 +60,11 @@ pub use api::{
     initialize,
     post_message,
     post_message_unreliable,
+    post_message_unreliable_ring,
     post_vaa,
     set_fees,
     transfer_fees,
+    update_deny_list,
     upgrade_contract,
     upgrade_guardian_set,
     verify_signatures,
@@ -61,6 +73,8 @@ pub use api::{
     PostMessage,
     PostMessageData,
     PostMessageUnreliable,
+    PostMessageUnreliableRing,
+    PostMessageUnreliableRingData,
     PostVAA,
     PostVAAData,
     SetFees,
@@ -69,6 +83,8 @@ pub use api::{
     TransferFees,
     TransferFeesData,
     UninitializedMessage,
+    UpdateDenyList,
+    UpdateDenyListData,
     UpgradeContract,
     UpgradeContractData,
     UpgradeGuardianSet,
@@ -78,6 +94,7 @@ pub use api::{
 };
 
 pub mod error;
+pub mod events;
 pub mod types;
 pub mod vaa;
 
@@ -99,4 +116,6 @@ solitaire! {
     UpgradeGuardianSet => upgrade_guardian_set,
     VerifySignatures   => verify_signatures,
     PostMessageUnreliable        => post_message_unreliable,
+    PostMessageUnreliableRing    => post_message_unreliable_ring,
+    UpdateDenyList               => update_deny_list,
 }
@@ -0,0 +1,124 @@
+//! Client side lookup of how far a VAA has progressed through the bridge: whether its signatures
+//! are being verified, whether it has been posted and which modules have claimed it.
+
+use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
+use solitaire::ErrBox;
+
+use crate::{
+    accounts::{
+        ClaimData,
+        SignatureSetData,
+    },
+    instructions::{
+        claim_address,
+        hash_vaa,
+        posted_vaa_address,
+    },
+    vaa::VAA,
+    PostVAAData,
+};
+
+/// Read access to account data. Implemented for any `Fn(&Pubkey)` so an RPC client can be wrapped
+/// in a closure, and tests can substitute a map of accounts.
+pub trait AccountSource {
+    /// Returns the data of the account, or `None` if it does not exist.
+    fn account_data(&self, key: &Pubkey) -> Result<Option<Vec<u8>>, ErrBox>;
+}
+
+impl<F> AccountSource for F
+where
+    F: Fn(&Pubkey) -> Result<Option<Vec<u8>>, ErrBox>,
+{
+    fn account_data(&self, key: &Pubkey) -> Result<Option<Vec<u8>>, ErrBox> {
+        self(key)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VAAStatus {
+    /// Nothing is known about the VAA on chain.
+    NotPosted,
+
+    /// Signatures are being verified into `signature_set`, `guardians` holds the indices of the
+    /// guardians whose signatures have been verified so far.
+    PartiallyVerified {
+        signature_set: Pubkey,
+        guardian_set_index: u32,
+        guardians: Vec<u8>,
+    },
+
+    /// The VAA has been posted to `message` but no module has claimed it.
+    Posted { message: Pubkey },
+
+    /// The VAA has been posted to `message` and consumed by `module`.
+    Claimed { message: Pubkey, module: Pubkey },
+}
+
+/// Look up the status of a serialized VAA.
+///
+/// Signature sets are not derived from the VAA, so `signature_set` must be given to detect partial
+/// verification. Claims are derived per module, so `modules` lists the programs (including the
+/// bridge itself for governance) whose claims should be checked.
+pub fn vaa_status<S: AccountSource>(
+    source: &S,
+    program_id: &Pubkey,
+    vaa: &[u8],
+    signature_set: Option<&Pubkey>,
+    modules: &[Pubkey],
+) -> Result<VAAStatus, ErrBox> {
+    let vaa = VAA::deserialize(vaa)?;
+    vaa_body_status(source, program_id, &vaa.into(), signature_set, modules)
+}
+
+/// Same as `vaa_status`, for a VAA that has already been parsed.
+pub fn vaa_body_status<S: AccountSource>(
+    source: &S,
+    program_id: &Pubkey,
+    vaa: &PostVAAData,
+    signature_set: Option<&Pubkey>,
+    modules: &[Pubkey],
+) -> Result<VAAStatus, ErrBox> {
+    let hash = hash_vaa(vaa);
+    let message = posted_vaa_address(*program_id, hash);
+    if source.account_data(&message)?.is_some() {
+        for module in modules {
+            let claim = claim_address(
+                *module,
+                vaa.emitter_address,
+                vaa.emitter_chain,
+                vaa.sequence,
+            );
+            if let Some(data) = source.account_data(&claim)? {
+                if ClaimData::deserialize(&mut data.as_slice())?.claimed {
+                    return Ok(VAAStatus::Claimed {
+                        message,
+                        module: *module,
+                    });
+                }
+            }
+        }
+        return Ok(VAAStatus::Posted { message });
+    }
+
+    if let Some(signature_set) = signature_set {
+        if let Some(data) = source.account_data(signature_set)? {
+            let signatures = SignatureSetData::deserialize(&mut data.as_slice())?;
+            if signatures.hash == hash {
+                return Ok(VAAStatus::PartiallyVerified {
+                    signature_set: *signature_set,
+                    guardian_set_index: signatures.guardian_set_index,
+                    guardians: signatures
+                        .signatures
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, verified)| **verified)
+                        .map(|(index, _)| index as u8)
+                        .collect(),
+                });
+            }
+        }
+    }
+
+    Ok(VAAStatus::NotPosted)
+}
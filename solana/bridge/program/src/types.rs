@@ -233,3 +233,56 @@ impl SerializeGovernancePayload for GovernancePayloadTransferFees {
 
 impl DeserializeGovernancePayload for GovernancePayloadTransferFees {
 }
+
+pub struct GovernancePayloadUpdateDenyList {
+    // Account to add to or remove from the deny list
+    pub account: Pubkey,
+
+    // Whether the account is denied (added) or allowed again (removed)
+    pub denied: bool,
+}
+
+impl SerializePayload for GovernancePayloadUpdateDenyList {
+    fn serialize<W: Write>(&self, v: &mut W) -> std::result::Result<(), SolitaireError> {
+        use byteorder::WriteBytesExt;
+        v.write_all(&self.account.to_bytes())?;
+        v.write_u8(self.denied as u8)?;
+        Ok(())
+    }
+}
+
+impl DeserializePayload for GovernancePayloadUpdateDenyList
+where
+    Self: DeserializeGovernancePayload,
+{
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, SolitaireError> {
+        let mut c = Cursor::new(buf);
+        Self::check_governance_header(&mut c)?;
+
+        let mut account = [0u8; 32];
+        c.read_exact(&mut account)?;
+
+        let denied = match c.read_u8()? {
+            0 => false,
+            1 => true,
+            _ => return Err(InvalidAccountData.into()),
+        };
+
+        if c.position() != c.into_inner().len() as u64 {
+            return Err(InvalidAccountData.into());
+        }
+
+        Ok(GovernancePayloadUpdateDenyList {
+            account: Pubkey::new_from_array(account),
+            denied,
+        })
+    }
+}
+
+impl SerializeGovernancePayload for GovernancePayloadUpdateDenyList {
+    const MODULE: &'static str = "Core";
+    const ACTION: u8 = 5;
+}
+
+impl DeserializeGovernancePayload for GovernancePayloadUpdateDenyList {
+}
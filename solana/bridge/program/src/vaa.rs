@@ -4,6 +4,7 @@ use crate::{
         ForeignAddress,
     },
     error::Error::{
+        AccountDenied,
         InvalidGovernanceAction,
         InvalidGovernanceChain,
         InvalidGovernanceModule,
@@ -11,6 +12,8 @@ use crate::{
     },
     Claim,
     ClaimDerivationData,
+    DenyList,
+    DenyListDerivationData,
     PostedVAAData,
     Result,
     CHAIN_ID_SOLANA,
@@ -129,6 +132,10 @@ impl<'a, 'b: 'a, 'c, T: DeserializePayload> Peel<'a, 'b, 'c> for PayloadMessage<
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         Data::persist(&self.0, program_id)
     }
+
+    fn describe(name: &str) -> Vec<solitaire::idl::IdlAccount> {
+        Data::<'b, PostedVAAData, { AccountState::Initialized }>::describe(name)
+    }
 }
 
 impl<'b, T: DeserializePayload> Deref for PayloadMessage<'b, T> {
@@ -155,6 +162,9 @@ pub struct ClaimableVAA<'b, T: DeserializePayload> {
 
     // Claim account to prevent double spending
     pub claim: Mut<Claim<'b, { AccountState::Uninitialized }>>,
+
+    // Governed list of VAAs that must not be claimed
+    pub deny_list: MaybeMut<DenyList<'b, { AccountState::MaybeInitialized }>>,
 }
 
 impl<'b, T: DeserializePayload> Deref for ClaimableVAA<'b, T> {
@@ -178,6 +188,14 @@ impl<'b, T: DeserializePayload> ClaimableVAA<'b, T> {
             },
         )?;
 
+        // The deny list always lives on the bridge, also when claiming from another program.
+        let bridge_id = self.deny_list.owner_pubkey(program_id)?;
+        self.deny_list
+            .verify_derivation(&bridge_id, &DenyListDerivationData)?;
+        if self.deny_list.contains(self.message.info().key) {
+            return Err(AccountDenied.into());
+        }
+
         Ok(())
     }
 }
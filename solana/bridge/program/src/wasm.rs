@@ -26,17 +26,21 @@ use crate::{
         GuardianSet,
         GuardianSetData,
         GuardianSetDerivationData,
-        PostedVAA,
         PostedVAAData,
-        PostedVAADerivationData,
     },
     instructions::{
+        self,
+        decode_error,
         hash_vaa,
         post_message,
         post_message_unreliable,
+        post_message_unreliable_ring,
         post_vaa,
+        posted_vaa_address,
         set_fees,
         transfer_fees,
+        unreliable_message_address,
+        update_deny_list,
         upgrade_contract,
         upgrade_guardian_set,
         verify_signatures,
@@ -47,8 +51,6 @@ use crate::{
         GovernancePayloadTransferFees,
         GovernancePayloadUpgrade,
     },
-    Claim,
-    ClaimDerivationData,
     PostVAAData,
     VerifySignaturesData,
 };
@@ -111,6 +113,36 @@ pub fn post_message_unreliable_ix(
     return JsValue::from_serde(&ix).unwrap();
 }
 
+#[wasm_bindgen]
+pub fn post_message_unreliable_ring_ix(
+    program_id: String,
+    payer: String,
+    emitter: String,
+    sequence: u64,
+    ring_size: u16,
+    nonce: u32,
+    msg: Vec<u8>,
+    consistency: String,
+) -> JsValue {
+    let consistency_level = match consistency.as_str() {
+        "CONFIRMED" => ConsistencyLevel::Confirmed,
+        "FINALIZED" => ConsistencyLevel::Finalized,
+        _ => panic!("invalid consistency level"),
+    };
+    let ix = post_message_unreliable_ring(
+        Pubkey::from_str(program_id.as_str()).unwrap(),
+        Pubkey::from_str(payer.as_str()).unwrap(),
+        Pubkey::from_str(emitter.as_str()).unwrap(),
+        sequence,
+        ring_size,
+        nonce,
+        msg,
+        consistency_level,
+    )
+    .unwrap();
+    return JsValue::from_serde(&ix).unwrap();
+}
+
 #[wasm_bindgen]
 pub fn post_vaa_ix(
     program_id: String,
@@ -145,12 +177,7 @@ pub fn update_guardian_set_ix(program_id: String, payer: String, vaa: Vec<u8>) -
     let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
     let payload =
         GovernancePayloadGuardianSetChange::deserialize(&mut vaa.payload.as_slice()).unwrap();
-    let message_key = PostedVAA::<'_, { AccountState::Uninitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: hash_vaa(&vaa.clone().into()).to_vec(),
-        },
-        &program_id,
-    );
+    let message_key = posted_vaa_address(program_id, hash_vaa(&vaa.clone().into()));
     let ix = upgrade_guardian_set(
         program_id,
         Pubkey::from_str(payer.as_str()).unwrap(),
@@ -167,12 +194,7 @@ pub fn update_guardian_set_ix(program_id: String, payer: String, vaa: Vec<u8>) -
 pub fn set_fees_ix(program_id: String, payer: String, vaa: Vec<u8>) -> JsValue {
     let program_id = Pubkey::from_str(program_id.as_str()).unwrap();
     let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
-    let message_key = PostedVAA::<'_, { AccountState::Uninitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: hash_vaa(&vaa.clone().into()).to_vec(),
-        },
-        &program_id,
-    );
+    let message_key = posted_vaa_address(program_id, hash_vaa(&vaa.clone().into()));
     let ix = set_fees(
         program_id,
         Pubkey::from_str(payer.as_str()).unwrap(),
@@ -183,17 +205,27 @@ pub fn set_fees_ix(program_id: String, payer: String, vaa: Vec<u8>) -> JsValue {
     return JsValue::from_serde(&ix).unwrap();
 }
 
+#[wasm_bindgen]
+pub fn update_deny_list_ix(program_id: String, payer: String, vaa: Vec<u8>) -> JsValue {
+    let program_id = Pubkey::from_str(program_id.as_str()).unwrap();
+    let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
+    let message_key = posted_vaa_address(program_id, hash_vaa(&vaa.clone().into()));
+    let ix = update_deny_list(
+        program_id,
+        Pubkey::from_str(payer.as_str()).unwrap(),
+        message_key,
+        Pubkey::new(&vaa.emitter_address),
+        vaa.sequence,
+    );
+    return JsValue::from_serde(&ix).unwrap();
+}
+
 #[wasm_bindgen]
 pub fn transfer_fees_ix(program_id: String, payer: String, vaa: Vec<u8>) -> JsValue {
     let program_id = Pubkey::from_str(program_id.as_str()).unwrap();
     let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
     let payload = GovernancePayloadTransferFees::deserialize(&mut vaa.payload.as_slice()).unwrap();
-    let message_key = PostedVAA::<'_, { AccountState::Uninitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: hash_vaa(&vaa.clone().into()).to_vec(),
-        },
-        &program_id,
-    );
+    let message_key = posted_vaa_address(program_id, hash_vaa(&vaa.clone().into()));
     let ix = transfer_fees(
         program_id,
         Pubkey::from_str(payer.as_str()).unwrap(),
@@ -216,12 +248,7 @@ pub fn upgrade_contract_ix(
     let spill = Pubkey::from_str(spill.as_str()).unwrap();
     let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
     let payload = GovernancePayloadUpgrade::deserialize(&mut vaa.payload.as_slice()).unwrap();
-    let message_key = PostedVAA::<'_, { AccountState::Uninitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: hash_vaa(&vaa.clone().into()).to_vec(),
-        },
-        &program_id,
-    );
+    let message_key = posted_vaa_address(program_id, hash_vaa(&vaa.clone().into()));
     let ix = upgrade_contract(
         program_id,
         Pubkey::from_str(payer.as_str()).unwrap(),
@@ -374,18 +401,30 @@ pub fn fee_collector_address(bridge: String) -> Vec<u8> {
     bridge_key.to_bytes().to_vec()
 }
 
+#[wasm_bindgen]
+pub fn unreliable_message_slot_address(
+    bridge: String,
+    emitter: String,
+    sequence: u64,
+    ring_size: u16,
+) -> Vec<u8> {
+    let program_id = Pubkey::from_str(bridge.as_str()).unwrap();
+    let emitter = Pubkey::from_str(emitter.as_str()).unwrap();
+    let message_key = unreliable_message_address(program_id, emitter, sequence, ring_size);
+
+    message_key.to_bytes().to_vec()
+}
+
 #[wasm_bindgen]
 pub fn claim_address(program_id: String, vaa: Vec<u8>) -> Vec<u8> {
     let program_id = Pubkey::from_str(program_id.as_str()).unwrap();
 
     let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
-    let claim_key = Claim::<'_, { AccountState::Initialized }>::key(
-        &ClaimDerivationData {
-            emitter_address: vaa.emitter_address,
-            emitter_chain: vaa.emitter_chain,
-            sequence: vaa.sequence,
-        },
-        &program_id,
+    let claim_key = instructions::claim_address(
+        program_id,
+        vaa.emitter_address,
+        vaa.emitter_chain,
+        vaa.sequence,
     );
     claim_key.to_bytes().to_vec()
 }
@@ -399,3 +438,11 @@ pub fn parse_posted_message(data: Vec<u8>) -> JsValue {
 pub fn parse_vaa(data: Vec<u8>) -> JsValue {
     JsValue::from_serde(&VAA::deserialize(data.as_slice()).unwrap()).unwrap()
 }
+
+/// Decode the custom error code of a failed bridge instruction into `[code, name, message]`, or
+/// `null` if the code is unknown.
+#[wasm_bindgen]
+pub fn decode_error_code(code: u32) -> JsValue {
+    let error = decode_error(code).map(|e| (e.code, e.name, e.message));
+    JsValue::from_serde(&error).unwrap()
+}
@@ -35,6 +35,7 @@ use std::{
         Cursor,
         Write,
     },
+    path::Path,
     time::SystemTime,
 };
 
@@ -47,7 +48,10 @@ use bridge::{
     VerifySignaturesData,
 };
 
-use solitaire::processors::seeded::Seeded;
+use solitaire::{
+    processors::seeded::Seeded,
+    profile::StageCost,
+};
 
 pub use helpers::*;
 
@@ -66,6 +70,7 @@ pub async fn execute<T: Signers>(
     client
         .process_transaction_with_commitment(transaction, commitment_level)
         .await
+        .map_err(Into::into)
 }
 
 mod helpers {
@@ -90,6 +95,25 @@ mod helpers {
         (client, payer, program)
     }
 
+    /// Write program logs to `path` instead of stderr. Programs built with the `profile` feature
+    /// log compute unit checkpoints that `profile` then reads back. Must be called after `setup`,
+    /// which installs its own logger.
+    #[allow(dead_code)]
+    pub fn capture_logs(path: &Path) {
+        let _ = std::fs::remove_file(path);
+        solana_logger::setup_file_with_default(
+            path.to_str().unwrap(),
+            "solana_runtime::message_processor=debug",
+        );
+    }
+
+    /// Compute units spent in each stage of the instructions executed since `capture_logs`.
+    #[allow(dead_code)]
+    pub fn profile(path: &Path) -> Vec<StageCost> {
+        let logs = std::fs::read_to_string(path).unwrap();
+        solitaire::profile::stage_costs(logs.lines())
+    }
+
     /// Wait for a single transaction to fully finalize, guaranteeing chain state has been
     /// confirmed. Useful for consistently fetching data during state checks.
     pub async fn sync(client: &mut BanksClient, payer: &Keypair) {
@@ -226,6 +250,7 @@ mod helpers {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn post_message(
         client: &mut BanksClient,
         program: &Pubkey,
@@ -262,7 +287,7 @@ mod helpers {
         execute(
             client,
             payer,
-            &[payer, emitter, &message],
+            &[payer, emitter, message],
             &[
                 system_instruction::transfer(&payer.pubkey(), &fee_collector, fee),
                 instruction,
@@ -274,6 +299,7 @@ mod helpers {
         Ok(message.pubkey())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn post_message_unreliable(
         client: &mut BanksClient,
         program: &Pubkey,
@@ -302,7 +328,7 @@ mod helpers {
         execute(
             client,
             payer,
-            &[payer, emitter, &message],
+            &[payer, emitter, message],
             &[
                 system_instruction::transfer(&payer.pubkey(), &fee_collector, fee),
                 instruction,
@@ -312,6 +338,55 @@ mod helpers {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn post_message_unreliable_ring(
+        client: &mut BanksClient,
+        program: &Pubkey,
+        payer: &Keypair,
+        emitter: &Keypair,
+        sequence: u64,
+        ring_size: u16,
+        nonce: u32,
+        data: Vec<u8>,
+        fee: u64,
+    ) -> Result<Pubkey, TransportError> {
+        // Transfer money into the fee collector as it needs a balance/must exist.
+        let fee_collector = FeeCollector::<'_>::key(None, program);
+
+        // The slot is derived from the sequence, later functions will need its address.
+        let message = instructions::unreliable_message_address(
+            *program,
+            emitter.pubkey(),
+            sequence,
+            ring_size,
+        );
+        let instruction = instructions::post_message_unreliable_ring(
+            *program,
+            payer.pubkey(),
+            emitter.pubkey(),
+            sequence,
+            ring_size,
+            nonce,
+            data,
+            ConsistencyLevel::Confirmed,
+        )
+        .unwrap();
+
+        execute(
+            client,
+            payer,
+            &[payer, emitter],
+            &[
+                system_instruction::transfer(&payer.pubkey(), &fee_collector, fee),
+                instruction,
+            ],
+            CommitmentLevel::Processed,
+        )
+        .await?;
+
+        Ok(message)
+    }
+
     pub async fn verify_signatures(
         client: &mut BanksClient,
         program: &Pubkey,
@@ -455,6 +530,30 @@ mod helpers {
         .await
     }
 
+    pub async fn update_deny_list(
+        client: &mut BanksClient,
+        program: &Pubkey,
+        payer: &Keypair,
+        message: Pubkey,
+        emitter: Pubkey,
+        sequence: u64,
+    ) -> Result<(), TransportError> {
+        execute(
+            client,
+            payer,
+            &[payer],
+            &[instructions::update_deny_list(
+                *program,
+                payer.pubkey(),
+                message,
+                emitter,
+                sequence,
+            )],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
     pub async fn transfer_fees(
         client: &mut BanksClient,
         program: &Pubkey,
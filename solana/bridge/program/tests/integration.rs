@@ -10,35 +10,46 @@ use solana_program_test::{
 };
 use solana_sdk::{
     commitment_config::CommitmentLevel,
+    instruction::InstructionError,
     signature::{
         Keypair,
         Signer,
     },
+    transaction::TransactionError,
+    transport::TransportError,
 };
 use solitaire::{
+    idl::IdlMutability,
     processors::seeded::Seeded,
     AccountState,
+    ErrBox,
 };
+use std::collections::HashMap;
 
 use bridge::{
     accounts::{
         Bridge,
         BridgeData,
+        DenyListData,
         FeeCollector,
         GuardianSet,
         GuardianSetData,
         GuardianSetDerivationData,
-        PostedVAA,
         PostedVAAData,
-        PostedVAADerivationData,
         SignatureSetData,
     },
+    error::Error,
     instructions,
+    status::{
+        self,
+        VAAStatus,
+    },
     types::{
         ConsistencyLevel,
         GovernancePayloadGuardianSetChange,
         GovernancePayloadSetMessageFee,
         GovernancePayloadTransferFees,
+        GovernancePayloadUpdateDenyList,
         GovernancePayloadUpgrade,
     },
     SerializeGovernancePayload,
@@ -177,12 +188,7 @@ async fn bridge_messages() {
                 .unwrap();
 
         // Derive where we expect the posted VAA to be stored.
-        let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-            &PostedVAADerivationData {
-                payload_hash: body.to_vec(),
-            },
-            program,
-        );
+        let message_key = instructions::posted_vaa_address(*program, body);
         common::post_vaa(client, program, payer, signature_set, vaa)
             .await
             .unwrap();
@@ -260,12 +266,7 @@ async fn bridge_messages() {
         .unwrap();
 
     // Derive where we expect the posted VAA to be stored.
-    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: body.to_vec(),
-        },
-        program,
-    );
+    let message_key = instructions::posted_vaa_address(*program, body);
     common::post_vaa(client, program, payer, signature_set, vaa)
         .await
         .unwrap();
@@ -346,7 +347,7 @@ async fn test_bridge_messages_unreliable() {
         );
 
         // Emulate Guardian behaviour, verifying the data and publishing signatures/VAA.
-        let (vaa, body, body_hash) =
+        let (vaa, body, _body_hash) =
             common::generate_vaa(&emitter, message.to_vec(), nonce, sequence, 0, 1);
         let signature_set =
             common::verify_signatures(client, program, payer, body, &context.secret, 0)
@@ -355,12 +356,7 @@ async fn test_bridge_messages_unreliable() {
         common::post_vaa(client, program, payer, signature_set, vaa)
             .await
             .unwrap();
-        let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-            &PostedVAADerivationData {
-                payload_hash: body.to_vec(),
-            },
-            program,
-        );
+        let message_key = instructions::posted_vaa_address(*program, body);
         common::sync(client, payer).await;
 
         // Fetch chain accounts to verify state.
@@ -383,8 +379,8 @@ async fn test_bridge_messages_unreliable() {
         assert_eq!(signatures.hash, body);
         assert_eq!(signatures.guardian_set_index, 0);
 
-        for (signature, secret_key) in signatures.signatures.iter().zip(context.secret.iter()) {
-            assert_eq!(*signature, true);
+        for (signature, _) in signatures.signatures.iter().zip(context.secret.iter()) {
+            assert!(*signature);
         }
     }
 
@@ -462,6 +458,120 @@ async fn test_bridge_messages_unreliable_do_not_override_reliable() {
     .is_err());
 }
 
+#[tokio::test]
+async fn test_bridge_messages_unreliable_ring() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+
+    let emitter = Keypair::new();
+    let ring_size = 3;
+    let mut slots = Vec::new();
+    let bridge_key = Bridge::<'_, { AccountState::Uninitialized }>::key(None, program);
+    let fee_collector = FeeCollector::key(None, program);
+
+    // Cycle through the ring more than once with varying payload lengths, forcing the slot
+    // accounts to be created, grown and shrunk.
+    for len in [32usize, 64, 16, 48, 8, 96, 32] {
+        let nonce = rand::thread_rng().gen();
+        let message: Vec<u8> = (0..len).map(|_| rand::thread_rng().gen()).collect();
+        let sequence = context.seq.next(emitter.pubkey().to_bytes());
+        let slot = instructions::unreliable_message_address(
+            *program,
+            emitter.pubkey(),
+            sequence,
+            ring_size,
+        );
+        let slot_before = client
+            .get_account(slot)
+            .await
+            .unwrap()
+            .map(|account| account.lamports)
+            .unwrap_or_default();
+        let collector_before = common::get_account_balance(client, fee_collector).await;
+
+        let message_key = common::post_message_unreliable_ring(
+            client,
+            program,
+            payer,
+            &emitter,
+            sequence,
+            ring_size,
+            nonce,
+            message.clone(),
+            10_000,
+        )
+        .await
+        .unwrap();
+
+        // The slot is selected by the sequence.
+        if sequence < ring_size as u64 {
+            slots.push(message_key);
+        }
+        assert_eq!(message_key, slots[(sequence % ring_size as u64) as usize]);
+
+        // Verify on chain Message
+        let posted_message: PostedVAAData = common::get_account_data(client, message_key).await;
+        assert_eq!(posted_message.message.vaa_version, 0);
+        assert_eq!(posted_message.message.nonce, nonce);
+        assert_eq!(posted_message.message.sequence, sequence);
+        assert_eq!(posted_message.message.emitter_chain, 1);
+        assert_eq!(posted_message.message.payload, message);
+        assert_eq!(
+            posted_message.message.emitter_address,
+            emitter.pubkey().to_bytes()
+        );
+
+        // The slot must stay exactly rent exempt after being resized. Missing rent comes from the
+        // payer, excess rent goes to the fee collector rather than whoever posts next.
+        let account = client.get_account(message_key).await.unwrap().unwrap();
+        assert_eq!(
+            account.lamports,
+            Rent::default().minimum_balance(account.data.len())
+        );
+        let collector_after = common::get_account_balance(client, fee_collector).await;
+        let refund = slot_before.saturating_sub(account.lamports);
+        assert_eq!(collector_after, collector_before + 10_000 + refund);
+
+        // The refund does not count towards the fee of the next message.
+        let bridge: BridgeData = common::get_account_data(client, bridge_key).await;
+        assert_eq!(bridge.last_lamports, collector_after);
+    }
+
+    // Slots are unique per emitter.
+    assert_ne!(slots[0], slots[1]);
+    assert_ne!(slots[1], slots[2]);
+
+    // Posting into a slot that does not belong to the next sequence fails.
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+    assert!(common::post_message_unreliable_ring(
+        client,
+        program,
+        payer,
+        &emitter,
+        sequence + 1,
+        ring_size,
+        rand::thread_rng().gen(),
+        vec![0u8; 32],
+        10_000,
+    )
+    .await
+    .is_err());
+
+    // Oversized rings are rejected.
+    assert!(common::post_message_unreliable_ring(
+        client,
+        program,
+        payer,
+        &emitter,
+        sequence,
+        bridge::MAX_UNRELIABLE_RING_SIZE + 1,
+        rand::thread_rng().gen(),
+        vec![0u8; 32],
+        10_000,
+    )
+    .await
+    .is_err());
+}
+
 #[tokio::test]
 async fn bridge_works_after_transfer_fees() {
     // This test aims to ensure that the bridge remains operational after the
@@ -716,12 +826,7 @@ async fn guardian_set_change() {
     let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
         .await
         .unwrap();
-    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: body.to_vec(),
-        },
-        program,
-    );
+    let message_key = instructions::posted_vaa_address(*program, body);
     common::post_vaa(client, program, payer, signature_set, vaa)
         .await
         .unwrap();
@@ -804,12 +909,7 @@ async fn guardian_set_change() {
         .await
         .unwrap();
     let vaa_time = vaa.timestamp;
-    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: body.to_vec(),
-        },
-        program,
-    );
+    let message_key = instructions::posted_vaa_address(*program, body);
     common::post_vaa(client, program, payer, signature_set, vaa)
         .await
         .unwrap();
@@ -985,12 +1085,7 @@ async fn set_fees() {
         .await
         .unwrap();
     let vaa_time = vaa.timestamp;
-    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: body.to_vec(),
-        },
-        program,
-    );
+    let message_key = instructions::posted_vaa_address(*program, body);
     common::post_vaa(client, program, payer, signature_set, vaa)
         .await
         .unwrap();
@@ -1154,12 +1249,7 @@ async fn free_fees() {
         .await
         .unwrap();
     let vaa_time = vaa.timestamp;
-    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: body.to_vec(),
-        },
-        program,
-    );
+    let message_key = instructions::posted_vaa_address(*program, body);
     common::post_vaa(client, program, payer, signature_set, vaa)
         .await
         .unwrap();
@@ -1399,12 +1489,7 @@ async fn foreign_bridge_messages() {
         common::generate_vaa(&emitter, message.clone(), nonce, sequence, 0, 2);
 
     // Derive where we expect created accounts to be.
-    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
-        &PostedVAADerivationData {
-            payload_hash: body.to_vec(),
-        },
-        program,
-    );
+    let message_key = instructions::posted_vaa_address(*program, body);
 
     let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
         .await
@@ -1560,3 +1645,665 @@ async fn upgrade_contract() {
     .unwrap();
     common::sync(client, payer).await;
 }
+
+// Submit a governance VAA adding `account` to, or removing it from, the deny list.
+async fn update_deny_list(
+    context: &mut Context,
+    client: &mut BanksClient,
+    payer: &Keypair,
+    program: &Pubkey,
+    account: Pubkey,
+    denied: bool,
+) {
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+
+    let nonce = rand::thread_rng().gen();
+    let message = GovernancePayloadUpdateDenyList { account, denied }
+        .try_to_vec()
+        .unwrap();
+
+    let message_key = common::post_message(
+        client,
+        program,
+        payer,
+        &emitter,
+        None,
+        nonce,
+        message.clone(),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let (vaa, body, _body_hash) =
+        common::generate_vaa(&emitter, message.clone(), nonce, sequence, 0, 1);
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, program, payer, signature_set, vaa)
+        .await
+        .unwrap();
+    common::update_deny_list(
+        client,
+        program,
+        payer,
+        message_key,
+        emitter.pubkey(),
+        sequence,
+    )
+    .await
+    .unwrap();
+    common::sync(client, payer).await;
+
+    let deny_list_key = instructions::deny_list_address(*program);
+    let deny_list: DenyListData = common::get_account_data(client, deny_list_key).await;
+    assert_eq!(deny_list.contains(&account), denied);
+}
+
+#[tokio::test]
+async fn deny_list_rejects_vaa_claims() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+
+    // Post a governance VAA, but deny it before it is claimed.
+    let nonce = rand::thread_rng().gen();
+    let message = GovernancePayloadSetMessageFee {
+        fee: U256::from(100u128),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let message_key = common::post_message(
+        client,
+        program,
+        payer,
+        &emitter,
+        None,
+        nonce,
+        message.clone(),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let (vaa, body, _body_hash) =
+        common::generate_vaa(&emitter, message.clone(), nonce, sequence, 0, 1);
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, program, payer, signature_set, vaa)
+        .await
+        .unwrap();
+    common::sync(client, payer).await;
+
+    update_deny_list(context, client, payer, program, message_key, true).await;
+
+    // A listed VAA can not be claimed.
+    assert!(common::set_fees(
+        client,
+        program,
+        payer,
+        message_key,
+        emitter.pubkey(),
+        sequence,
+    )
+    .await
+    .is_err());
+    common::sync(client, payer).await;
+
+    // Removing it from the list makes it claimable again.
+    update_deny_list(context, client, payer, program, message_key, false).await;
+
+    common::set_fees(
+        client,
+        program,
+        payer,
+        message_key,
+        emitter.pubkey(),
+        sequence,
+    )
+    .await
+    .unwrap();
+    common::sync(client, payer).await;
+
+    let bridge_key = Bridge::<'_, { AccountState::Uninitialized }>::key(None, program);
+    let bridge: BridgeData = common::get_account_data(client, bridge_key).await;
+    assert_eq!(bridge.config.fee, 100);
+}
+
+#[tokio::test]
+async fn post_vaa_requires_deny_list() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+    let emitter = Keypair::new();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+    let nonce = rand::thread_rng().gen();
+    let message = [0u8; 32].to_vec();
+
+    let (vaa, body, _body_hash) = common::generate_vaa(&emitter, message, nonce, sequence, 0, 1);
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+
+    // Leaving the deny list out must not dodge it.
+    let deny_list = instructions::deny_list_address(*program);
+    let mut instruction = instructions::post_vaa(*program, payer.pubkey(), signature_set, vaa);
+    instruction
+        .accounts
+        .retain(|account| account.pubkey != deny_list);
+    let code = match common::execute(
+        client,
+        payer,
+        &[payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    {
+        Err(TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) => code,
+        other => panic!("unexpected result: {:?}", other),
+    };
+    assert_eq!(code, Error::MissingAccount.code());
+}
+
+#[tokio::test]
+async fn deny_list_rejects_vaa_posts() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+
+    // Deny a governance VAA before it is posted.
+    let nonce = rand::thread_rng().gen();
+    let message = GovernancePayloadSetMessageFee {
+        fee: U256::from(100u128),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let message_key = common::post_message(
+        client,
+        program,
+        payer,
+        &emitter,
+        None,
+        nonce,
+        message.clone(),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let (vaa, body, _body_hash) =
+        common::generate_vaa(&emitter, message.clone(), nonce, sequence, 0, 1);
+    let posted_key = instructions::posted_vaa_address(*program, body);
+    update_deny_list(context, client, payer, program, posted_key, true).await;
+
+    // A listed VAA can not be posted.
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    assert!(
+        common::post_vaa(client, program, payer, signature_set, vaa.clone())
+            .await
+            .is_err()
+    );
+    common::sync(client, payer).await;
+
+    // Removing it from the list makes it postable, and claimable, again.
+    update_deny_list(context, client, payer, program, posted_key, false).await;
+
+    common::post_vaa(client, program, payer, signature_set, vaa)
+        .await
+        .unwrap();
+    common::set_fees(
+        client,
+        program,
+        payer,
+        message_key,
+        emitter.pubkey(),
+        sequence,
+    )
+    .await
+    .unwrap();
+    common::sync(client, payer).await;
+
+    let bridge_key = Bridge::<'_, { AccountState::Uninitialized }>::key(None, program);
+    let bridge: BridgeData = common::get_account_data(client, bridge_key).await;
+    assert_eq!(bridge.config.fee, 100);
+}
+
+#[tokio::test]
+async fn deny_list_rejects_signature_and_guardian_sets() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+    let emitter = Keypair::new();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+
+    let nonce = rand::thread_rng().gen();
+    let message = [0u8; 32].to_vec();
+    common::post_message(
+        client,
+        program,
+        payer,
+        &emitter,
+        None,
+        nonce,
+        message.clone(),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    // A listed signature set can not be used to post a VAA.
+    let (vaa, body, _body_hash) =
+        common::generate_vaa(&emitter, message.clone(), nonce, sequence, 0, 1);
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    update_deny_list(context, client, payer, program, signature_set, true).await;
+    assert!(
+        common::post_vaa(client, program, payer, signature_set, vaa.clone())
+            .await
+            .is_err()
+    );
+    common::sync(client, payer).await;
+
+    // A listed guardian set can not be used to post a VAA, even with a fresh signature set.
+    let guardian_set_key = GuardianSet::<'_, { AccountState::Initialized }>::key(
+        &GuardianSetDerivationData { index: 0 },
+        program,
+    );
+    update_deny_list(context, client, payer, program, guardian_set_key, true).await;
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    assert!(
+        common::post_vaa(client, program, payer, signature_set, vaa.clone())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn error_codes_are_surfaced() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+
+    // Governance from a non-governance emitter fails with the bridge's own error code.
+    let emitter = Keypair::new();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+
+    let nonce = rand::thread_rng().gen();
+    let message = GovernancePayloadSetMessageFee {
+        fee: U256::from(100u128),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let message_key = common::post_message(
+        client,
+        program,
+        payer,
+        &emitter,
+        None,
+        nonce,
+        message.clone(),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let (vaa, body, _body_hash) =
+        common::generate_vaa(&emitter, message.clone(), nonce, sequence, 0, 1);
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, program, payer, signature_set, vaa)
+        .await
+        .unwrap();
+
+    let code = match common::set_fees(client, program, payer, message_key, emitter.pubkey(), sequence)
+        .await
+    {
+        Err(TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) => code,
+        other => panic!("unexpected result: {:?}", other),
+    };
+    assert_eq!(code, Error::InvalidGovernanceKey.code());
+    assert!(matches!(
+        Error::from_code(code),
+        Some(Error::InvalidGovernanceKey)
+    ));
+    assert_eq!(
+        Error::InvalidGovernanceKey.to_string(),
+        "Governance VAA was not emitted by the governance emitter"
+    );
+
+    let decoded = instructions::decode_error(code).unwrap();
+    assert_eq!(decoded.name, "InvalidGovernanceKey");
+
+    // Framework errors live in their own range and decode as well.
+    let decoded = instructions::decode_error(solitaire::error::SOLITAIRE_ERROR_BASE).unwrap();
+    assert_eq!(decoded.name, "InvalidMutability");
+    assert!(instructions::decode_error(u32::MAX).is_none());
+}
+
+/// Snapshot accounts from the test validator, standing in for RPC in the VAA status lookup.
+async fn account_snapshot(client: &mut BanksClient, keys: &[Pubkey]) -> HashMap<Pubkey, Vec<u8>> {
+    let mut accounts = HashMap::new();
+    for key in keys {
+        if let Some(account) = client.get_account(*key).await.unwrap() {
+            accounts.insert(*key, account.data);
+        }
+    }
+    accounts
+}
+
+#[tokio::test]
+async fn vaa_status_follows_lifecycle() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+
+    let nonce = rand::thread_rng().gen();
+    let message = GovernancePayloadSetMessageFee {
+        fee: U256::from(100u128),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let message_key = common::post_message(
+        client,
+        program,
+        payer,
+        &emitter,
+        None,
+        nonce,
+        message.clone(),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let (vaa, body, _body_hash) =
+        common::generate_vaa(&emitter, message.clone(), nonce, sequence, 0, 1);
+    let posted_key = instructions::posted_vaa_address(*program, body);
+    let claim_key = instructions::claim_address(
+        *program,
+        vaa.emitter_address,
+        vaa.emitter_chain,
+        sequence,
+    );
+
+    let lookup = |accounts: &HashMap<Pubkey, Vec<u8>>, signature_set: Option<&Pubkey>| {
+        let source =
+            |key: &Pubkey| -> Result<Option<Vec<u8>>, ErrBox> { Ok(accounts.get(key).cloned()) };
+        status::vaa_body_status(&source, program, &vaa, signature_set, &[*program]).unwrap()
+    };
+
+    let accounts = account_snapshot(client, &[posted_key, claim_key]).await;
+    assert_eq!(lookup(&accounts, None), VAAStatus::NotPosted);
+
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    let accounts = account_snapshot(client, &[signature_set, posted_key, claim_key]).await;
+    assert_eq!(
+        lookup(&accounts, Some(&signature_set)),
+        VAAStatus::PartiallyVerified {
+            signature_set,
+            guardian_set_index: 0,
+            guardians: (0..context.secret.len() as u8).collect(),
+        }
+    );
+
+    common::post_vaa(client, program, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let accounts = account_snapshot(client, &[signature_set, posted_key, claim_key]).await;
+    assert_eq!(
+        lookup(&accounts, Some(&signature_set)),
+        VAAStatus::Posted {
+            message: posted_key
+        }
+    );
+
+    common::set_fees(
+        client,
+        program,
+        payer,
+        message_key,
+        emitter.pubkey(),
+        sequence,
+    )
+    .await
+    .unwrap();
+    let accounts = account_snapshot(client, &[signature_set, posted_key, claim_key]).await;
+    assert_eq!(
+        lookup(&accounts, Some(&signature_set)),
+        VAAStatus::Claimed {
+            message: posted_key,
+            module: *program,
+        }
+    );
+}
+
+#[test]
+fn idl_matches_instruction_builders() {
+    let program = Pubkey::new_unique();
+    let emitter = Keypair::new();
+    let ix = instructions::post_message(
+        program,
+        Pubkey::new_unique(),
+        emitter.pubkey(),
+        Pubkey::new_unique(),
+        0,
+        vec![],
+        ConsistencyLevel::Confirmed,
+    )
+    .unwrap();
+
+    let idl = bridge::instruction::idl();
+    let described = idl.instruction("PostMessage").unwrap();
+    assert_eq!(described.discriminant, ix.data[0]);
+
+    // Accounts past the described ones are only needed for CPI.
+    assert!(described.accounts.len() <= ix.accounts.len());
+    for (account, meta) in described.accounts.iter().zip(ix.accounts.iter()) {
+        assert_eq!(account.signer, meta.is_signer, "{}", account.name);
+        match account.mutability {
+            IdlMutability::Writable => assert!(meta.is_writable, "{}", account.name),
+            IdlMutability::Readonly => assert!(!meta.is_writable, "{}", account.name),
+            IdlMutability::Maybe => {}
+        }
+    }
+
+    let bridge = &described.accounts[0];
+    assert_eq!(bridge.name, "bridge");
+    assert_eq!(bridge.seed.as_deref(), Some("Bridge"));
+    assert_eq!(bridge.state.as_deref(), Some("Initialized"));
+    let clock = described.accounts.iter().find(|a| a.name == "clock").unwrap();
+    assert_eq!(
+        clock.address,
+        Some(solana_program::sysvar::clock::id().to_string())
+    );
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn generated_builder_matches_instructions() {
+    use bridge::{
+        accounts::SequenceDerivationData,
+        vaa::ClaimableVAA,
+        ClaimDerivationData,
+        PostMessage,
+        PostMessageData,
+        SetFees,
+        SetFeesData,
+        CHAIN_ID_SOLANA,
+    };
+
+    let program = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let emitter = Pubkey::new_unique();
+    let message = Pubkey::new_unique();
+
+    let built = PostMessage::builder(program)
+        .message(message)
+        .emitter(emitter)
+        .sequence_seeded(&SequenceDerivationData {
+            emitter_key: &emitter,
+        })
+        .payer(payer)
+        .with_rent()
+        .with_system_program()
+        .build(
+            bridge::instruction::Instruction::PostMessage,
+            PostMessageData {
+                nonce: 7,
+                payload: vec![1, 2, 3],
+                consistency_level: ConsistencyLevel::Confirmed,
+            },
+        )
+        .unwrap();
+    let expected = instructions::post_message(
+        program,
+        payer,
+        emitter,
+        message,
+        7,
+        vec![1, 2, 3],
+        ConsistencyLevel::Confirmed,
+    )
+    .unwrap();
+    assert_eq!(built, expected);
+
+    // Nested accounts structs are set through their own builder.
+    let vaa = ClaimableVAA::<GovernancePayloadSetMessageFee>::builder(program)
+        .message(message)
+        .claim_seeded(&ClaimDerivationData {
+            emitter_address: emitter.to_bytes(),
+            emitter_chain: CHAIN_ID_SOLANA,
+            sequence: 3,
+        });
+    let built = SetFees::builder(program)
+        .payer(payer)
+        .vaa(vaa)
+        .with_system_program()
+        .build(bridge::instruction::Instruction::SetFees, SetFeesData {})
+        .unwrap();
+    assert_eq!(
+        built,
+        instructions::set_fees(program, payer, message, emitter, 3)
+    );
+
+    // Accounts that can not be derived must be passed.
+    assert!(PostMessage::builder(program)
+        .build(bridge::instruction::Instruction::PostMessage, ())
+        .is_err());
+}
+
+#[test]
+fn cpi_accounts_match_instructions() {
+    use bridge::api::PostMessageCpi;
+    use solana_program::account_info::AccountInfo;
+    use solitaire::CpiAccounts;
+
+    let program = Pubkey::new_unique();
+    let expected = instructions::post_message(
+        program,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        0,
+        vec![],
+        ConsistencyLevel::Confirmed,
+    )
+    .unwrap();
+
+    // Accounts as a calling program sees them, the emitter is a PDA that only signs through the
+    // invocation.
+    let owner = Pubkey::new_unique();
+    let mut lamports = vec![0u64; 7];
+    let mut data = vec![Vec::<u8>::new(); 7];
+    let infos: Vec<AccountInfo> = expected.accounts[..7]
+        .iter()
+        .zip(lamports.iter_mut().zip(data.iter_mut()))
+        .enumerate()
+        .map(|(i, (meta, (lamports, data)))| {
+            AccountInfo::new(
+                &meta.pubkey,
+                meta.is_signer && i != 2,
+                meta.is_writable,
+                lamports,
+                data,
+                &owner,
+                false,
+                0,
+            )
+        })
+        .collect();
+
+    let accounts = PostMessageCpi {
+        bridge: infos[0].clone(),
+        message: infos[1].clone(),
+        emitter: infos[2].clone(),
+        sequence: infos[3].clone(),
+        payer: infos[4].clone(),
+        fee_collector: infos[5].clone(),
+        clock: infos[6].clone(),
+    };
+    assert_eq!(accounts.to_account_metas(), expected.accounts[..7].to_vec());
+}
+
+/// Reports the compute units spent in each stage of the instructions guardians call for every
+/// message. Requires the BPF build, run it on its own so logs of other tests do not mix in:
+/// `cargo test-bpf --features profile,instructions -- --nocapture compute_units`.
+#[cfg(feature = "profile")]
+#[tokio::test]
+async fn compute_units() {
+    let (ref mut context, ref mut client, ref payer, ref program) = initialize().await;
+    let logs = std::env::temp_dir().join("bridge-compute-units.log");
+    common::capture_logs(&logs);
+
+    let message = [0u8; 32].to_vec();
+    let emitter = Keypair::new();
+    let nonce = rand::thread_rng().gen();
+    let sequence = context.seq.next(emitter.pubkey().to_bytes());
+    common::post_message(
+        client,
+        program,
+        payer,
+        &emitter,
+        None,
+        nonce,
+        message.clone(),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let (vaa, body, _) = common::generate_vaa(&emitter, message, nonce, sequence, 0, 1);
+    let signature_set = common::verify_signatures(client, program, payer, body, &context.secret, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, program, payer, signature_set, vaa)
+        .await
+        .unwrap();
+
+    let costs = common::profile(&logs);
+    for cost in &costs {
+        println!("{} {}: {} units", cost.instruction, cost.stage, cost.units);
+    }
+    for instruction in &["PostMessage", "VerifySignatures", "PostVAA"] {
+        assert!(
+            solitaire::profile::instruction_cost(&costs, instruction) > 0,
+            "{} was not profiled",
+            instruction
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Print the IDL of the program as JSON, see `solitaire::idl`.
+
+fn main() {
+    let idl = wormhole_migration::instruction::idl()
+        .with_errors(wormhole_migration::MigrationError::ERROR_CODES);
+    println!("{}", idl.to_json());
+}
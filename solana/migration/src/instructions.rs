@@ -30,6 +30,7 @@ use solana_program::{
 use solitaire::{
     processors::seeded::Seeded,
     AccountState,
+    ErrorCode,
 };
 
 pub fn add_liquidity(
@@ -292,3 +293,8 @@ pub fn migrate_tokens(
             .try_to_vec()?,
     })
 }
+
+/// Decode the `ProgramError::Custom` code of a failed migration instruction.
+pub fn decode_error(code: u32) -> Option<&'static ErrorCode> {
+    solitaire::decode_error(code, crate::MigrationError::ERROR_CODES)
+}
@@ -11,7 +11,7 @@ use api::{
 };
 use solitaire::{
     solitaire,
-    SolitaireError,
+    SolitaireErrors,
 };
 
 pub mod accounts;
@@ -29,14 +29,10 @@ extern crate wasm_bindgen;
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 pub mod wasm;
 
+#[derive(SolitaireErrors)]
 pub enum MigrationError {
-    WrongMint,
-}
-
-impl From<MigrationError> for SolitaireError {
-    fn from(t: MigrationError) -> SolitaireError {
-        SolitaireError::Custom(t as u64)
-    }
+    /// Mint does not match the migration pool
+    WrongMint = 0,
 }
 
 solitaire! {
@@ -220,3 +220,11 @@ pub fn to_custody_address(program_id: String, pool: String) -> Vec<u8> {
 pub fn parse_pool(data: Vec<u8>) -> JsValue {
     JsValue::from_serde(&PoolData::try_from_slice(data.as_slice()).unwrap()).unwrap()
 }
+
+/// Decode the custom error code of a failed migration instruction into `[code, name, message]`, or
+/// `null` if the code is unknown.
+#[wasm_bindgen]
+pub fn decode_error_code(code: u32) -> JsValue {
+    let error = instructions::decode_error(code).map(|e| (e.code, e.name, e.message));
+    JsValue::from_serde(&error).unwrap()
+}
@@ -6,7 +6,9 @@ use crate::{
     },
     messages::{
         GovernancePayloadUpgrade,
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceUpdateChain,
     },
     TokenBridgeError::{
         InvalidChain,
@@ -14,6 +16,7 @@ use crate::{
     },
 };
 use bridge::{
+    accounts::FeeCollector,
     vaa::{
         ClaimableVAA,
         DeserializePayload,
@@ -162,3 +165,94 @@ pub fn register_chain(
 
     Ok(())
 }
+
+#[derive(FromAccounts)]
+pub struct UpdateChain<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    /// Endpoint currently registered for the chain, closed in favor of `endpoint`
+    #[seeds(&EndpointDerivationData {
+        emitter_chain: accs.registered.chain,
+        emitter_address: accs.registered.contract,
+    })]
+    #[constraint(accs.registered.chain == accs.vaa.chain, InvalidChain)]
+    pub registered: Mut<Endpoint<'b, { AccountState::Initialized }>>,
+
+    #[seeds]
+    pub endpoint: Mut<Endpoint<'b, { AccountState::Uninitialized }>>,
+
+    /// Fee collector of the core bridge, receives the rent of `registered`
+    #[constraint(*accs.fee_collector.key == FeeCollector::key(None, &accs.config.wormhole_bridge))]
+    pub fee_collector: Mut<Info<'b>>,
+
+    pub vaa: ClaimableVAA<'b, PayloadGovernanceUpdateChain>,
+}
+
+impl<'a> From<&UpdateChain<'a>> for EndpointDerivationData {
+    fn from(accs: &UpdateChain<'a>) -> Self {
+        EndpointDerivationData {
+            emitter_chain: accs.vaa.chain,
+            emitter_address: accs.vaa.endpoint_address,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct UpdateChainData {}
+
+/// Replace the endpoint registered for a chain. The previous endpoint is closed in the same
+/// instruction, transfers it emitted can no longer be redeemed.
+pub fn update_chain(
+    ctx: &ExecutionContext,
+    accs: &mut UpdateChain,
+    _data: UpdateChainData,
+) -> Result<()> {
+    verify_governance(&accs.vaa)?;
+    accs.vaa.verify(ctx.program_id)?;
+    accs.vaa.claim(ctx, accs.payer.key)?;
+
+    accs.endpoint
+        .create(&((&*accs).into()), ctx, accs.payer.key, Exempt)?;
+    accs.endpoint.chain = accs.vaa.chain;
+    accs.endpoint.contract = accs.vaa.endpoint_address;
+    accs.registered.close(&accs.fee_collector)?;
+
+    Ok(())
+}
+
+#[derive(FromAccounts)]
+pub struct DeregisterChain<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(&EndpointDerivationData {
+        emitter_chain: accs.vaa.chain,
+        emitter_address: accs.vaa.endpoint_address,
+    })]
+    pub registered: Mut<Endpoint<'b, { AccountState::Initialized }>>,
+
+    /// Fee collector of the core bridge, receives the rent of `registered`
+    #[constraint(*accs.fee_collector.key == FeeCollector::key(None, &accs.config.wormhole_bridge))]
+    pub fee_collector: Mut<Info<'b>>,
+
+    pub vaa: ClaimableVAA<'b, PayloadGovernanceDeregisterChain>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct DeregisterChainData {}
+
+/// Remove an endpoint registered for a chain, transfers it emitted can no longer be redeemed.
+pub fn deregister_chain(
+    ctx: &ExecutionContext,
+    accs: &mut DeregisterChain,
+    _data: DeregisterChainData,
+) -> Result<()> {
+    verify_governance(&accs.vaa)?;
+    accs.vaa.verify(ctx.program_id)?;
+    accs.vaa.claim(ctx, accs.payer.key)?;
+
+    accs.registered.close(&accs.fee_collector)?;
+
+    Ok(())
+}
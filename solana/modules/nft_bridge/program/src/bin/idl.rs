@@ -0,0 +1,6 @@
+//! Print the IDL of the program as JSON, see `solitaire::idl`.
+
+fn main() {
+    let idl = nft_bridge::instruction::idl().with_errors(nft_bridge::TokenBridgeError::ERROR_CODES);
+    println!("{}", idl.to_json());
+}
@@ -21,13 +21,17 @@ use crate::{
             CompleteNativeData,
             CompleteWrappedData,
         },
+        DeregisterChainData,
         RegisterChainData,
         TransferNativeData,
         TransferWrappedData,
+        UpdateChainData,
         UpgradeContractData,
     },
     messages::{
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
     },
     CompleteWrappedMetaData,
@@ -38,6 +42,8 @@ use bridge::{
         Bridge,
         Claim,
         ClaimDerivationData,
+        DenyList,
+        DenyListDerivationData,
         FeeCollector,
         Sequence,
         SequenceDerivationData,
@@ -57,6 +63,7 @@ use solana_program::{
 use solitaire::{
     processors::seeded::Seeded,
     AccountState,
+    ErrorCode,
 };
 
 pub fn initialize(
@@ -90,7 +97,8 @@ pub fn complete_native(
     data: CompleteNativeData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
     let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
         &EndpointDerivationData {
             emitter_chain: vaa.emitter_chain,
@@ -113,6 +121,7 @@ pub fn complete_native(
             AccountMeta::new_readonly(config_key, false),
             message_acc,
             claim_acc,
+            deny_list_acc,
             AccountMeta::new_readonly(endpoint, false),
             AccountMeta::new(associated_addr, false),
             AccountMeta::new_readonly(to_authority, false),
@@ -143,7 +152,8 @@ pub fn complete_wrapped(
     data: CompleteWrappedData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
     let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
         &EndpointDerivationData {
             emitter_chain: vaa.emitter_chain,
@@ -175,6 +185,7 @@ pub fn complete_wrapped(
             AccountMeta::new_readonly(config_key, false),
             message_acc,
             claim_acc,
+            deny_list_acc,
             AccountMeta::new_readonly(endpoint, false),
             AccountMeta::new(associated_addr, false),
             AccountMeta::new_readonly(to_authority, false),
@@ -204,7 +215,8 @@ pub fn complete_wrapped_meta(
     data: CompleteWrappedMetaData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, _claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
+    let (message_acc, _claim_acc, _deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
     let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
         &EndpointDerivationData {
             emitter_chain: vaa.emitter_chain,
@@ -266,7 +278,8 @@ pub fn register_chain(
     data: RegisterChainData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
     let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
         &EndpointDerivationData {
             emitter_chain: payload.chain,
@@ -283,6 +296,7 @@ pub fn register_chain(
             AccountMeta::new(endpoint, false),
             message_acc,
             claim_acc,
+            deny_list_acc,
             // Dependencies
             AccountMeta::new(solana_program::sysvar::rent::id(), false),
             AccountMeta::new(solana_program::system_program::id(), false),
@@ -293,23 +307,125 @@ pub fn register_chain(
     })
 }
 
+/// Register the endpoint replacing the one registered for `payload.chain`, `registered_address` is
+/// the address of the endpoint currently registered, which is closed.
+#[allow(clippy::too_many_arguments)]
+pub fn update_chain(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadGovernanceUpdateChain,
+    registered_address: ForeignAddress,
+    data: UpdateChainData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
+    let registered = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: payload.chain,
+            emitter_address: registered_address,
+        },
+        &program_id,
+    );
+    let endpoint = Endpoint::<'_, { AccountState::Uninitialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: payload.chain,
+            emitter_address: payload.endpoint_address,
+        },
+        &program_id,
+    );
+    let fee_collector_key = FeeCollector::key(None, &bridge_id);
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new(registered, false),
+            AccountMeta::new(endpoint, false),
+            AccountMeta::new(fee_collector_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+        ],
+        data: (crate::instruction::Instruction::UpdateChain, data).try_to_vec()?,
+    })
+}
+
+/// Remove the endpoint registered for `payload.chain` at `payload.endpoint_address`, its rent goes
+/// to the fee collector of the core bridge.
+pub fn deregister_chain(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadGovernanceDeregisterChain,
+    data: DeregisterChainData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
+    let registered = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: payload.chain,
+            emitter_address: payload.endpoint_address,
+        },
+        &program_id,
+    );
+    let fee_collector_key = FeeCollector::key(None, &bridge_id);
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new(registered, false),
+            AccountMeta::new(fee_collector_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+        ],
+        data: (crate::instruction::Instruction::DeregisterChain, data).try_to_vec()?,
+    })
+}
+
 fn claimable_vaa(
+    program_id: Pubkey,
     bridge_id: Pubkey,
     message_key: Pubkey,
     vaa: PostVAAData,
-) -> (AccountMeta, AccountMeta) {
+) -> (AccountMeta, AccountMeta, AccountMeta) {
     let claim_key = Claim::<'_, { AccountState::Initialized }>::key(
         &ClaimDerivationData {
             emitter_address: vaa.emitter_address,
             emitter_chain: vaa.emitter_chain,
             sequence: vaa.sequence,
         },
+        &program_id,
+    );
+    let deny_list_key = DenyList::<'_, { AccountState::MaybeInitialized }>::key(
+        &DenyListDerivationData,
         &bridge_id,
     );
 
     (
         AccountMeta::new_readonly(message_key, false),
         AccountMeta::new(claim_key, false),
+        AccountMeta::new_readonly(deny_list_key, false),
     )
 }
 
@@ -455,8 +571,10 @@ pub fn transfer_wrapped(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn upgrade_contract(
     program_id: Pubkey,
+    bridge_id: Pubkey,
     payer: Pubkey,
     payload_message: Pubkey,
     emitter: Pubkey,
@@ -472,6 +590,10 @@ pub fn upgrade_contract(
         },
         &program_id,
     );
+    let deny_list = DenyList::<'_, { AccountState::MaybeInitialized }>::key(
+        &DenyListDerivationData,
+        &bridge_id,
+    );
 
     let (upgrade_authority, _) = Pubkey::find_program_address(&["upgrade".as_bytes()], &program_id);
 
@@ -487,6 +609,7 @@ pub fn upgrade_contract(
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(payload_message, false),
             AccountMeta::new(claim, false),
+            AccountMeta::new_readonly(deny_list, false),
             AccountMeta::new_readonly(upgrade_authority, false),
             AccountMeta::new(spill, false),
             AccountMeta::new(new_contract, false),
@@ -506,3 +629,8 @@ pub fn upgrade_contract(
             .unwrap(),
     }
 }
+
+/// Decode the `ProgramError::Custom` code of a failed NFT bridge instruction.
+pub fn decode_error(code: u32) -> Option<&'static ErrorCode> {
+    solitaire::decode_error(code, crate::TokenBridgeError::ERROR_CODES)
+}
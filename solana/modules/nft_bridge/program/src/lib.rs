@@ -24,10 +24,12 @@ pub use api::{
     complete_native,
     complete_wrapped,
     complete_wrapped_meta,
+    deregister_chain,
     initialize,
     register_chain,
     transfer_native,
     transfer_wrapped,
+    update_chain,
     upgrade_contract,
     CompleteNative,
     CompleteNativeData,
@@ -35,6 +37,8 @@ pub use api::{
     CompleteWrappedData,
     CompleteWrappedMeta,
     CompleteWrappedMetaData,
+    DeregisterChain,
+    DeregisterChainData,
     Initialize,
     InitializeData,
     RegisterChain,
@@ -43,32 +47,44 @@ pub use api::{
     TransferNativeData,
     TransferWrapped,
     TransferWrappedData,
+    UpdateChain,
+    UpdateChainData,
     UpgradeContract,
     UpgradeContractData,
 };
 
 use solitaire::*;
 
+/// Errors are reported on chain as `ProgramError::Custom(code)` where the code is the discriminant
+/// below. Codes are part of the public interface: never reorder or reuse them.
+#[derive(SolitaireErrors)]
 pub enum TokenBridgeError {
-    AlreadyExecuted,
-    InvalidChain,
-    InvalidGovernanceKey,
-    InvalidMetadata,
-    InvalidMint,
-    InvalidPayload,
-    InvalidUTF8String,
-    TokenNotNative,
-    UninitializedMint,
-    WrongAccountOwner,
-    TokenNotNFT,
-    InvalidAssociatedAccount,
-    InvalidRecipient,
-}
-
-impl From<TokenBridgeError> for SolitaireError {
-    fn from(t: TokenBridgeError) -> SolitaireError {
-        SolitaireError::Custom(t as u64)
-    }
+    /// VAA has already been executed
+    AlreadyExecuted = 0,
+    /// Chain is not supported by this transfer
+    InvalidChain = 1,
+    /// Governance VAA was not emitted by the governance emitter
+    InvalidGovernanceKey = 2,
+    /// Token metadata account is invalid
+    InvalidMetadata = 3,
+    /// Mint does not match the transfer
+    InvalidMint = 4,
+    /// VAA payload is malformed
+    InvalidPayload = 5,
+    /// String is not valid UTF-8
+    InvalidUTF8String = 6,
+    /// Token is not native to Solana
+    TokenNotNative = 7,
+    /// Mint has not been initialized
+    UninitializedMint = 8,
+    /// Token account is owned by the wrong wallet
+    WrongAccountOwner = 9,
+    /// Mint is not an NFT
+    TokenNotNFT = 10,
+    /// Recipient is not the associated token account of the owner
+    InvalidAssociatedAccount = 11,
+    /// Recipient does not match the transfer
+    InvalidRecipient = 12,
 }
 
 solitaire! {
@@ -80,4 +96,6 @@ solitaire! {
     TransferNative      => transfer_native,
     RegisterChain       => register_chain,
     UpgradeContract     => upgrade_contract,
+    UpdateChain         => update_chain,
+    DeregisterChain     => deregister_chain,
 }
@@ -244,12 +244,118 @@ impl SerializeGovernancePayload for GovernancePayloadUpgrade {
 impl DeserializeGovernancePayload for GovernancePayloadUpgrade {
 }
 
+#[derive(PartialEq, Debug)]
+pub struct PayloadGovernanceUpdateChain {
+    // Chain ID of the registered chain
+    pub chain: ChainID,
+    // Address of the endpoint replacing the registered one
+    pub endpoint_address: Address,
+}
+
+impl SerializeGovernancePayload for PayloadGovernanceUpdateChain {
+    const MODULE: &'static str = MODULE;
+    const ACTION: u8 = 3;
+}
+
+impl DeserializeGovernancePayload for PayloadGovernanceUpdateChain {
+}
+
+impl DeserializePayload for PayloadGovernanceUpdateChain
+where
+    Self: DeserializeGovernancePayload,
+{
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, SolitaireError> {
+        let mut v = Cursor::new(buf);
+        Self::check_governance_header(&mut v)?;
+
+        let chain = v.read_u16::<BigEndian>()?;
+        let mut endpoint_address = [0u8; 32];
+        v.read_exact(&mut endpoint_address)?;
+
+        if v.position() != v.into_inner().len() as u64 {
+            return Err(InvalidAccountData.into());
+        }
+
+        Ok(PayloadGovernanceUpdateChain {
+            chain,
+            endpoint_address,
+        })
+    }
+}
+
+impl SerializePayload for PayloadGovernanceUpdateChain
+where
+    Self: SerializeGovernancePayload,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SolitaireError> {
+        self.write_governance_header(writer)?;
+        writer.write_u16::<BigEndian>(self.chain)?;
+        writer.write_all(&self.endpoint_address[..])?;
+
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct PayloadGovernanceDeregisterChain {
+    // Chain ID of the chain to be deregistered
+    pub chain: ChainID,
+    // Address of the endpoint to remove
+    pub endpoint_address: Address,
+}
+
+impl SerializeGovernancePayload for PayloadGovernanceDeregisterChain {
+    const MODULE: &'static str = MODULE;
+    const ACTION: u8 = 4;
+}
+
+impl DeserializeGovernancePayload for PayloadGovernanceDeregisterChain {
+}
+
+impl DeserializePayload for PayloadGovernanceDeregisterChain
+where
+    Self: DeserializeGovernancePayload,
+{
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, SolitaireError> {
+        let mut v = Cursor::new(buf);
+        Self::check_governance_header(&mut v)?;
+
+        let chain = v.read_u16::<BigEndian>()?;
+        let mut endpoint_address = [0u8; 32];
+        v.read_exact(&mut endpoint_address)?;
+
+        if v.position() != v.into_inner().len() as u64 {
+            return Err(InvalidAccountData.into());
+        }
+
+        Ok(PayloadGovernanceDeregisterChain {
+            chain,
+            endpoint_address,
+        })
+    }
+}
+
+impl SerializePayload for PayloadGovernanceDeregisterChain
+where
+    Self: SerializeGovernancePayload,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SolitaireError> {
+        self.write_governance_header(writer)?;
+        writer.write_u16::<BigEndian>(self.chain)?;
+        writer.write_all(&self.endpoint_address[..])?;
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "no-entrypoint")]
 #[allow(unused_imports)]
 mod tests {
     use crate::messages::{
         GovernancePayloadUpgrade,
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
     };
     use bridge::{
@@ -311,4 +417,36 @@ mod tests {
 
         assert_eq!(original, deser);
     }
+
+    #[test]
+    pub fn test_serde_gov_update_chain() {
+        let mut endpoint_address = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut endpoint_address);
+
+        let original = PayloadGovernanceUpdateChain {
+            chain: 8,
+            endpoint_address,
+        };
+
+        let data = original.try_to_vec().unwrap();
+        let deser = PayloadGovernanceUpdateChain::deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(original, deser);
+    }
+
+    #[test]
+    pub fn test_serde_gov_deregister_chain() {
+        let mut endpoint_address = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut endpoint_address);
+
+        let original = PayloadGovernanceDeregisterChain {
+            chain: 8,
+            endpoint_address,
+        };
+
+        let data = original.try_to_vec().unwrap();
+        let deser = PayloadGovernanceDeregisterChain::deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(original, deser);
+    }
 }
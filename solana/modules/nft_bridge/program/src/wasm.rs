@@ -13,6 +13,7 @@ use crate::{
         complete_native,
         complete_wrapped,
         complete_wrapped_meta,
+        decode_error,
         register_chain,
         transfer_native,
         transfer_wrapped,
@@ -301,6 +302,7 @@ pub fn upgrade_contract_ix(
     );
     let ix = upgrade_contract(
         program_id,
+        bridge_id,
         Pubkey::from_str(payer.as_str()).unwrap(),
         message_key,
         Pubkey::new(&vaa.emitter_address),
@@ -427,3 +429,11 @@ pub fn parse_wrapped_meta(data: Vec<u8>) -> JsValue {
 pub fn parse_endpoint_registration(data: Vec<u8>) -> JsValue {
     JsValue::from_serde(&EndpointRegistration::try_from_slice(data.as_slice()).unwrap()).unwrap()
 }
+
+/// Decode the custom error code of a failed NFT bridge instruction into `[code, name, message]`, or
+/// `null` if the code is unknown.
+#[wasm_bindgen]
+pub fn decode_error_code(code: u32) -> JsValue {
+    let error = decode_error(code).map(|e| (e.code, e.name, e.message));
+    JsValue::from_serde(&error).unwrap()
+}
@@ -63,6 +63,7 @@ pub async fn execute<T: Signers>(
     client
         .process_transaction_with_commitment(transaction, commitment_level)
         .await
+        .map_err(Into::into)
 }
 
 mod helpers {
@@ -76,15 +77,19 @@ mod helpers {
         CompleteNativeData,
         CompleteWrappedData,
         CompleteWrappedMetaData,
+        DeregisterChainData,
         RegisterChainData,
         TransferNativeData,
         TransferWrappedData,
+        UpdateChainData,
     };
     use primitive_types::U256;
     use solana_program_test::processor;
 
     use nft_bridge::messages::{
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
     };
 
@@ -329,6 +334,69 @@ mod helpers {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_chain(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        message_acc: Pubkey,
+        vaa: PostVAAData,
+        payload: PayloadGovernanceUpdateChain,
+        registered_address: Address,
+        payer: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::update_chain(
+            program,
+            bridge,
+            payer.pubkey(),
+            message_acc,
+            vaa,
+            payload,
+            registered_address,
+            UpdateChainData {},
+        )
+        .expect("Could not create Update Chain instruction");
+
+        execute(
+            client,
+            payer,
+            &[payer],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
+    pub async fn deregister_chain(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        message_acc: Pubkey,
+        vaa: PostVAAData,
+        payload: PayloadGovernanceDeregisterChain,
+        payer: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::deregister_chain(
+            program,
+            bridge,
+            payer.pubkey(),
+            message_acc,
+            vaa,
+            payload,
+            DeregisterChainData {},
+        )
+        .expect("Could not create Deregister Chain instruction");
+
+        execute(
+            client,
+            payer,
+            &[payer],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn complete_native(
         client: &mut BanksClient,
@@ -1,8 +1,10 @@
 use bridge::{
     accounts::{
+        FeeCollector,
         PostedVAA,
         PostedVAADerivationData,
     },
+    PostVAAData,
     SerializePayload,
 };
 
@@ -10,14 +12,21 @@ use libsecp256k1::SecretKey;
 use nft_bridge::{
     accounts::{
         ConfigAccount,
+        Endpoint,
+        EndpointDerivationData,
         WrappedDerivationData,
         WrappedMint,
     },
     messages::{
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
     },
-    types::Config,
+    types::{
+        Config,
+        EndpointRegistration,
+    },
 };
 use primitive_types::U256;
 use rand::Rng;
@@ -438,3 +447,151 @@ async fn transfer_wrapped() {
     .await
     .unwrap();
 }
+
+/// Sign and post a governance VAA carrying `payload`, returning the posted message account.
+async fn post_governance_vaa(
+    context: &mut Context,
+    payload: Vec<u8>,
+    sequence: u64,
+) -> (PostVAAData, Pubkey) {
+    let Context {
+        ref payer,
+        ref mut client,
+        ref bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let (vaa, body, _) =
+        common::generate_vaa(emitter.pubkey().to_bytes(), 1, payload, nonce, sequence);
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        bridge,
+    );
+    (vaa, message_key)
+}
+
+fn endpoint_key(context: &Context, emitter_address: [u8; 32]) -> Pubkey {
+    Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: 2,
+            emitter_address,
+        },
+        &context.nft_bridge,
+    )
+}
+
+#[tokio::test]
+async fn update_chain() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+
+    let payload = PayloadGovernanceUpdateChain {
+        chain: 2,
+        endpoint_address: [9u8; 32],
+    };
+    let (vaa, message_key) =
+        post_governance_vaa(&mut context, payload.try_to_vec().unwrap(), 1).await;
+    common::update_chain(
+        &mut context.client,
+        context.nft_bridge,
+        context.bridge,
+        message_key,
+        vaa.clone(),
+        payload,
+        [0u8; 32],
+        &context.payer,
+    )
+    .await
+    .unwrap();
+
+    // The previous endpoint is closed in the same instruction.
+    let old_endpoint = endpoint_key(&context, [0u8; 32]);
+    let new_endpoint = endpoint_key(&context, [9u8; 32]);
+    assert!(context
+        .client
+        .get_account(old_endpoint)
+        .await
+        .unwrap()
+        .is_none());
+    let registration: EndpointRegistration =
+        common::get_account_data(&mut context.client, new_endpoint)
+            .await
+            .unwrap();
+    assert_eq!(registration.chain, 2);
+    assert_eq!(registration.contract, [9u8; 32]);
+
+    // The governance VAA is claimed, replaying it against the new endpoint fails.
+    let result = common::update_chain(
+        &mut context.client,
+        context.nft_bridge,
+        context.bridge,
+        message_key,
+        vaa,
+        PayloadGovernanceUpdateChain {
+            chain: 2,
+            endpoint_address: [7u8; 32],
+        },
+        [9u8; 32],
+        &context.payer,
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn deregister_chain() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+
+    let endpoint = endpoint_key(&context, [0u8; 32]);
+    let rent = context
+        .client
+        .get_account(endpoint)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let fee_collector = FeeCollector::key(None, &context.bridge);
+    let collected = context.client.get_balance(fee_collector).await.unwrap();
+    let payload = PayloadGovernanceDeregisterChain {
+        chain: 2,
+        endpoint_address: [0u8; 32],
+    };
+    let (vaa, message_key) =
+        post_governance_vaa(&mut context, payload.try_to_vec().unwrap(), 1).await;
+    common::deregister_chain(
+        &mut context.client,
+        context.nft_bridge,
+        context.bridge,
+        message_key,
+        vaa,
+        payload,
+        &context.payer,
+    )
+    .await
+    .unwrap();
+
+    assert!(context
+        .client
+        .get_account(endpoint)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        context.client.get_balance(fee_collector).await.unwrap(),
+        collected + rent
+    );
+}
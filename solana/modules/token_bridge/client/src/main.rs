@@ -43,15 +43,18 @@ use solana_sdk::{
     signature::{
         read_keypair_file,
         Keypair,
+        Signature,
         Signer,
     },
     transaction::Transaction,
 };
+use solana_transaction_status::UiTransactionEncoding;
 use solitaire::{
     processors::seeded::Seeded,
     Derive,
     Info,
 };
+use token_bridge::events::TokenBridgeEvent;
 
 struct Config {
     rpc_client: RpcClient,
@@ -129,6 +132,23 @@ fn command_create_meta(
     Ok(Some(transaction))
 }
 
+fn command_events(config: &Config, bridge: &Pubkey, signature: &Signature) -> CommmandResult {
+    let transaction = config
+        .rpc_client
+        .get_transaction(signature, UiTransactionEncoding::Json)?;
+    let logs = transaction
+        .transaction
+        .meta
+        .and_then(|meta| meta.log_messages)
+        .unwrap_or_default();
+
+    for event in token_bridge::events::decode_logs::<TokenBridgeEvent>(bridge, &logs) {
+        println!("{:?}", event);
+    }
+
+    Ok(None)
+}
+
 fn main() {
     let matches = App::new(crate_name!())
         .about(crate_description!())
@@ -272,6 +292,28 @@ fn main() {
                         .help("URI of the token metadata"),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("events")
+                .about("Decode the token bridge events emitted by a transaction")
+                .arg(
+                    Arg::with_name("bridge")
+                        .long("bridge")
+                        .value_name("BRIDGE_KEY")
+                        .validator(is_pubkey_or_keypair)
+                        .takes_value(true)
+                        .index(1)
+                        .required(true)
+                        .help("Specify the token bridge program address"),
+                )
+                .arg(
+                    Arg::with_name("signature")
+                        .value_name("SIGNATURE")
+                        .takes_value(true)
+                        .index(2)
+                        .required(true)
+                        .help("Signature of the transaction to decode"),
+                ),
+        )
         .get_matches();
 
     let config = {
@@ -348,6 +390,15 @@ fn main() {
 
             Ok(None)
         }
+        ("events", Some(arg_matches)) => {
+            let bridge = pubkey_of(arg_matches, "bridge").unwrap();
+            let signature: Signature = value_of(arg_matches, "signature").unwrap_or_else(|| {
+                eprintln!("Invalid transaction signature");
+                exit(1);
+            });
+
+            command_events(&config, &bridge, &signature)
+        }
 
         _ => unreachable!(),
     }
@@ -363,6 +414,7 @@ fn main() {
                         preflight_commitment: None,
                         encoding: None,
                         max_retries: None,
+                        min_context_slot: None,
                     },
                 )?;
             println!("Signature: {}", signature);
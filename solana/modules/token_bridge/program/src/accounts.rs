@@ -34,7 +34,7 @@ impl<'b, const STATE: AccountState> Seeded<&CustodyAccountDerivationData>
     }
 }
 
-pub type WrappedMint<'b, const STATE: AccountState> = Data<'b, SplMint, { STATE }>;
+pub type WrappedMint<'b, const STATE: AccountState> = Data<'b, SplWrappedMint, { STATE }>;
 
 pub struct WrappedDerivationData {
     pub token_chain: ChainID,
@@ -68,6 +68,25 @@ impl<'b, const STATE: AccountState> Seeded<&WrappedMetaDerivationData>
     }
 }
 
+/// Latest attestation applied to a wrapped mint, opened by the first attestation that passes it
+pub type WrappedAttestationAccount<'b, const STATE: AccountState> =
+    Data<'b, WrappedAttestation, { STATE }>;
+
+pub struct WrappedAttestationDerivationData {
+    pub mint: Pubkey,
+}
+
+impl<'b, const STATE: AccountState> Seeded<&WrappedAttestationDerivationData>
+    for WrappedAttestationAccount<'b, { STATE }>
+{
+    fn seeds(data: &WrappedAttestationDerivationData) -> Vec<Vec<u8>> {
+        vec![
+            String::from("attestation").as_bytes().to_vec(),
+            data.mint.to_bytes().to_vec(),
+        ]
+    }
+}
+
 /// Registered chain endpoint
 pub type Endpoint<'b, const STATE: AccountState> = Data<'b, EndpointRegistration, { STATE }>;
 
@@ -86,6 +105,85 @@ impl<'b, const STATE: AccountState> Seeded<&EndpointDerivationData> for Endpoint
     }
 }
 
+/// Governor limits of a native mint, transfers of mints without one are not limited
+pub type TransferLimitAccount<'b, const STATE: AccountState> = Data<'b, TransferLimit, { STATE }>;
+
+pub struct TransferLimitDerivationData {
+    pub mint: Pubkey,
+}
+
+impl<'b, const STATE: AccountState> Seeded<&TransferLimitDerivationData>
+    for TransferLimitAccount<'b, { STATE }>
+{
+    fn seeds(data: &TransferLimitDerivationData) -> Vec<Vec<u8>> {
+        vec![
+            String::from("governor").as_bytes().to_vec(),
+            data.mint.to_bytes().to_vec(),
+        ]
+    }
+}
+
+/// Outbound transfer queued by the governor, derived from the message account of the transfer
+pub type PendingTransferAccount<'b, const STATE: AccountState> =
+    Data<'b, PendingTransfer, { STATE }>;
+
+pub struct PendingTransferDerivationData {
+    pub message: Pubkey,
+}
+
+impl<'b, const STATE: AccountState> Seeded<&PendingTransferDerivationData>
+    for PendingTransferAccount<'b, { STATE }>
+{
+    fn seeds(data: &PendingTransferDerivationData) -> Vec<Vec<u8>> {
+        vec![
+            String::from("pending").as_bytes().to_vec(),
+            data.message.to_bytes().to_vec(),
+        ]
+    }
+}
+
+/// Tokens of a native mint owed to other chains, opened on the first transfer after accounting
+/// was introduced
+pub type CustodyLedgerAccount<'b, const STATE: AccountState> = Data<'b, CustodyLedger, { STATE }>;
+
+pub struct CustodyLedgerDerivationData {
+    pub mint: Pubkey,
+}
+
+impl<'b, const STATE: AccountState> Seeded<&CustodyLedgerDerivationData>
+    for CustodyLedgerAccount<'b, { STATE }>
+{
+    fn seeds(data: &CustodyLedgerDerivationData) -> Vec<Vec<u8>> {
+        vec![
+            String::from("ledger").as_bytes().to_vec(),
+            data.mint.to_bytes().to_vec(),
+        ]
+    }
+}
+
+/// Token account wrapping or unwrapping SOL within a single instruction, derived from the message
+/// account of the transfer
+pub type TemporaryAccount<'b, const STATE: AccountState> = Data<'b, SplAccount, { STATE }>;
+
+pub struct TemporaryAccountDerivationData {
+    pub message: Pubkey,
+}
+
+impl<'b, const STATE: AccountState> Seeded<&TemporaryAccountDerivationData>
+    for TemporaryAccount<'b, { STATE }>
+{
+    fn seeds(data: &TemporaryAccountDerivationData) -> Vec<Vec<u8>> {
+        vec![
+            String::from("temporary").as_bytes().to_vec(),
+            data.message.to_bytes().to_vec(),
+        ]
+    }
+}
+
+/// Transfers paused by governance
+pub type PauseAccount<'b, const STATE: AccountState> =
+    Derive<Data<'b, PauseConfig, { STATE }>, "pause">;
+
 pub type SplTokenMeta<'b> = Info<'b>;
 
 pub struct SplTokenMetaDerivationData {
@@ -2,7 +2,9 @@ pub mod attest;
 pub mod complete_transfer;
 pub mod complete_transfer_payload;
 pub mod create_wrapped;
+pub mod custody;
 pub mod governance;
+pub mod governor;
 pub mod initialize;
 pub mod transfer;
 pub mod transfer_payload;
@@ -11,7 +13,9 @@ pub use attest::*;
 pub use complete_transfer::*;
 pub use complete_transfer_payload::*;
 pub use create_wrapped::*;
+pub use custody::*;
 pub use governance::*;
+pub use governor::*;
 pub use initialize::*;
 pub use transfer::*;
 pub use transfer_payload::*;
@@ -9,29 +9,27 @@ use crate::{
         WrappedTokenMeta,
     },
     messages::PayloadAssetMeta,
+    token,
     types::*,
     TokenBridgeError::*,
 };
 use bridge::{
-    api::PostMessageData,
+    api::{
+        PostMessageCpi,
+        PostMessageData,
+    },
     types::ConsistencyLevel,
     vaa::SerializePayload,
     CHAIN_ID_SOLANA,
 };
 use solana_program::{
     account_info::AccountInfo,
-    instruction::{
-        AccountMeta,
-        Instruction,
-    },
+    instruction::AccountMeta,
     program::invoke,
     sysvar::clock::Clock,
 };
 use solitaire::{
-    processors::seeded::{
-        invoke_seeded,
-        Seeded,
-    },
+    processors::seeded::Seeded,
     *,
 };
 use spl_token_metadata::state::Metadata;
@@ -93,6 +91,9 @@ pub fn attest_token(
     accs: &mut AttestToken,
     data: AttestTokenData,
 ) -> Result<()> {
+    // Mints of either token program are accepted, extended Token-2022 layouts included.
+    token::verify_mint_extensions(accs.mint.info())?;
+
     // Pay fee
     let transfer_ix = solana_program::system_instruction::transfer(
         accs.payer.key,
@@ -132,31 +133,33 @@ pub fn attest_token(
         payload.symbol = metadata.data.symbol;
     }
 
-    let params = (
+    let post_message = CpiContext::new(
+        accs.config.wormhole_bridge,
+        PostMessageCpi {
+            bridge: accs.bridge.info().clone(),
+            message: accs.message.info().clone(),
+            emitter: accs.emitter.info().clone(),
+            sequence: accs.sequence.info().clone(),
+            payer: accs.payer.info().clone(),
+            fee_collector: accs.fee_collector.info().clone(),
+            clock: accs.clock.info().clone(),
+        },
+    )
+    .remaining(vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
+    ]);
+    post_message.invoke_seeded(
+        ctx,
         bridge::instruction::Instruction::PostMessage,
         PostMessageData {
             nonce: data.nonce,
             payload: payload.try_to_vec()?,
             consistency_level: ConsistencyLevel::Finalized,
         },
-    );
-
-    let ix = Instruction::new_with_bytes(
-        accs.config.wormhole_bridge,
-        params.try_to_vec()?.as_slice(),
-        vec![
-            AccountMeta::new(*accs.bridge.info().key, false),
-            AccountMeta::new(*accs.message.key, true),
-            AccountMeta::new_readonly(*accs.emitter.key, true),
-            AccountMeta::new(*accs.sequence.key, false),
-            AccountMeta::new(*accs.payer.key, true),
-            AccountMeta::new(*accs.fee_collector.key, false),
-            AccountMeta::new_readonly(*accs.clock.info().key, false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-            AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
-        ],
-    );
-    invoke_seeded(&ix, ctx, &accs.emitter, None)?;
+        &accs.emitter,
+        None,
+    )?;
 
     Ok(())
 }
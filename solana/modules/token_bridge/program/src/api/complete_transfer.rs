@@ -3,16 +3,38 @@ use crate::{
         ConfigAccount,
         CustodyAccount,
         CustodyAccountDerivationData,
+        CustodyLedgerAccount,
+        CustodyLedgerDerivationData,
         CustodySigner,
         Endpoint,
         EndpointDerivationData,
         MintSigner,
-        WrappedDerivationData,
+        PauseAccount,
+        TemporaryAccount,
+        TemporaryAccountDerivationData,
+        TransferLimitAccount,
+        TransferLimitDerivationData,
         WrappedMetaDerivationData,
         WrappedMint,
         WrappedTokenMeta,
     },
+    api::{
+        custody::{
+            find_ledger,
+            record_release,
+        },
+        governance::find_pause,
+        governor::{
+            find_transfer_limit,
+            verify_inbound,
+        },
+    },
+    events::{
+        emit,
+        TokenBridgeEvent,
+    },
     messages::PayloadTransfer,
+    token,
     types::*,
     TokenBridgeError::*,
     INVALID_VAAS,
@@ -21,7 +43,16 @@ use bridge::{
     vaa::ClaimableVAA,
     CHAIN_ID_SOLANA,
 };
-use solana_program::account_info::AccountInfo;
+use solana_program::{
+    account_info::AccountInfo,
+    program::invoke,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{
+        clock::Clock,
+        Sysvar as _,
+    },
+};
 use solitaire::{
     processors::seeded::{
         invoke_seeded,
@@ -38,29 +69,20 @@ pub struct CompleteNative<'b> {
     pub vaa: ClaimableVAA<'b, PayloadTransfer>,
     pub chain_registration: Endpoint<'b, { AccountState::Initialized }>,
 
+    #[constraint(accs.to.mint == *accs.mint.info().key, InvalidMint)]
     pub to: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
+
+    #[constraint(accs.to_fees.mint == *accs.mint.info().key, InvalidMint)]
     pub to_fees: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
     pub custody: Mut<CustodyAccount<'b, { AccountState::Initialized }>>,
+
     pub mint: Data<'b, SplMint, { AccountState::Initialized }>,
 
     pub custody_signer: CustodySigner<'b>,
-}
 
-impl<'a> From<&CompleteNative<'a>> for EndpointDerivationData {
-    fn from(accs: &CompleteNative<'a>) -> Self {
-        EndpointDerivationData {
-            emitter_chain: accs.vaa.meta().emitter_chain,
-            emitter_address: accs.vaa.meta().emitter_address,
-        }
-    }
-}
-
-impl<'a> From<&CompleteNative<'a>> for CustodyAccountDerivationData {
-    fn from(accs: &CompleteNative<'a>) -> Self {
-        CustodyAccountDerivationData {
-            mint: *accs.mint.info().key,
-        }
-    }
+    /// Accounts added after the original layout, found by key: the pause config, the ledger of the
+    /// mint and its transfer limit, see [`find_pause`], [`find_ledger`] and [`verify_inbound`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Default)]
@@ -71,168 +93,728 @@ pub fn complete_native(
     accs: &mut CompleteNative,
     _data: CompleteNativeData,
 ) -> Result<()> {
-    // Verify the chain registration
-    let derivation_data: EndpointDerivationData = (&*accs).into();
-    accs.chain_registration
-        .verify_derivation(ctx.program_id, &derivation_data)?;
+    verify_recipient(
+        &accs.vaa,
+        accs.to.info().key,
+        &accs.to.owner,
+        accs.mint.info(),
+    )?;
 
-    // Verify that the custody account is derived correctly
-    let derivation_data: CustodyAccountDerivationData = (&*accs).into();
-    accs.custody
-        .verify_derivation(ctx.program_id, &derivation_data)?;
+    let mut ledger = find_ledger(ctx, &accs.trailing, accs.mint.info().key)?;
+    let mut transfer_limit = find_transfer_limit(ctx, &accs.trailing, accs.mint.info().key)?;
+    let (amount, fee) = claim_native(
+        ctx,
+        accs.payer.key,
+        &find_pause(ctx, &accs.trailing)?,
+        &mut accs.vaa,
+        &accs.chain_registration,
+        &accs.custody,
+        &mut ledger,
+        &accs.mint,
+        &accs.custody_signer,
+        &Clock::get()?,
+        &mut transfer_limit,
+    )?;
+    Peel::persist(&ledger, ctx.program_id)?;
+    Peel::persist(&transfer_limit, ctx.program_id)?;
 
-    // Verify mints
-    if *accs.mint.info().key != accs.to.mint {
-        return Err(InvalidMint.into());
-    }
-    if *accs.mint.info().key != accs.to_fees.mint {
-        return Err(InvalidMint.into());
-    }
-    if *accs.mint.info().key != accs.custody.mint {
-        return Err(InvalidMint.into());
-    }
-    if *accs.custody_signer.key != accs.custody.owner {
-        return Err(WrongAccountOwner.into());
-    }
+    // Transfer tokens
+    let transfer_ix = token::transfer_checked(
+        accs.mint.info().owner,
+        accs.custody.info().key,
+        accs.mint.info().key,
+        accs.to.info().key,
+        accs.custody_signer.key,
+        amount.checked_sub(fee).ok_or(InvalidFee)?,
+        accs.mint.decimals,
+    )?;
+    invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
 
-    // Verify VAA
-    if accs.vaa.token_address != accs.mint.info().key.to_bytes() {
-        return Err(InvalidMint.into());
-    }
-    if accs.vaa.token_chain != 1 {
-        return Err(InvalidChain.into());
-    }
-    if accs.vaa.to_chain != CHAIN_ID_SOLANA {
-        return Err(InvalidChain.into());
+    // Transfer fees
+    if fee > 0 {
+        let transfer_ix = token::transfer_checked(
+            accs.mint.info().owner,
+            accs.custody.info().key,
+            accs.mint.info().key,
+            accs.to_fees.info().key,
+            accs.custody_signer.key,
+            fee,
+            accs.mint.decimals,
+        )?;
+        invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
     }
-    if accs.vaa.to != accs.to.info().key.to_bytes() {
-        return Err(InvalidRecipient.into());
+
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: accs.vaa.fee.as_u64(),
+        bridged_amount: amount,
+    })?;
+
+    Ok(())
+}
+
+/// Redemption by a relayer, who collects the fee in the associated token account of
+/// `fee_recipient`. Transfers do not name their relayer, so any relayer can redeem them.
+#[derive(FromAccounts)]
+pub struct CompleteNativeRelayed<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(None)]
+    pub pause: PauseAccount<'b, { AccountState::MaybeInitialized }>,
+
+    pub vaa: ClaimableVAA<'b, PayloadTransfer>,
+    pub chain_registration: Endpoint<'b, { AccountState::Initialized }>,
+
+    /// Token account of `to_owner`, created as its associated token account if missing
+    pub to: Mut<Data<'b, SplAccount, { AccountState::MaybeInitialized }>>,
+    pub to_owner: Info<'b>,
+
+    /// Wallet collecting the relayer fee
+    pub fee_recipient: Info<'b>,
+
+    /// Associated token account of `fee_recipient`, created by the payer if missing
+    pub to_fees: Mut<Data<'b, SplAccount, { AccountState::MaybeInitialized }>>,
+    pub custody: Mut<CustodyAccount<'b, { AccountState::Initialized }>>,
+
+    /// Tokens of the mint owed to other chains, see [`record_release`]
+    #[seeds]
+    pub ledger: Mut<CustodyLedgerAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    pub mint: Data<'b, SplMint, { AccountState::Initialized }>,
+
+    pub custody_signer: CustodySigner<'b>,
+
+    pub clock: Sysvar<'b, Clock>,
+
+    /// Governor limits of the mint, see [`verify_inbound`]
+    #[seeds]
+    pub transfer_limit: Mut<TransferLimitAccount<'b, { AccountState::MaybeInitialized }>>,
+}
+
+impl<'a> From<&CompleteNativeRelayed<'a>> for CustodyLedgerDerivationData {
+    fn from(accs: &CompleteNativeRelayed<'a>) -> Self {
+        CustodyLedgerDerivationData {
+            mint: *accs.mint.info().key,
+        }
     }
-    if INVALID_VAAS.contains(&&*accs.vaa.message.info().key.to_string()) {
-        return Err(InvalidVAA.into());
+}
+
+impl<'a> From<&CompleteNativeRelayed<'a>> for TransferLimitDerivationData {
+    fn from(accs: &CompleteNativeRelayed<'a>) -> Self {
+        TransferLimitDerivationData {
+            mint: *accs.mint.info().key,
+        }
     }
+}
 
-    // Prevent vaa double signing
-    accs.vaa.verify(ctx.program_id)?;
-    accs.vaa.claim(ctx, accs.payer.key)?;
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteNativeRelayedData {}
 
-    let mut amount = accs.vaa.amount.as_u64();
-    let mut fee = accs.vaa.fee.as_u64();
+pub fn complete_native_relayed(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteNativeRelayed,
+    _data: CompleteNativeRelayedData,
+) -> Result<()> {
+    prepare_recipient(
+        ctx,
+        accs.payer.key,
+        &accs.vaa,
+        &accs.to,
+        accs.to_owner.key,
+        accs.mint.info(),
+    )?;
 
-    // Wormhole always caps transfers at 8 decimals; un-truncate if the local token has more
-    if accs.mint.decimals > 8 {
-        amount *= 10u64.pow((accs.mint.decimals - 8) as u32);
-        fee *= 10u64.pow((accs.mint.decimals - 8) as u32);
-    }
+    let (amount, fee) = claim_native(
+        ctx,
+        accs.payer.key,
+        &accs.pause,
+        &mut accs.vaa,
+        &accs.chain_registration,
+        &accs.custody,
+        &mut accs.ledger,
+        &accs.mint,
+        &accs.custody_signer,
+        &accs.clock,
+        &mut accs.transfer_limit,
+    )?;
 
     // Transfer tokens
-    let transfer_ix = spl_token::instruction::transfer(
-        &spl_token::id(),
+    let transfer_ix = token::transfer_checked(
+        accs.mint.info().owner,
         accs.custody.info().key,
+        accs.mint.info().key,
         accs.to.info().key,
         accs.custody_signer.key,
-        &[],
-        amount.checked_sub(fee).unwrap(),
+        amount.checked_sub(fee).ok_or(InvalidFee)?,
+        accs.mint.decimals,
     )?;
     invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
 
     // Transfer fees
-    let transfer_ix = spl_token::instruction::transfer(
-        &spl_token::id(),
+    if fee > 0 {
+        token::create_associated_account(
+            ctx,
+            accs.payer.key,
+            accs.fee_recipient.key,
+            accs.mint.info(),
+            accs.to_fees.info(),
+        )?;
+        let transfer_ix = token::transfer_checked(
+            accs.mint.info().owner,
+            accs.custody.info().key,
+            accs.mint.info().key,
+            accs.to_fees.info().key,
+            accs.custody_signer.key,
+            fee,
+            accs.mint.decimals,
+        )?;
+        invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
+    }
+
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: accs.vaa.fee.as_u64(),
+        bridged_amount: amount,
+    })?;
+
+    Ok(())
+}
+
+/// Redemption by the owner of the recipient account, who keeps the relayer fee.
+#[derive(FromAccounts)]
+pub struct CompleteNativeByRecipient<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(None)]
+    pub pause: PauseAccount<'b, { AccountState::MaybeInitialized }>,
+
+    pub vaa: ClaimableVAA<'b, PayloadTransfer>,
+    pub chain_registration: Endpoint<'b, { AccountState::Initialized }>,
+
+    #[constraint(accs.to.owner == *accs.to_owner.key, WrongAccountOwner)]
+    #[constraint(accs.to.mint == *accs.mint.info().key, InvalidMint)]
+    pub to: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
+    pub to_owner: Signer<Info<'b>>,
+    pub custody: Mut<CustodyAccount<'b, { AccountState::Initialized }>>,
+
+    /// Tokens of the mint owed to other chains, see [`record_release`]
+    #[seeds]
+    pub ledger: Mut<CustodyLedgerAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    pub mint: Data<'b, SplMint, { AccountState::Initialized }>,
+
+    pub custody_signer: CustodySigner<'b>,
+
+    pub clock: Sysvar<'b, Clock>,
+
+    /// Governor limits of the mint, see [`verify_inbound`]
+    #[seeds]
+    pub transfer_limit: Mut<TransferLimitAccount<'b, { AccountState::MaybeInitialized }>>,
+}
+
+impl<'a> From<&CompleteNativeByRecipient<'a>> for CustodyLedgerDerivationData {
+    fn from(accs: &CompleteNativeByRecipient<'a>) -> Self {
+        CustodyLedgerDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+impl<'a> From<&CompleteNativeByRecipient<'a>> for TransferLimitDerivationData {
+    fn from(accs: &CompleteNativeByRecipient<'a>) -> Self {
+        TransferLimitDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteNativeByRecipientData {}
+
+pub fn complete_native_by_recipient(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteNativeByRecipient,
+    _data: CompleteNativeByRecipientData,
+) -> Result<()> {
+    verify_recipient(
+        &accs.vaa,
+        accs.to.info().key,
+        accs.to_owner.key,
+        accs.mint.info(),
+    )?;
+
+    let (amount, _fee) = claim_native(
+        ctx,
+        accs.payer.key,
+        &accs.pause,
+        &mut accs.vaa,
+        &accs.chain_registration,
+        &accs.custody,
+        &mut accs.ledger,
+        &accs.mint,
+        &accs.custody_signer,
+        &accs.clock,
+        &mut accs.transfer_limit,
+    )?;
+
+    // Transfer tokens, including the fee nobody relayed for
+    let transfer_ix = token::transfer_checked(
+        accs.mint.info().owner,
         accs.custody.info().key,
-        accs.to_fees.info().key,
+        accs.mint.info().key,
+        accs.to.info().key,
         accs.custody_signer.key,
-        &[],
-        fee,
+        amount,
+        accs.mint.decimals,
     )?;
     invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
 
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: accs.vaa.fee.as_u64(),
+        bridged_amount: amount,
+    })?;
+
     Ok(())
 }
 
+/// Redemption of wrapped SOL straight to a wallet, unwrapped through a temporary token account.
 #[derive(FromAccounts)]
-pub struct CompleteWrapped<'b> {
+pub struct CompleteNativeSol<'b> {
+    /// Fronts the rent of the temporary account and forwards the unwrapped SOL
     pub payer: Mut<Signer<AccountInfo<'b>>>,
     pub config: ConfigAccount<'b, { AccountState::Initialized }>,
 
-    // Signed message for the transfer
-    pub vaa: ClaimableVAA<'b, PayloadTransfer>,
+    #[seeds(None)]
+    pub pause: PauseAccount<'b, { AccountState::MaybeInitialized }>,
 
+    pub vaa: ClaimableVAA<'b, PayloadTransfer>,
     pub chain_registration: Endpoint<'b, { AccountState::Initialized }>,
 
-    pub to: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
-    pub to_fees: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
-    pub mint: Mut<WrappedMint<'b, { AccountState::Initialized }>>,
-    pub wrapped_meta: WrappedTokenMeta<'b, { AccountState::Initialized }>,
+    /// Wallet receiving the SOL. Lamports sent to accounts of other programs could be stuck there.
+    #[constraint(*accs.to.owner == system_program::id(), InvalidRecipient)]
+    pub to: Mut<Info<'b>>,
 
-    pub mint_authority: MintSigner<'b>,
+    /// Wallet collecting the relayer fee
+    pub fee_recipient: Mut<Info<'b>>,
+
+    #[seeds(&TemporaryAccountDerivationData { message: *accs.vaa.info().key })]
+    pub temporary: Mut<TemporaryAccount<'b, { AccountState::Uninitialized }>>,
+    pub custody: Mut<CustodyAccount<'b, { AccountState::Initialized }>>,
+
+    /// Tokens of the mint owed to other chains, see [`record_release`]
+    #[seeds]
+    pub ledger: Mut<CustodyLedgerAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    #[constraint(*accs.mint.info().key == spl_token::native_mint::id(), InvalidMint)]
+    pub mint: Data<'b, SplMint, { AccountState::Initialized }>,
+
+    pub custody_signer: CustodySigner<'b>,
+
+    pub clock: Sysvar<'b, Clock>,
+
+    /// Governor limits of the mint, see [`verify_inbound`]
+    #[seeds]
+    pub transfer_limit: Mut<TransferLimitAccount<'b, { AccountState::MaybeInitialized }>>,
 }
 
-impl<'a> From<&CompleteWrapped<'a>> for EndpointDerivationData {
-    fn from(accs: &CompleteWrapped<'a>) -> Self {
-        EndpointDerivationData {
-            emitter_chain: accs.vaa.meta().emitter_chain,
-            emitter_address: accs.vaa.meta().emitter_address,
+impl<'a> From<&CompleteNativeSol<'a>> for TemporaryAccountDerivationData {
+    fn from(accs: &CompleteNativeSol<'a>) -> Self {
+        TemporaryAccountDerivationData {
+            message: *accs.vaa.info().key,
+        }
+    }
+}
+
+impl<'a> From<&CompleteNativeSol<'a>> for CustodyLedgerDerivationData {
+    fn from(accs: &CompleteNativeSol<'a>) -> Self {
+        CustodyLedgerDerivationData {
+            mint: *accs.mint.info().key,
         }
     }
 }
 
-impl<'a> From<&CompleteWrapped<'a>> for WrappedDerivationData {
-    fn from(accs: &CompleteWrapped<'a>) -> Self {
-        WrappedDerivationData {
-            token_chain: accs.vaa.token_chain,
-            token_address: accs.vaa.token_address,
+impl<'a> From<&CompleteNativeSol<'a>> for TransferLimitDerivationData {
+    fn from(accs: &CompleteNativeSol<'a>) -> Self {
+        TransferLimitDerivationData {
+            mint: *accs.mint.info().key,
         }
     }
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Default)]
-pub struct CompleteWrappedData {}
+pub struct CompleteNativeSolData {}
 
-pub fn complete_wrapped(
+pub fn complete_native_sol(
     ctx: &ExecutionContext,
-    accs: &mut CompleteWrapped,
-    _data: CompleteWrappedData,
+    accs: &mut CompleteNativeSol,
+    _data: CompleteNativeSolData,
+) -> Result<()> {
+    verify_recipient(&accs.vaa, accs.to.key, accs.to.key, accs.mint.info())?;
+
+    let (amount, fee) = claim_native(
+        ctx,
+        accs.payer.key,
+        &accs.pause,
+        &mut accs.vaa,
+        &accs.chain_registration,
+        &accs.custody,
+        &mut accs.ledger,
+        &accs.mint,
+        &accs.custody_signer,
+        &accs.clock,
+        &mut accs.transfer_limit,
+    )?;
+
+    // Move the wrapped SOL out of custody into a temporary account
+    let derivation_data: TemporaryAccountDerivationData = (&*accs).into();
+    let seeds = accs
+        .temporary
+        .self_bumped_seeds(&derivation_data, ctx.program_id);
+    token::create_token_account(
+        ctx,
+        accs.payer.key,
+        accs.temporary.info(),
+        &seeds,
+        accs.mint.info(),
+        accs.custody_signer.key,
+    )?;
+    let transfer_ix = token::transfer_checked(
+        accs.mint.info().owner,
+        accs.custody.info().key,
+        accs.mint.info().key,
+        accs.temporary.info().key,
+        accs.custody_signer.key,
+        amount,
+        accs.mint.decimals,
+    )?;
+    invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
+
+    // Closing unwraps the SOL to the payer, together with the rent it fronted
+    let close_ix = token::close_account(
+        accs.mint.info().owner,
+        accs.temporary.info().key,
+        accs.payer.key,
+        accs.custody_signer.key,
+    )?;
+    invoke_seeded(&close_ix, ctx, &accs.custody_signer, None)?;
+
+    // Pay out the SOL
+    let transfer_ix = solana_program::system_instruction::transfer(
+        accs.payer.key,
+        accs.to.key,
+        amount.checked_sub(fee).ok_or(InvalidFee)?,
+    );
+    invoke(&transfer_ix, ctx.accounts)?;
+
+    // Pay out the fees
+    if fee > 0 {
+        let transfer_ix = solana_program::system_instruction::transfer(
+            accs.payer.key,
+            accs.fee_recipient.key,
+            fee,
+        );
+        invoke(&transfer_ix, ctx.accounts)?;
+    }
+
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: accs.vaa.fee.as_u64(),
+        bridged_amount: amount,
+    })?;
+
+    Ok(())
+}
+
+/// Transfers may name either the recipient token account or the wallet owning it. Transfers naming
+/// the wallet are only paid out to its associated token account of `mint`.
+fn verify_recipient(
+    vaa: &ClaimableVAA<PayloadTransfer>,
+    to: &Pubkey,
+    to_owner: &Pubkey,
+    mint: &AccountInfo,
+) -> Result<()> {
+    if vaa.to == to.to_bytes() {
+        return Ok(());
+    }
+    if vaa.to != to_owner.to_bytes() {
+        return Err(InvalidRecipient.into());
+    }
+    if *to != token::associated_address(to_owner, mint.key, mint.owner) {
+        return Err(InvalidTokenAccount.into());
+    }
+    Ok(())
+}
+
+/// Verify the recipient of a relayed redemption. A missing `to` account is created as the
+/// associated token account of `to_owner`, paid for by `payer`. The owner of an existing account
+/// only matters if the transfer names the wallet rather than the account.
+fn prepare_recipient(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    vaa: &ClaimableVAA<PayloadTransfer>,
+    to: &Mut<Data<SplAccount, { AccountState::MaybeInitialized }>>,
+    to_owner: &Pubkey,
+    mint: &AccountInfo,
 ) -> Result<()> {
+    verify_recipient(vaa, to.info().key, to_owner, mint)?;
+
+    if to.info().data_is_empty() {
+        return token::create_associated_account(ctx, payer, to_owner, mint, to.info());
+    }
+    if to.mint != *mint.key {
+        return Err(InvalidMint.into());
+    }
+    if vaa.to != to.info().key.to_bytes() && to.owner != *to_owner {
+        return Err(WrongAccountOwner.into());
+    }
+    Ok(())
+}
+
+/// Checks shared by the native redemption paths, the recipient is checked by the callers. Claims
+/// the VAA and returns the amount and fee in units of the mint.
+#[allow(clippy::too_many_arguments)]
+fn claim_native(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    pause: &PauseConfig,
+    vaa: &mut ClaimableVAA<PayloadTransfer>,
+    chain_registration: &Endpoint<{ AccountState::Initialized }>,
+    custody: &Mut<CustodyAccount<{ AccountState::Initialized }>>,
+    ledger: &mut Mut<CustodyLedgerAccount<{ AccountState::MaybeInitialized }>>,
+    mint: &Data<SplMint, { AccountState::Initialized }>,
+    custody_signer: &CustodySigner,
+    clock: &Clock,
+    transfer_limit: &mut Mut<TransferLimitAccount<{ AccountState::MaybeInitialized }>>,
+) -> Result<(u64, u64)> {
     // Verify the chain registration
-    let derivation_data: EndpointDerivationData = (&*accs).into();
-    accs.chain_registration
-        .verify_derivation(ctx.program_id, &derivation_data)?;
+    chain_registration.verify_derivation(
+        ctx.program_id,
+        &EndpointDerivationData {
+            emitter_chain: vaa.meta().emitter_chain,
+            emitter_address: vaa.meta().emitter_address,
+        },
+    )?;
 
-    // Verify mint
-    accs.wrapped_meta.verify_derivation(
+    // Verify that the custody account is derived correctly
+    custody.verify_derivation(
         ctx.program_id,
-        &WrappedMetaDerivationData {
-            mint_key: *accs.mint.info().key,
+        &CustodyAccountDerivationData {
+            mint: *mint.info().key,
         },
     )?;
-    if accs.wrapped_meta.token_address != accs.vaa.token_address
-        || accs.wrapped_meta.chain != accs.vaa.token_chain
-    {
-        return Err(InvalidMint.into());
-    }
 
     // Verify mints
-    if *accs.mint.info().key != accs.to.mint {
+    if *mint.info().key != custody.mint {
         return Err(InvalidMint.into());
     }
-    if *accs.mint.info().key != accs.to_fees.mint {
-        return Err(InvalidMint.into());
+    if *custody_signer.key != custody.owner {
+        return Err(WrongAccountOwner.into());
     }
 
     // Verify VAA
-    if accs.vaa.to_chain != CHAIN_ID_SOLANA {
+    if vaa.token_address != mint.info().key.to_bytes() {
+        return Err(InvalidMint.into());
+    }
+    if vaa.token_chain != 1 {
         return Err(InvalidChain.into());
     }
-    if accs.vaa.to != accs.to.info().key.to_bytes() {
-        return Err(InvalidRecipient.into());
+    if vaa.to_chain != CHAIN_ID_SOLANA {
+        return Err(InvalidChain.into());
     }
-    if INVALID_VAAS.contains(&&*accs.vaa.message.info().key.to_string()) {
+
+    if pause.inbound_paused(mint.info().key, vaa.meta().emitter_chain) {
+        return Err(TransfersPaused.into());
+    }
+
+    // Transfers over the governor limit fail and can be redeemed once the window moved on.
+    verify_inbound(transfer_limit, clock, vaa.amount.as_u64())?;
+
+    if INVALID_VAAS.contains(&&*vaa.message.info().key.to_string()) {
         return Err(InvalidVAA.into());
     }
 
-    accs.vaa.verify(ctx.program_id)?;
-    accs.vaa.claim(ctx, accs.payer.key)?;
+    // Prevent vaa double signing
+    vaa.verify(ctx.program_id)?;
+    vaa.claim(ctx, payer)?;
+
+    // Wormhole always caps transfers at 8 decimals; un-truncate if the local token has more
+    let amount = token::denormalize(vaa.amount.as_u64(), mint.decimals)?;
+    let fee = token::denormalize(vaa.fee.as_u64(), mint.decimals)?;
+    record_release(ctx, payer, ledger, mint.info().key, custody.amount, amount)?;
+
+    Ok((amount, fee))
+}
+
+#[derive(FromAccounts)]
+pub struct CompleteWrapped<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    // Signed message for the transfer
+    pub vaa: ClaimableVAA<'b, PayloadTransfer>,
+
+    pub chain_registration: Endpoint<'b, { AccountState::Initialized }>,
+
+    #[constraint(accs.to.mint == *accs.mint.info().key, InvalidMint)]
+    pub to: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
+
+    #[constraint(accs.to_fees.mint == *accs.mint.info().key, InvalidMint)]
+    pub to_fees: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
+    pub mint: Mut<WrappedMint<'b, { AccountState::Initialized }>>,
+    pub wrapped_meta: WrappedTokenMeta<'b, { AccountState::Initialized }>,
+
+    pub mint_authority: MintSigner<'b>,
+
+    /// Accounts added after the original layout, found by key: the pause config, see [`find_pause`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteWrappedData {}
+
+pub fn complete_wrapped(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteWrapped,
+    _data: CompleteWrappedData,
+) -> Result<()> {
+    verify_recipient(
+        &accs.vaa,
+        accs.to.info().key,
+        &accs.to.owner,
+        accs.mint.info(),
+    )?;
+
+    claim_wrapped(
+        ctx,
+        accs.payer.key,
+        &find_pause(ctx, &accs.trailing)?,
+        &mut accs.vaa,
+        &accs.chain_registration,
+        &accs.mint,
+        &accs.wrapped_meta,
+    )?;
+
+    // Mint tokens
+    let mint_ix = spl_token::instruction::mint_to(
+        &spl_token::id(),
+        accs.mint.info().key,
+        accs.to.info().key,
+        accs.mint_authority.key,
+        &[],
+        accs.vaa
+            .amount
+            .as_u64()
+            .checked_sub(accs.vaa.fee.as_u64())
+            .ok_or(InvalidFee)?,
+    )?;
+    invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
+
+    // Mint fees
+    if accs.vaa.fee.as_u64() > 0 {
+        let mint_ix = spl_token::instruction::mint_to(
+            &spl_token::id(),
+            accs.mint.info().key,
+            accs.to_fees.info().key,
+            accs.mint_authority.key,
+            &[],
+            accs.vaa.fee.as_u64(),
+        )?;
+        invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
+    }
+
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: accs.vaa.fee.as_u64(),
+        bridged_amount: accs.vaa.amount.as_u64(),
+    })?;
+
+    Ok(())
+}
+
+/// Redemption by a relayer, who collects the fee in the associated token account of
+/// `fee_recipient`. Transfers do not name their relayer, so any relayer can redeem them.
+#[derive(FromAccounts)]
+pub struct CompleteWrappedRelayed<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(None)]
+    pub pause: PauseAccount<'b, { AccountState::MaybeInitialized }>,
+
+    // Signed message for the transfer
+    pub vaa: ClaimableVAA<'b, PayloadTransfer>,
+
+    pub chain_registration: Endpoint<'b, { AccountState::Initialized }>,
+
+    /// Token account of `to_owner`, created as its associated token account if missing
+    pub to: Mut<Data<'b, SplAccount, { AccountState::MaybeInitialized }>>,
+    pub to_owner: Info<'b>,
+
+    /// Wallet collecting the relayer fee
+    pub fee_recipient: Info<'b>,
+
+    /// Associated token account of `fee_recipient`, created by the payer if missing
+    pub to_fees: Mut<Data<'b, SplAccount, { AccountState::MaybeInitialized }>>,
+    pub mint: Mut<WrappedMint<'b, { AccountState::Initialized }>>,
+    pub wrapped_meta: WrappedTokenMeta<'b, { AccountState::Initialized }>,
+
+    pub mint_authority: MintSigner<'b>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteWrappedRelayedData {}
+
+pub fn complete_wrapped_relayed(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteWrappedRelayed,
+    _data: CompleteWrappedRelayedData,
+) -> Result<()> {
+    prepare_recipient(
+        ctx,
+        accs.payer.key,
+        &accs.vaa,
+        &accs.to,
+        accs.to_owner.key,
+        accs.mint.info(),
+    )?;
+
+    claim_wrapped(
+        ctx,
+        accs.payer.key,
+        &accs.pause,
+        &mut accs.vaa,
+        &accs.chain_registration,
+        &accs.mint,
+        &accs.wrapped_meta,
+    )?;
 
     // Mint tokens
     let mint_ix = spl_token::instruction::mint_to(
@@ -245,20 +827,163 @@ pub fn complete_wrapped(
             .amount
             .as_u64()
             .checked_sub(accs.vaa.fee.as_u64())
-            .unwrap(),
+            .ok_or(InvalidFee)?,
     )?;
     invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
 
     // Mint fees
+    if accs.vaa.fee.as_u64() > 0 {
+        token::create_associated_account(
+            ctx,
+            accs.payer.key,
+            accs.fee_recipient.key,
+            accs.mint.info(),
+            accs.to_fees.info(),
+        )?;
+        let mint_ix = spl_token::instruction::mint_to(
+            &spl_token::id(),
+            accs.mint.info().key,
+            accs.to_fees.info().key,
+            accs.mint_authority.key,
+            &[],
+            accs.vaa.fee.as_u64(),
+        )?;
+        invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
+    }
+
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: accs.vaa.fee.as_u64(),
+        bridged_amount: accs.vaa.amount.as_u64(),
+    })?;
+
+    Ok(())
+}
+
+/// Redemption by the owner of the recipient account, who keeps the relayer fee.
+#[derive(FromAccounts)]
+pub struct CompleteWrappedByRecipient<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(None)]
+    pub pause: PauseAccount<'b, { AccountState::MaybeInitialized }>,
+
+    // Signed message for the transfer
+    pub vaa: ClaimableVAA<'b, PayloadTransfer>,
+
+    pub chain_registration: Endpoint<'b, { AccountState::Initialized }>,
+
+    #[constraint(accs.to.owner == *accs.to_owner.key, WrongAccountOwner)]
+    #[constraint(accs.to.mint == *accs.mint.info().key, InvalidMint)]
+    pub to: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
+    pub to_owner: Signer<Info<'b>>,
+    pub mint: Mut<WrappedMint<'b, { AccountState::Initialized }>>,
+    pub wrapped_meta: WrappedTokenMeta<'b, { AccountState::Initialized }>,
+
+    pub mint_authority: MintSigner<'b>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteWrappedByRecipientData {}
+
+pub fn complete_wrapped_by_recipient(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteWrappedByRecipient,
+    _data: CompleteWrappedByRecipientData,
+) -> Result<()> {
+    verify_recipient(
+        &accs.vaa,
+        accs.to.info().key,
+        accs.to_owner.key,
+        accs.mint.info(),
+    )?;
+
+    claim_wrapped(
+        ctx,
+        accs.payer.key,
+        &accs.pause,
+        &mut accs.vaa,
+        &accs.chain_registration,
+        &accs.mint,
+        &accs.wrapped_meta,
+    )?;
+
+    // Mint tokens, including the fee nobody relayed for
     let mint_ix = spl_token::instruction::mint_to(
         &spl_token::id(),
         accs.mint.info().key,
-        accs.to_fees.info().key,
+        accs.to.info().key,
         accs.mint_authority.key,
         &[],
-        accs.vaa.fee.as_u64(),
+        accs.vaa.amount.as_u64(),
     )?;
     invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
 
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: accs.vaa.fee.as_u64(),
+        bridged_amount: accs.vaa.amount.as_u64(),
+    })?;
+
+    Ok(())
+}
+
+/// Checks shared by the wrapped redemption paths, claims the VAA.
+#[allow(clippy::too_many_arguments)]
+fn claim_wrapped(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    pause: &PauseConfig,
+    vaa: &mut ClaimableVAA<PayloadTransfer>,
+    chain_registration: &Endpoint<{ AccountState::Initialized }>,
+    mint: &Mut<WrappedMint<{ AccountState::Initialized }>>,
+    wrapped_meta: &WrappedTokenMeta<{ AccountState::Initialized }>,
+) -> Result<()> {
+    // Verify the chain registration
+    chain_registration.verify_derivation(
+        ctx.program_id,
+        &EndpointDerivationData {
+            emitter_chain: vaa.meta().emitter_chain,
+            emitter_address: vaa.meta().emitter_address,
+        },
+    )?;
+
+    // Verify mint
+    wrapped_meta.verify_derivation(
+        ctx.program_id,
+        &WrappedMetaDerivationData {
+            mint_key: *mint.info().key,
+        },
+    )?;
+    if wrapped_meta.token_address != vaa.token_address || wrapped_meta.chain != vaa.token_chain {
+        return Err(InvalidMint.into());
+    }
+
+    // Verify VAA
+    if vaa.to_chain != CHAIN_ID_SOLANA {
+        return Err(InvalidChain.into());
+    }
+
+    if pause.inbound_paused(mint.info().key, vaa.meta().emitter_chain) {
+        return Err(TransfersPaused.into());
+    }
+    if INVALID_VAAS.contains(&&*vaa.message.info().key.to_string()) {
+        return Err(InvalidVAA.into());
+    }
+
+    vaa.verify(ctx.program_id)?;
+    vaa.claim(ctx, payer)?;
+
     Ok(())
 }
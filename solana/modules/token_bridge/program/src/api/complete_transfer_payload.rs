@@ -12,7 +12,23 @@ use crate::{
         WrappedMint,
         WrappedTokenMeta,
     },
+    api::{
+        custody::{
+            find_ledger,
+            record_release,
+        },
+        governance::find_pause,
+        governor::{
+            find_transfer_limit,
+            verify_inbound,
+        },
+    },
+    events::{
+        emit,
+        TokenBridgeEvent,
+    },
     messages::PayloadTransferWithPayload,
+    token,
     types::*,
     TokenBridgeError::*,
 };
@@ -20,7 +36,13 @@ use bridge::{
     vaa::ClaimableVAA,
     CHAIN_ID_SOLANA,
 };
-use solana_program::account_info::AccountInfo;
+use solana_program::{
+    account_info::AccountInfo,
+    sysvar::{
+        clock::Clock,
+        Sysvar as _,
+    },
+};
 use solitaire::{
     processors::seeded::{
         invoke_seeded,
@@ -48,6 +70,10 @@ impl<'a, 'b: 'a, 'c> Peel<'a, 'b, 'c> for RedeemerAccount<'b> {
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         MaybeMut::persist(&self.0, program_id)
     }
+
+    fn describe(name: &str) -> Vec<solitaire::idl::IdlAccount> {
+        MaybeMut::<Signer<Info<'b>>>::describe(name)
+    }
 }
 
 // May or may not be a PDA, so we don't use [`Derive`], instead implement
@@ -82,7 +108,7 @@ impl<'b> RedeemerAccount<'b> {
     /// The `vaa.to` account must own the token account.
     fn verify_recipient_address(&self, recipient: &Pubkey) -> Result<()> {
         if recipient == self.info().key {
-            return Ok(());
+            Ok(())
         } else {
             self.verify_derivation(recipient, ())
         }
@@ -103,9 +129,14 @@ pub struct CompleteNativeWithPayload<'b> {
     pub redeemer: RedeemerAccount<'b>,
     pub to_fees: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
     pub custody: Mut<CustodyAccount<'b, { AccountState::Initialized }>>,
+
     pub mint: Data<'b, SplMint, { AccountState::Initialized }>,
 
     pub custody_signer: CustodySigner<'b>,
+
+    /// Accounts added after the original layout, found by key: the pause config, the ledger of the
+    /// mint and its transfer limit, see [`find_pause`], [`find_ledger`] and [`verify_inbound`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 impl<'a> From<&CompleteNativeWithPayload<'a>> for EndpointDerivationData {
@@ -178,28 +209,60 @@ pub fn complete_native_with_payload(
         return Err(InvalidRecipient.into());
     }
 
+    let pause = find_pause(ctx, &accs.trailing)?;
+    if pause.inbound_paused(accs.mint.info().key, accs.vaa.meta().emitter_chain) {
+        return Err(TransfersPaused.into());
+    }
+
+    // Transfers over the governor limit fail and can be redeemed once the window moved on.
+    let mut transfer_limit = find_transfer_limit(ctx, &accs.trailing, accs.mint.info().key)?;
+    verify_inbound(
+        &mut transfer_limit,
+        &Clock::get()?,
+        accs.vaa.amount.as_u64(),
+    )?;
+    Peel::persist(&transfer_limit, ctx.program_id)?;
+
     // Prevent vaa double signing
     accs.vaa.verify(ctx.program_id)?;
     accs.vaa.claim(ctx, accs.payer.key)?;
 
-    let mut amount = accs.vaa.amount.as_u64();
-
     // Wormhole always caps transfers at 8 decimals; un-truncate if the local token has more
-    if accs.mint.decimals > 8 {
-        amount *= 10u64.pow((accs.mint.decimals - 8) as u32);
-    }
+    let amount = token::denormalize(accs.vaa.amount.as_u64(), accs.mint.decimals)?;
+    let mut ledger = find_ledger(ctx, &accs.trailing, accs.mint.info().key)?;
+    record_release(
+        ctx,
+        accs.payer.key,
+        &mut ledger,
+        accs.mint.info().key,
+        accs.custody.amount,
+        amount,
+    )?;
+    Peel::persist(&ledger, ctx.program_id)?;
 
     // Transfer tokens
-    let transfer_ix = spl_token::instruction::transfer(
-        &spl_token::id(),
+    let transfer_ix = token::transfer_checked(
+        accs.mint.info().owner,
         accs.custody.info().key,
+        accs.mint.info().key,
         accs.to.info().key,
         accs.custody_signer.key,
-        &[],
         amount,
+        accs.mint.decimals,
     )?;
     invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
 
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: 0,
+        bridged_amount: amount,
+    })?;
+
     Ok(())
 }
 
@@ -222,6 +285,9 @@ pub struct CompleteWrappedWithPayload<'b> {
     pub wrapped_meta: WrappedTokenMeta<'b, { AccountState::Initialized }>,
 
     pub mint_authority: MintSigner<'b>,
+
+    /// Accounts added after the original layout, found by key: the pause config, see [`find_pause`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 impl<'a> From<&CompleteWrappedWithPayload<'a>> for EndpointDerivationData {
@@ -291,6 +357,11 @@ pub fn complete_wrapped_with_payload(
         return Err(InvalidRecipient.into());
     }
 
+    let pause = find_pause(ctx, &accs.trailing)?;
+    if pause.inbound_paused(accs.mint.info().key, accs.vaa.meta().emitter_chain) {
+        return Err(TransfersPaused.into());
+    }
+
     accs.vaa.verify(ctx.program_id)?;
     accs.vaa.claim(ctx, accs.payer.key)?;
 
@@ -305,5 +376,16 @@ pub fn complete_wrapped_with_payload(
     )?;
     invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
 
+    emit(&TokenBridgeEvent::TransferCompleted {
+        vaa: *accs.vaa.info().key,
+        mint: *accs.mint.info().key,
+        recipient: *accs.to.info().key,
+        token_chain: accs.vaa.token_chain,
+        token_address: accs.vaa.token_address,
+        amount: accs.vaa.amount.as_u64(),
+        fee: 0,
+        bridged_amount: accs.vaa.amount.as_u64(),
+    })?;
+
     Ok(())
 }
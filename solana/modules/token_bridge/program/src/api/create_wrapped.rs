@@ -6,16 +6,21 @@ use crate::{
         MintSigner,
         SplTokenMeta,
         SplTokenMetaDerivationData,
+        WrappedAttestationAccount,
+        WrappedAttestationDerivationData,
         WrappedDerivationData,
         WrappedMetaDerivationData,
         WrappedMint,
         WrappedTokenMeta,
     },
+    api::governance::find_pause,
     messages::PayloadAssetMeta,
     TokenBridgeError::{
         InvalidChain,
         InvalidMetadata,
         InvalidVAA,
+        StaleAttestation,
+        TransfersPaused,
     },
     INVALID_VAAS,
 };
@@ -26,6 +31,7 @@ use bridge::{
 use solana_program::{
     account_info::AccountInfo,
     program::invoke_signed,
+    pubkey::Pubkey,
 };
 use solitaire::{
     processors::seeded::{
@@ -58,6 +64,10 @@ pub struct CreateWrapped<'b> {
     pub spl_metadata: Mut<SplTokenMeta<'b>>,
 
     pub mint_authority: MintSigner<'b>,
+
+    /// Accounts added after the original layout, found by key: the pause config and the
+    /// attestation account of the mint, see [`find_pause`] and [`find_attestation`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 impl<'a> From<&CreateWrapped<'a>> for EndpointDerivationData {
@@ -111,18 +121,58 @@ pub fn create_wrapped(
     accs.chain_registration
         .verify_derivation(ctx.program_id, &derivation_data)?;
 
+    let pause = find_pause(ctx, &accs.trailing)?;
+    if pause.inbound_paused(accs.mint.info().key, accs.vaa.meta().emitter_chain) {
+        return Err(TransfersPaused.into());
+    }
+
     if INVALID_VAAS.contains(&&*accs.vaa.message.info().key.to_string()) {
         return Err(InvalidVAA.into());
     }
 
     accs.vaa.verify(ctx.program_id)?;
-    accs.vaa.claim(ctx, accs.payer.key)?;
 
+    let mut attestation = match find_attestation(ctx, &accs.trailing, accs.mint.info().key)? {
+        Some(attestation) => attestation,
+        None => {
+            // Callers of the original layout keep its behaviour, every attestation is claimed and
+            // refreshes are not ordered.
+            accs.vaa.claim(ctx, accs.payer.key)?;
+            return if accs.mint.is_initialized() {
+                update_accounts(ctx, accs, None, data)
+            } else {
+                create_accounts(ctx, accs, data)
+            };
+        }
+    };
+
+    // Only the first attestation is claimed, later ones refresh the metadata in sequence order.
     if accs.mint.is_initialized() {
-        update_accounts(ctx, accs, data)
+        // Mints refreshed without their attestation account claimed every attestation applied to
+        // them, claiming the first ordered refresh keeps those from being replayed.
+        if !attestation.is_initialized() {
+            accs.vaa.claim(ctx, accs.payer.key)?;
+        }
+        update_accounts(ctx, accs, Some(&attestation), data)?;
     } else {
-        create_accounts(ctx, accs, data)
+        accs.vaa.claim(ctx, accs.payer.key)?;
+        create_accounts(ctx, accs, data)?;
     }
+    record_attestation(ctx, accs, &mut attestation)?;
+    Peel::persist(&attestation, ctx.program_id)
+}
+
+/// Attestation account of `mint` among the `trailing` accounts, if the caller passed it.
+pub fn find_attestation<'b>(
+    ctx: &ExecutionContext,
+    trailing: &Remaining<MaybeMut<Info<'b>>>,
+    mint: &Pubkey,
+) -> Result<Option<Mut<WrappedAttestationAccount<'b, { AccountState::MaybeInitialized }>>>> {
+    let key = WrappedAttestationAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &WrappedAttestationDerivationData { mint: *mint },
+        ctx.program_id,
+    );
+    trailing.find(ctx.program_id, &key)
 }
 
 pub fn create_accounts(
@@ -157,8 +207,8 @@ pub fn create_accounts(
     )?;
 
     // Normalize Token Metadata.
-    let name = truncate_utf8(&accs.vaa.name, 32 - 11) + " (Wormhole)";
-    let symbol = truncate_utf8(&accs.vaa.symbol, 10);
+    let name = wrapped_name(&accs.vaa.name);
+    let symbol = wrapped_symbol(&accs.vaa.symbol);
 
     let spl_token_metadata_ix = spl_token_metadata::instruction::create_metadata_accounts(
         spl_token_metadata::id(),
@@ -188,8 +238,22 @@ pub fn create_accounts(
 pub fn update_accounts(
     ctx: &ExecutionContext,
     accs: &mut CreateWrapped,
+    attestation: Option<&Mut<WrappedAttestationAccount<{ AccountState::MaybeInitialized }>>>,
     _data: CreateWrappedData,
 ) -> Result<()> {
+    // Attestations stay unclaimed on ordered refreshes, so replays and older ones must not roll
+    // back. Sequences start over with a new emitter, e.g. once governance replaced the endpoint.
+    let meta = accs.vaa.meta();
+    let stale = attestation.map_or(false, |attestation| {
+        attestation.is_initialized()
+            && attestation.emitter_chain == meta.emitter_chain
+            && attestation.emitter_address == meta.emitter_address
+            && meta.sequence <= attestation.sequence
+    });
+    if stale {
+        return Err(StaleAttestation.into());
+    }
+
     accs.spl_metadata.verify_derivation(
         &spl_token_metadata::id(),
         &SplTokenMetaDerivationData {
@@ -201,9 +265,11 @@ pub fn update_accounts(
         .ok_or(InvalidMetadata)?
         .data;
 
-    // Normalize token metadata.
-    metadata.name = truncate_utf8(&accs.vaa.name, 32 - 11) + " (Wormhole)";
-    metadata.symbol = truncate_utf8(&accs.vaa.symbol, 10);
+    // Only bridged fields change, the URI and anything else set on Solana is kept. Metaplex pads
+    // stored strings, strip that so they pass its length checks again.
+    metadata.name = wrapped_name(&accs.vaa.name);
+    metadata.symbol = wrapped_symbol(&accs.vaa.symbol);
+    metadata.uri = metadata.uri.trim_end_matches('\0').to_string();
 
     // Update SPL Metadata
     let spl_token_metadata_ix = spl_token_metadata::instruction::update_metadata_accounts(
@@ -219,6 +285,46 @@ pub fn update_accounts(
     Ok(())
 }
 
+/// Keep the untruncated attestation, along with its emitter and sequence to order later refreshes.
+fn record_attestation(
+    ctx: &ExecutionContext,
+    accs: &CreateWrapped,
+    attestation: &mut Mut<WrappedAttestationAccount<{ AccountState::MaybeInitialized }>>,
+) -> Result<()> {
+    if !attestation.is_initialized() {
+        attestation.create(
+            &WrappedAttestationDerivationData {
+                mint: *accs.mint.info().key,
+            },
+            ctx,
+            accs.payer.key,
+            Exempt,
+        )?;
+    }
+    attestation.emitter_chain = accs.vaa.meta().emitter_chain;
+    attestation.emitter_address = accs.vaa.meta().emitter_address;
+    attestation.sequence = accs.vaa.meta().sequence;
+    attestation.original_symbol = pad_bytes32(&accs.vaa.symbol);
+    attestation.original_name = pad_bytes32(&accs.vaa.name);
+    Ok(())
+}
+
+fn pad_bytes32(s: &str) -> [u8; 32] {
+    let mut padded = [0u8; 32];
+    let len = min(s.len(), 32);
+    padded[..len].copy_from_slice(&s.as_bytes()[..len]);
+    padded
+}
+
+/// SPL metadata name of a wrapped token, within the 32 bytes Metaplex allows.
+fn wrapped_name(name: &str) -> String {
+    truncate_utf8(name, 32)
+}
+
+fn wrapped_symbol(symbol: &str) -> String {
+    truncate_utf8(symbol, 10)
+}
+
 // Byte-truncates potentially invalid UTF-8 encoded strings by converting to Unicode codepoints and
 // stripping unrecognised characters.
 pub fn truncate_utf8(data: impl AsRef<[u8]>, len: usize) -> String {
@@ -0,0 +1,153 @@
+//! Accounting of native tokens held in custody. Every lock and release of a native mint is
+//! recorded in its `CustodyLedger`, so that the custody balance can be checked against what the
+//! bridge owes to other chains. Ledgers are opened with the custody balance they find, tokens in
+//! custody before accounting was introduced count as owed.
+
+use crate::{
+    accounts::{
+        CustodyAccount,
+        CustodyAccountDerivationData,
+        CustodyLedgerAccount,
+        CustodyLedgerDerivationData,
+    },
+    events::{
+        emit,
+        TokenBridgeEvent,
+    },
+    types::*,
+    TokenBridgeError::{
+        AmountOverflow,
+        CustodyDeficit,
+        MissingAccount,
+    },
+};
+use solana_program::pubkey::Pubkey;
+use solitaire::{
+    CreationLamports::Exempt,
+    *,
+};
+
+/// Ledger of `mint`, found among the `trailing` accounts of an instruction whose layout predates
+/// it. It is required, a transfer that is not recorded would leave the ledger short.
+pub fn find_ledger<'b>(
+    ctx: &ExecutionContext,
+    trailing: &Remaining<MaybeMut<Info<'b>>>,
+    mint: &Pubkey,
+) -> Result<Mut<CustodyLedgerAccount<'b, { AccountState::MaybeInitialized }>>> {
+    let key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint: *mint },
+        ctx.program_id,
+    );
+    Ok(trailing.find(ctx.program_id, &key)?.ok_or(MissingAccount)?)
+}
+
+/// Record `amount` of `mint` entering custody, which held `custody_before` prior to the transfer.
+pub fn record_lock(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    ledger: &mut Mut<CustodyLedgerAccount<{ AccountState::MaybeInitialized }>>,
+    mint: &Pubkey,
+    custody_before: u64,
+    amount: u64,
+) -> Result<()> {
+    open_ledger(ctx, payer, ledger, mint, custody_before)?;
+    ledger.lock(amount).ok_or(AmountOverflow)?;
+    Ok(())
+}
+
+/// Record `amount` of `mint` leaving custody, which held `custody_before` prior to the transfer.
+/// Fails if more is released than custody owes.
+pub fn record_release(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    ledger: &mut Mut<CustodyLedgerAccount<{ AccountState::MaybeInitialized }>>,
+    mint: &Pubkey,
+    custody_before: u64,
+    amount: u64,
+) -> Result<()> {
+    open_ledger(ctx, payer, ledger, mint, custody_before)?;
+    ledger.release(amount).ok_or(CustodyDeficit)?;
+    Ok(())
+}
+
+fn open_ledger(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    ledger: &mut Mut<CustodyLedgerAccount<{ AccountState::MaybeInitialized }>>,
+    mint: &Pubkey,
+    custody_balance: u64,
+) -> Result<()> {
+    if ledger.is_initialized() {
+        return Ok(());
+    }
+    ledger.mint = *mint;
+    ledger.outstanding = custody_balance;
+    ledger.create(
+        &CustodyLedgerDerivationData { mint: *mint },
+        ctx,
+        payer,
+        Exempt,
+    )
+}
+
+/// Compares the custody balance of a mint with its ledger, without modifying either. Fails with
+/// `CustodyDeficit` if custody holds less than it owes, simulate it to check solvency.
+#[derive(FromAccounts)]
+pub struct ReconcileCustody<'b> {
+    pub mint: Data<'b, SplMint, { AccountState::Initialized }>,
+
+    #[seeds(&CustodyAccountDerivationData { mint: *accs.mint.info().key })]
+    pub custody: CustodyAccount<'b, { AccountState::MaybeInitialized }>,
+
+    /// Mints without a ledger have not been transferred since accounting was introduced
+    #[seeds]
+    pub ledger: CustodyLedgerAccount<'b, { AccountState::MaybeInitialized }>,
+}
+
+impl<'a> From<&ReconcileCustody<'a>> for CustodyAccountDerivationData {
+    fn from(accs: &ReconcileCustody<'a>) -> Self {
+        CustodyAccountDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+impl<'a> From<&ReconcileCustody<'a>> for CustodyLedgerDerivationData {
+    fn from(accs: &ReconcileCustody<'a>) -> Self {
+        CustodyLedgerDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct ReconcileCustodyData {}
+
+pub fn reconcile_custody(
+    _ctx: &ExecutionContext,
+    accs: &mut ReconcileCustody,
+    _data: ReconcileCustodyData,
+) -> Result<()> {
+    let custody_balance = if accs.custody.is_initialized() {
+        accs.custody.amount
+    } else {
+        0
+    };
+    // A missing ledger would be opened with the custody balance.
+    let outstanding = if accs.ledger.is_initialized() {
+        accs.ledger.outstanding
+    } else {
+        custody_balance
+    };
+
+    emit(&TokenBridgeEvent::CustodyReconciled {
+        mint: *accs.mint.info().key,
+        custody_balance,
+        outstanding,
+    })?;
+
+    if custody_balance < outstanding {
+        return Err(CustodyDeficit.into());
+    }
+    Ok(())
+}
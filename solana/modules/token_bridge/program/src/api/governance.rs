@@ -3,18 +3,34 @@ use crate::{
         ConfigAccount,
         Endpoint,
         EndpointDerivationData,
+        PauseAccount,
+        TransferLimitAccount,
+        TransferLimitDerivationData,
+    },
+    events::{
+        emit,
+        TokenBridgeEvent,
     },
     messages::{
         GovernancePayloadUpgrade,
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceSetPause,
+        PayloadGovernanceSetTransferLimit,
+        PayloadGovernanceUpdateChain,
     },
+    types::PauseConfig,
     TokenBridgeError::{
+        InvalidChain,
         InvalidGovernanceKey,
         InvalidVAA,
+        MissingAccount,
+        StalePause,
     },
     INVALID_VAAS,
 };
 use bridge::{
+    accounts::FeeCollector,
     vaa::{
         ClaimableVAA,
         DeserializePayload,
@@ -164,5 +180,243 @@ pub fn register_chain(
     accs.endpoint.chain = accs.vaa.chain;
     accs.endpoint.contract = accs.vaa.endpoint_address;
 
+    emit(&TokenBridgeEvent::ChainRegistered {
+        chain: accs.vaa.chain,
+        endpoint_address: accs.vaa.endpoint_address,
+    })?;
+
     Ok(())
 }
+
+#[derive(FromAccounts)]
+pub struct UpdateChain<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    /// Endpoint currently registered for the chain, closed in favor of `endpoint`
+    #[seeds(&EndpointDerivationData {
+        emitter_chain: accs.registered.chain,
+        emitter_address: accs.registered.contract,
+    })]
+    #[constraint(accs.registered.chain == accs.vaa.chain, InvalidChain)]
+    pub registered: Mut<Endpoint<'b, { AccountState::Initialized }>>,
+
+    #[seeds]
+    pub endpoint: Mut<Endpoint<'b, { AccountState::Uninitialized }>>,
+
+    /// Fee collector of the core bridge, receives the rent of `registered`
+    #[constraint(*accs.fee_collector.key == FeeCollector::key(None, &accs.config.wormhole_bridge))]
+    pub fee_collector: Mut<Info<'b>>,
+
+    pub vaa: ClaimableVAA<'b, PayloadGovernanceUpdateChain>,
+}
+
+impl<'a> From<&UpdateChain<'a>> for EndpointDerivationData {
+    fn from(accs: &UpdateChain<'a>) -> Self {
+        EndpointDerivationData {
+            emitter_chain: accs.vaa.chain,
+            emitter_address: accs.vaa.endpoint_address,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct UpdateChainData {}
+
+/// Replace the endpoint registered for a chain, e.g. after its contract migrated. The previous
+/// endpoint is closed in the same instruction, so the chain never has two endpoints and transfers
+/// the previous one emitted can no longer be redeemed.
+pub fn update_chain(
+    ctx: &ExecutionContext,
+    accs: &mut UpdateChain,
+    _data: UpdateChainData,
+) -> Result<()> {
+    if INVALID_VAAS.contains(&&*accs.vaa.message.info().key.to_string()) {
+        return Err(InvalidVAA.into());
+    }
+
+    verify_governance(&accs.vaa)?;
+    accs.vaa.verify(ctx.program_id)?;
+    accs.vaa.claim(ctx, accs.payer.key)?;
+
+    accs.endpoint
+        .create(&((&*accs).into()), ctx, accs.payer.key, Exempt)?;
+    accs.endpoint.chain = accs.vaa.chain;
+    accs.endpoint.contract = accs.vaa.endpoint_address;
+    accs.registered.close(&accs.fee_collector)?;
+
+    emit(&TokenBridgeEvent::ChainUpdated {
+        chain: accs.vaa.chain,
+        previous_address: accs.registered.contract,
+        endpoint_address: accs.vaa.endpoint_address,
+    })?;
+
+    Ok(())
+}
+
+#[derive(FromAccounts)]
+pub struct DeregisterChain<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(&EndpointDerivationData {
+        emitter_chain: accs.vaa.chain,
+        emitter_address: accs.vaa.endpoint_address,
+    })]
+    pub registered: Mut<Endpoint<'b, { AccountState::Initialized }>>,
+
+    /// Fee collector of the core bridge, receives the rent of `registered`
+    #[constraint(*accs.fee_collector.key == FeeCollector::key(None, &accs.config.wormhole_bridge))]
+    pub fee_collector: Mut<Info<'b>>,
+
+    pub vaa: ClaimableVAA<'b, PayloadGovernanceDeregisterChain>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct DeregisterChainData {}
+
+/// Remove the endpoint registered for a chain, transfers from the chain are rejected until it is
+/// registered again.
+pub fn deregister_chain(
+    ctx: &ExecutionContext,
+    accs: &mut DeregisterChain,
+    _data: DeregisterChainData,
+) -> Result<()> {
+    if INVALID_VAAS.contains(&&*accs.vaa.message.info().key.to_string()) {
+        return Err(InvalidVAA.into());
+    }
+
+    verify_governance(&accs.vaa)?;
+    accs.vaa.verify(ctx.program_id)?;
+    accs.vaa.claim(ctx, accs.payer.key)?;
+
+    accs.registered.close(&accs.fee_collector)?;
+
+    emit(&TokenBridgeEvent::ChainDeregistered {
+        chain: accs.vaa.chain,
+        endpoint_address: accs.vaa.endpoint_address,
+    })?;
+
+    Ok(())
+}
+
+#[derive(FromAccounts)]
+pub struct SetTransferLimit<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds]
+    pub transfer_limit: Mut<TransferLimitAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    pub vaa: ClaimableVAA<'b, PayloadGovernanceSetTransferLimit>,
+}
+
+impl<'a> From<&SetTransferLimit<'a>> for TransferLimitDerivationData {
+    fn from(accs: &SetTransferLimit<'a>) -> Self {
+        TransferLimitDerivationData {
+            mint: Pubkey::new_from_array(accs.vaa.mint),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct SetTransferLimitData {}
+
+pub fn set_transfer_limit(
+    ctx: &ExecutionContext,
+    accs: &mut SetTransferLimit,
+    _data: SetTransferLimitData,
+) -> Result<()> {
+    if INVALID_VAAS.contains(&&*accs.vaa.message.info().key.to_string()) {
+        return Err(InvalidVAA.into());
+    }
+
+    verify_governance(&accs.vaa)?;
+    accs.vaa.verify(ctx.program_id)?;
+    accs.vaa.claim(ctx, accs.payer.key)?;
+
+    if !accs.transfer_limit.is_initialized() {
+        accs.transfer_limit
+            .create(&((&*accs).into()), ctx, accs.payer.key, Exempt)?;
+    }
+
+    // Recorded flows are kept, so lowering a limit takes effect within the current window.
+    accs.transfer_limit.mint = Pubkey::new_from_array(accs.vaa.mint);
+    accs.transfer_limit.daily_limit = accs.vaa.daily_limit;
+    accs.transfer_limit.large_transfer_threshold = accs.vaa.large_transfer_threshold;
+    accs.transfer_limit.release_delay = accs.vaa.release_delay;
+
+    emit(&TokenBridgeEvent::TransferLimitSet {
+        mint: accs.transfer_limit.mint,
+        daily_limit: accs.vaa.daily_limit,
+        large_transfer_threshold: accs.vaa.large_transfer_threshold,
+        release_delay: accs.vaa.release_delay,
+    })?;
+
+    Ok(())
+}
+
+#[derive(FromAccounts)]
+pub struct SetPause<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(None)]
+    pub pause: Mut<PauseAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    /// Fee collector of the core bridge, receives the rent freed when the pause config shrinks
+    #[constraint(*accs.fee_collector.key == FeeCollector::key(None, &accs.config.wormhole_bridge))]
+    pub fee_collector: Mut<Info<'b>>,
+
+    pub vaa: ClaimableVAA<'b, PayloadGovernanceSetPause>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct SetPauseData {}
+
+/// Pause actions are applied in governance sequence order, an action older than the last one
+/// applied could otherwise undo it.
+pub fn set_pause(ctx: &ExecutionContext, accs: &mut SetPause, _data: SetPauseData) -> Result<()> {
+    if INVALID_VAAS.contains(&&*accs.vaa.message.info().key.to_string()) {
+        return Err(InvalidVAA.into());
+    }
+
+    verify_governance(&accs.vaa)?;
+    accs.vaa.verify(ctx.program_id)?;
+    accs.vaa.claim(ctx, accs.payer.key)?;
+
+    let sequence = accs.vaa.meta().sequence;
+    if accs.pause.is_initialized() && sequence <= accs.pause.sequence {
+        return Err(StalePause.into());
+    }
+    accs.pause.sequence = sequence;
+    accs.pause.set(&accs.vaa.target, accs.vaa.paused);
+
+    // The paused chains and mints grow and shrink with each action.
+    if accs.pause.is_initialized() {
+        accs.pause
+            .resize_to_fit(ctx, &accs.payer, &accs.fee_collector)?;
+    } else {
+        accs.pause.create(ctx, accs.payer.key, Exempt)?;
+    }
+
+    emit(&TokenBridgeEvent::PauseSet {
+        target: accs.vaa.target.clone(),
+        paused: accs.vaa.paused,
+    })?;
+
+    Ok(())
+}
+
+/// Pause config among the `trailing` accounts of an instruction whose layout predates it. It is
+/// found by key so the original accounts keep their position, but it is required: leaving it out
+/// would dodge the pause.
+pub fn find_pause(
+    ctx: &ExecutionContext,
+    trailing: &Remaining<MaybeMut<Info<'_>>>,
+) -> Result<PauseConfig> {
+    let key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, ctx.program_id);
+    let pause: PauseAccount<'_, { AccountState::MaybeInitialized }> =
+        trailing.find(ctx.program_id, &key)?.ok_or(MissingAccount)?;
+    Ok(PauseConfig::clone(&pause))
+}
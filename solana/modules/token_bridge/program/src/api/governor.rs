@@ -0,0 +1,252 @@
+//! The governor throttles custody flows of native mints. Transfers are tracked per mint over a
+//! rolling 24h window, see `types::TransferLimit`. Outbound transfers that do not fit are locked
+//! in custody right away but their message is held in a `PendingTransfer` until the release delay
+//! expired, inbound transfers that do not fit are rejected and can be redeemed later on.
+//!
+//! Instructions whose layout predates the governor find its accounts by key among their trailing
+//! accounts, so the original accounts keep their position.
+
+use crate::{
+    accounts::{
+        ConfigAccount,
+        CoreBridge,
+        EmitterAccount,
+        PauseAccount,
+        PendingTransferAccount,
+        PendingTransferDerivationData,
+        TransferLimitAccount,
+        TransferLimitDerivationData,
+    },
+    events::{
+        emit,
+        TokenBridgeEvent,
+    },
+    types::*,
+    TokenBridgeError::{
+        MissingAccount,
+        TransferLimitExceeded,
+        TransferNotReleasable,
+        TransfersPaused,
+    },
+};
+use bridge::{
+    api::{
+        PostMessageCpi,
+        PostMessageData,
+    },
+    types::ConsistencyLevel,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::AccountMeta,
+    program::invoke,
+    pubkey::Pubkey,
+    sysvar::clock::Clock,
+};
+use solitaire::{
+    CreationLamports::Exempt,
+    *,
+};
+use std::convert::TryFrom;
+
+/// Index of the hourly governor bucket `clock` falls into.
+pub fn governor_hour(clock: &Clock) -> u64 {
+    clock.unix_timestamp.max(0) as u64 / 3600
+}
+
+/// Transfer limit of `mint` among the `trailing` accounts of an instruction whose layout predates
+/// the governor. It is required whether or not the mint has a limit, leaving it out would dodge
+/// the governor.
+pub fn find_transfer_limit<'b>(
+    ctx: &ExecutionContext,
+    trailing: &Remaining<MaybeMut<Info<'b>>>,
+    mint: &Pubkey,
+) -> Result<Mut<TransferLimitAccount<'b, { AccountState::MaybeInitialized }>>> {
+    let key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint: *mint },
+        ctx.program_id,
+    );
+    Ok(trailing.find(ctx.program_id, &key)?.ok_or(MissingAccount)?)
+}
+
+/// Pending account for the transfer posting `message`, required for mints with a transfer limit.
+/// See [`find_transfer_limit`].
+pub fn find_pending<'b>(
+    ctx: &ExecutionContext,
+    trailing: &Remaining<MaybeMut<Info<'b>>>,
+    message: &Pubkey,
+) -> Result<Mut<PendingTransferAccount<'b, { AccountState::Uninitialized }>>> {
+    let key = PendingTransferAccount::<'_, { AccountState::Uninitialized }>::key(
+        &PendingTransferDerivationData { message: *message },
+        ctx.program_id,
+    );
+    Ok(trailing.find(ctx.program_id, &key)?.ok_or(MissingAccount)?)
+}
+
+/// Whether an outbound transfer of the normalized `amount` can be posted right away. Mints without
+/// a transfer limit are not governed.
+pub fn allow_outbound(
+    limit: &mut Mut<TransferLimitAccount<{ AccountState::MaybeInitialized }>>,
+    clock: &Clock,
+    amount: u64,
+) -> bool {
+    !limit.is_initialized() || limit.try_outbound(governor_hour(clock), amount)
+}
+
+/// Fail if an inbound transfer of the normalized `amount` exceeds the limit of its mint.
+pub fn verify_inbound(
+    limit: &mut Mut<TransferLimitAccount<{ AccountState::MaybeInitialized }>>,
+    clock: &Clock,
+    amount: u64,
+) -> Result<()> {
+    if limit.is_initialized() && !limit.try_inbound(governor_hour(clock), amount) {
+        return Err(TransferLimitExceeded.into());
+    }
+    Ok(())
+}
+
+/// Hold back an outbound transfer whose tokens are already in custody. The serialized `payload`
+/// is posted by `release_pending_transfer` once the release delay of the mint expired.
+#[allow(clippy::too_many_arguments)]
+pub fn queue_transfer(
+    ctx: &ExecutionContext,
+    payer: &Mut<Signer<AccountInfo>>,
+    pending: &mut Mut<PendingTransferAccount<{ AccountState::Uninitialized }>>,
+    derivation_data: &PendingTransferDerivationData,
+    limit: &TransferLimit,
+    clock: &Clock,
+    nonce: u32,
+    target_chain: ChainID,
+    amount: u64,
+    payload: Vec<u8>,
+) -> Result<()> {
+    let delay = i64::try_from(limit.release_delay).unwrap_or(i64::MAX);
+
+    pending.mint = limit.mint;
+    pending.release_after = clock.unix_timestamp.saturating_add(delay);
+    pending.nonce = nonce;
+    pending.target_chain = target_chain;
+    pending.amount = amount;
+    pending.payload = payload;
+    pending.rent_payer = *payer.key;
+    pending.create(derivation_data, ctx, payer.key, Exempt)?;
+
+    emit(&TokenBridgeEvent::TransferQueued {
+        pending: *pending.info().key,
+        mint: limit.mint,
+        amount,
+        release_after: pending.release_after,
+    })?;
+
+    Ok(())
+}
+
+#[derive(FromAccounts)]
+pub struct ReleasePendingTransfer<'b> {
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(None)]
+    pub pause: PauseAccount<'b, { AccountState::MaybeInitialized }>,
+
+    #[constraint(accs.pending.rent_payer == *accs.rent_payer.key)]
+    pub pending: Mut<PendingTransferAccount<'b, { AccountState::Initialized }>>,
+
+    /// Payer of the queued transfer, refunded the rent of the pending account
+    pub rent_payer: Mut<Info<'b>>,
+
+    /// Governor limits of the mint, charged the released amount
+    #[seeds(&TransferLimitDerivationData { mint: accs.pending.mint })]
+    pub transfer_limit: Mut<TransferLimitAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    /// CPI Context
+    pub bridge: Mut<CoreBridge<'b, { AccountState::Initialized }>>,
+
+    /// Account to store the posted message
+    pub message: Signer<Mut<Info<'b>>>,
+
+    /// Emitter of the VAA
+    pub emitter: EmitterAccount<'b>,
+
+    /// Tracker for the emitter sequence
+    pub sequence: Mut<Info<'b>>,
+
+    /// Account to collect tx fee
+    pub fee_collector: Mut<Info<'b>>,
+
+    pub clock: Sysvar<'b, Clock>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct ReleasePendingTransferData {}
+
+/// Anyone can release a pending transfer once its delay expired, the releasing payer covers the
+/// message fee. The released amount counts against the outbound window like a direct transfer.
+pub fn release_pending_transfer(
+    ctx: &ExecutionContext,
+    accs: &mut ReleasePendingTransfer,
+    _data: ReleasePendingTransferData,
+) -> Result<()> {
+    if accs.clock.unix_timestamp < accs.pending.release_after {
+        return Err(TransferNotReleasable.into());
+    }
+
+    if accs
+        .pause
+        .outbound_paused(&accs.pending.mint, accs.pending.target_chain)
+    {
+        return Err(TransfersPaused.into());
+    }
+
+    // The limit may have been removed while the transfer was queued.
+    if accs.transfer_limit.is_initialized() {
+        accs.transfer_limit
+            .record_released(governor_hour(&accs.clock), accs.pending.amount);
+    }
+
+    // Pay fee
+    let transfer_ix = solana_program::system_instruction::transfer(
+        accs.payer.key,
+        accs.fee_collector.key,
+        accs.bridge.config.fee,
+    );
+    invoke(&transfer_ix, ctx.accounts)?;
+
+    let post_message = CpiContext::new(
+        accs.config.wormhole_bridge,
+        PostMessageCpi {
+            bridge: accs.bridge.info().clone(),
+            message: accs.message.info().clone(),
+            emitter: accs.emitter.info().clone(),
+            sequence: accs.sequence.info().clone(),
+            payer: accs.payer.info().clone(),
+            fee_collector: accs.fee_collector.info().clone(),
+            clock: accs.clock.info().clone(),
+        },
+    )
+    .remaining(vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
+    ]);
+    post_message.invoke_seeded(
+        ctx,
+        bridge::instruction::Instruction::PostMessage,
+        PostMessageData {
+            nonce: accs.pending.nonce,
+            payload: accs.pending.payload.clone(),
+            consistency_level: ConsistencyLevel::Finalized,
+        },
+        &accs.emitter,
+        None,
+    )?;
+
+    emit(&TokenBridgeEvent::TransferReleased {
+        pending: *accs.pending.info().key,
+        message: *accs.message.key,
+        mint: accs.pending.mint,
+    })?;
+
+    accs.pending.close(accs.rent_payer.info())?;
+
+    Ok(())
+}
@@ -5,25 +5,56 @@ use crate::{
         CoreBridge,
         CustodyAccount,
         CustodyAccountDerivationData,
+        CustodyLedgerAccount,
+        CustodyLedgerDerivationData,
         CustodySigner,
         EmitterAccount,
         MintSigner,
+        PauseAccount,
+        PendingTransferAccount,
+        PendingTransferDerivationData,
+        TemporaryAccount,
+        TemporaryAccountDerivationData,
+        TransferLimitAccount,
+        TransferLimitDerivationData,
         WrappedDerivationData,
         WrappedMetaDerivationData,
         WrappedMint,
         WrappedTokenMeta,
     },
+    api::{
+        custody::{
+            find_ledger,
+            record_lock,
+        },
+        governance::find_pause,
+        governor::{
+            allow_outbound,
+            find_pending,
+            find_transfer_limit,
+            queue_transfer,
+        },
+    },
+    events::{
+        emit,
+        TokenBridgeEvent,
+    },
     messages::PayloadTransfer,
+    token,
     types::*,
     TokenBridgeError,
     TokenBridgeError::{
+        AmountOverflow,
         InvalidChain,
         InvalidFee,
-        WrongAccountOwner,
+        TransfersPaused,
     },
 };
 use bridge::{
-    api::PostMessageData,
+    api::{
+        PostMessageCpi,
+        PostMessageData,
+    },
     types::ConsistencyLevel,
     vaa::SerializePayload,
     CHAIN_ID_SOLANA,
@@ -31,14 +62,8 @@ use bridge::{
 use primitive_types::U256;
 use solana_program::{
     account_info::AccountInfo,
-    instruction::{
-        AccountMeta,
-        Instruction,
-    },
-    program::{
-        invoke,
-        invoke_signed,
-    },
+    instruction::AccountMeta,
+    program::invoke,
     program_option::COption,
     sysvar::clock::Clock,
 };
@@ -57,10 +82,12 @@ pub struct TransferNative<'b> {
 
     pub config: ConfigAccount<'b, { AccountState::Initialized }>,
 
+    #[constraint(accs.from.mint == *accs.mint.info().key, TokenBridgeError::InvalidMint)]
     pub from: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
 
     pub mint: Mut<Data<'b, SplMint, { AccountState::Initialized }>>,
 
+    #[seeds(&CustodyAccountDerivationData { mint: *accs.mint.info().key })]
     pub custody: Mut<CustodyAccount<'b, { AccountState::MaybeInitialized }>>,
 
     // This could allow someone to race someone else's tx if they do the approval in a separate tx.
@@ -85,6 +112,11 @@ pub struct TransferNative<'b> {
     pub fee_collector: Mut<Info<'b>>,
 
     pub clock: Sysvar<'b, Clock>,
+
+    /// Accounts added after the original layout, found by key: the pause config, the ledger of the
+    /// mint, its transfer limit and the pending account holding the transfer if the governor queues
+    /// it, see [`find_pause`], [`find_ledger`] and [`allow_outbound`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 impl<'a> From<&TransferNative<'a>> for CustodyAccountDerivationData {
@@ -114,14 +146,21 @@ pub fn transfer_native(
         return Err(InvalidChain.into());
     }
 
+    let pause = find_pause(ctx, &accs.trailing)?;
+    if pause.outbound_paused(accs.mint.info().key, data.target_chain) {
+        return Err(TransfersPaused.into());
+    }
+
+    let mut ledger = find_ledger(ctx, &accs.trailing, accs.mint.info().key)?;
     let derivation_data: CustodyAccountDerivationData = (&*accs).into();
-    let (amount, fee) = verify_and_execute_native_transfers(
+    let (amount, fee, bridged_amount) = verify_and_execute_native_transfers(
         ctx,
         &derivation_data,
         &accs.payer,
-        &accs.from,
+        accs.from.info(),
         &accs.mint,
         &accs.custody,
+        &mut ledger,
         &accs.authority_signer,
         &accs.custody_signer,
         &accs.bridge,
@@ -129,6 +168,7 @@ pub fn transfer_native(
         data.amount,
         data.fee,
     )?;
+    Peel::persist(&ledger, ctx.program_id)?;
 
     // Post message
     let payload = PayloadTransfer {
@@ -139,58 +179,341 @@ pub fn transfer_native(
         to_chain: data.target_chain,
         fee: U256::from(fee),
     };
-    let params = (
+
+    // Tokens are in custody either way, the governor only decides when the message goes out.
+    let mut transfer_limit = find_transfer_limit(ctx, &accs.trailing, accs.mint.info().key)?;
+    if transfer_limit.is_initialized() {
+        let mut pending = find_pending(ctx, &accs.trailing, accs.message.key)?;
+        let allowed = allow_outbound(&mut transfer_limit, &accs.clock, amount);
+        Peel::persist(&transfer_limit, ctx.program_id)?;
+        if !allowed {
+            queue_transfer(
+                ctx,
+                &accs.payer,
+                &mut pending,
+                &PendingTransferDerivationData {
+                    message: *accs.message.key,
+                },
+                &transfer_limit,
+                &accs.clock,
+                data.nonce,
+                data.target_chain,
+                amount,
+                payload.try_to_vec()?,
+            )?;
+            return Peel::persist(&pending, ctx.program_id);
+        }
+    }
+
+    let post_message = CpiContext::new(
+        accs.config.wormhole_bridge,
+        PostMessageCpi {
+            bridge: accs.bridge.info().clone(),
+            message: accs.message.info().clone(),
+            emitter: accs.emitter.info().clone(),
+            sequence: accs.sequence.info().clone(),
+            payer: accs.payer.info().clone(),
+            fee_collector: accs.fee_collector.info().clone(),
+            clock: accs.clock.info().clone(),
+        },
+    )
+    .remaining(vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
+    ]);
+    post_message.invoke_seeded(
+        ctx,
         bridge::instruction::Instruction::PostMessage,
         PostMessageData {
             nonce: data.nonce,
             payload: payload.try_to_vec()?,
             consistency_level: ConsistencyLevel::Finalized,
         },
+        &accs.emitter,
+        None,
+    )?;
+
+    emit(&TokenBridgeEvent::TransferOut {
+        message: *accs.message.key,
+        mint: *accs.mint.info().key,
+        token_chain: payload.token_chain,
+        token_address: payload.token_address,
+        amount: payload.amount.as_u64(),
+        fee: payload.fee.as_u64(),
+        bridged_amount,
+        target_chain: payload.to_chain,
+        target_address: payload.to,
+        with_payload: false,
+    })?;
+
+    Ok(())
+}
+
+#[derive(FromAccounts)]
+pub struct TransferNativeSol<'b> {
+    /// Sender of the SOL, also receives the rent of the temporary account back
+    pub payer: Mut<Signer<AccountInfo<'b>>>,
+
+    pub config: ConfigAccount<'b, { AccountState::Initialized }>,
+
+    #[seeds(None)]
+    pub pause: PauseAccount<'b, { AccountState::MaybeInitialized }>,
+
+    /// Wraps the SOL for the duration of the instruction
+    #[seeds(&TemporaryAccountDerivationData { message: *accs.message.key })]
+    pub temporary: Mut<TemporaryAccount<'b, { AccountState::Uninitialized }>>,
+
+    #[constraint(*accs.mint.info().key == spl_token::native_mint::id(), TokenBridgeError::InvalidMint)]
+    pub mint: Mut<Data<'b, SplMint, { AccountState::Initialized }>>,
+
+    #[seeds(&CustodyAccountDerivationData { mint: *accs.mint.info().key })]
+    pub custody: Mut<CustodyAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    /// Tokens of the mint owed to other chains, see [`record_lock`]
+    #[seeds]
+    pub ledger: Mut<CustodyLedgerAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    pub authority_signer: AuthoritySigner<'b>,
+
+    pub custody_signer: CustodySigner<'b>,
+
+    /// CPI Context
+    pub bridge: Mut<CoreBridge<'b, { AccountState::Initialized }>>,
+
+    /// Account to store the posted message
+    pub message: Signer<Mut<Info<'b>>>,
+
+    /// Emitter of the VAA
+    pub emitter: EmitterAccount<'b>,
+
+    /// Tracker for the emitter sequence
+    pub sequence: Mut<Info<'b>>,
+
+    /// Account to collect tx fee
+    pub fee_collector: Mut<Info<'b>>,
+
+    pub clock: Sysvar<'b, Clock>,
+
+    /// Governor limits of the mint, see [`allow_outbound`]
+    #[seeds]
+    pub transfer_limit: Mut<TransferLimitAccount<'b, { AccountState::MaybeInitialized }>>,
+
+    /// Holds the transfer if the governor queues it
+    #[seeds]
+    pub pending: Mut<PendingTransferAccount<'b, { AccountState::Uninitialized }>>,
+}
+
+impl<'a> From<&TransferNativeSol<'a>> for TemporaryAccountDerivationData {
+    fn from(accs: &TransferNativeSol<'a>) -> Self {
+        TemporaryAccountDerivationData {
+            message: *accs.message.key,
+        }
+    }
+}
+
+impl<'a> From<&TransferNativeSol<'a>> for CustodyAccountDerivationData {
+    fn from(accs: &TransferNativeSol<'a>) -> Self {
+        CustodyAccountDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+impl<'a> From<&TransferNativeSol<'a>> for CustodyLedgerDerivationData {
+    fn from(accs: &TransferNativeSol<'a>) -> Self {
+        CustodyLedgerDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+impl<'a> From<&TransferNativeSol<'a>> for TransferLimitDerivationData {
+    fn from(accs: &TransferNativeSol<'a>) -> Self {
+        TransferLimitDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+impl<'a> From<&TransferNativeSol<'a>> for PendingTransferDerivationData {
+    fn from(accs: &TransferNativeSol<'a>) -> Self {
+        PendingTransferDerivationData {
+            message: *accs.message.key,
+        }
+    }
+}
+
+/// Amounts are in lamports.
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct TransferNativeSolData {
+    pub nonce: u32,
+    pub amount: u64,
+    pub fee: u64,
+    pub target_address: Address,
+    pub target_chain: ChainID,
+}
+
+pub fn transfer_native_sol(
+    ctx: &ExecutionContext,
+    accs: &mut TransferNativeSol,
+    data: TransferNativeSolData,
+) -> Result<()> {
+    // Prevent transferring to the same chain.
+    if data.target_chain == CHAIN_ID_SOLANA {
+        return Err(InvalidChain.into());
+    }
+
+    if accs
+        .pause
+        .outbound_paused(accs.mint.info().key, data.target_chain)
+    {
+        return Err(TransfersPaused.into());
+    }
+
+    // Wrap the SOL in a temporary account, lamports sent before initialization become its balance.
+    let derivation_data: TemporaryAccountDerivationData = (&*accs).into();
+    let seeds = accs
+        .temporary
+        .self_bumped_seeds(&derivation_data, ctx.program_id);
+    let s: Vec<&[u8]> = seeds.iter().map(|item| item.as_slice()).collect();
+    create_account(
+        ctx,
+        accs.temporary.info(),
+        accs.payer.key,
+        Exempt,
+        token::account_len(accs.mint.info())?,
+        accs.mint.info().owner,
+        IsSigned::SignedWithSeeds(&[s.as_slice()]),
+    )?;
+    let transfer_ix = solana_program::system_instruction::transfer(
+        accs.payer.key,
+        accs.temporary.info().key,
+        data.amount,
     );
+    invoke(&transfer_ix, ctx.accounts)?;
+    let init_ix = token::initialize_account(
+        accs.mint.info().owner,
+        accs.temporary.info().key,
+        accs.mint.info().key,
+        accs.authority_signer.key,
+    )?;
+    invoke(&init_ix, ctx.accounts)?;
+
+    let derivation_data: CustodyAccountDerivationData = (&*accs).into();
+    let (amount, fee, bridged_amount) = verify_and_execute_native_transfers(
+        ctx,
+        &derivation_data,
+        &accs.payer,
+        accs.temporary.info(),
+        &accs.mint,
+        &accs.custody,
+        &mut accs.ledger,
+        &accs.authority_signer,
+        &accs.custody_signer,
+        &accs.bridge,
+        &accs.fee_collector,
+        data.amount,
+        data.fee,
+    )?;
+
+    // Closing unwraps the truncation dust and returns the rent.
+    let close_ix = token::close_account(
+        accs.mint.info().owner,
+        accs.temporary.info().key,
+        accs.payer.key,
+        accs.authority_signer.key,
+    )?;
+    invoke_seeded(&close_ix, ctx, &accs.authority_signer, None)?;
+
+    // Post message
+    let payload = PayloadTransfer {
+        amount: U256::from(amount),
+        token_address: accs.mint.info().key.to_bytes(),
+        token_chain: CHAIN_ID_SOLANA,
+        to: data.target_address,
+        to_chain: data.target_chain,
+        fee: U256::from(fee),
+    };
+
+    // Tokens are in custody either way, the governor only decides when the message goes out.
+    if !allow_outbound(&mut accs.transfer_limit, &accs.clock, amount) {
+        let derivation_data: PendingTransferDerivationData = (&*accs).into();
+        return queue_transfer(
+            ctx,
+            &accs.payer,
+            &mut accs.pending,
+            &derivation_data,
+            &accs.transfer_limit,
+            &accs.clock,
+            data.nonce,
+            data.target_chain,
+            amount,
+            payload.try_to_vec()?,
+        );
+    }
 
-    let ix = Instruction::new_with_bytes(
+    let post_message = CpiContext::new(
         accs.config.wormhole_bridge,
-        params.try_to_vec()?.as_slice(),
-        vec![
-            AccountMeta::new(*accs.bridge.info().key, false),
-            AccountMeta::new(*accs.message.key, true),
-            AccountMeta::new_readonly(*accs.emitter.key, true),
-            AccountMeta::new(*accs.sequence.key, false),
-            AccountMeta::new(*accs.payer.key, true),
-            AccountMeta::new(*accs.fee_collector.key, false),
-            AccountMeta::new_readonly(*accs.clock.info().key, false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-            AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
-        ],
-    );
-    invoke_seeded(&ix, ctx, &accs.emitter, None)?;
+        PostMessageCpi {
+            bridge: accs.bridge.info().clone(),
+            message: accs.message.info().clone(),
+            emitter: accs.emitter.info().clone(),
+            sequence: accs.sequence.info().clone(),
+            payer: accs.payer.info().clone(),
+            fee_collector: accs.fee_collector.info().clone(),
+            clock: accs.clock.info().clone(),
+        },
+    )
+    .remaining(vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
+    ]);
+    post_message.invoke_seeded(
+        ctx,
+        bridge::instruction::Instruction::PostMessage,
+        PostMessageData {
+            nonce: data.nonce,
+            payload: payload.try_to_vec()?,
+            consistency_level: ConsistencyLevel::Finalized,
+        },
+        &accs.emitter,
+        None,
+    )?;
+
+    emit(&TokenBridgeEvent::TransferOut {
+        message: *accs.message.key,
+        mint: *accs.mint.info().key,
+        token_chain: payload.token_chain,
+        token_address: payload.token_address,
+        amount: payload.amount.as_u64(),
+        fee: payload.fee.as_u64(),
+        bridged_amount,
+        target_chain: payload.to_chain,
+        target_address: payload.to,
+        with_payload: false,
+    })?;
 
     Ok(())
 }
 
+/// Moves the tokens into custody and returns the amount and fee truncated to 8 decimals, along
+/// with the local amount kept in custody for them.
 #[allow(clippy::too_many_arguments)]
 pub fn verify_and_execute_native_transfers(
     ctx: &ExecutionContext,
     derivation_data: &CustodyAccountDerivationData,
     payer: &Mut<Signer<AccountInfo>>,
-    from: &Mut<Data<SplAccount, { AccountState::Initialized }>>,
+    from: &AccountInfo,
     mint: &Mut<Data<SplMint, { AccountState::Initialized }>>,
     custody: &Mut<CustodyAccount<{ AccountState::MaybeInitialized }>>,
+    ledger: &mut Mut<CustodyLedgerAccount<{ AccountState::MaybeInitialized }>>,
     authority_signer: &AuthoritySigner,
     custody_signer: &CustodySigner,
     bridge: &Mut<CoreBridge<{ AccountState::Initialized }>>,
     fee_collector: &Mut<Info>,
     raw_amount: u64,
     raw_fee: u64,
-) -> Result<(u64, u64)> {
-    // Verify that the custody account is derived correctly
-    custody.verify_derivation(ctx.program_id, derivation_data)?;
-
-    // Verify mints
-    if from.mint != *mint.info().key {
-        return Err(TokenBridgeError::InvalidMint.into());
-    }
-
+) -> Result<(u64, u64, u64)> {
     // Fee must be less than amount
     if raw_fee > raw_amount {
         return Err(InvalidFee.into());
@@ -203,36 +526,72 @@ pub fn verify_and_execute_native_transfers(
         }
     }
 
-    if !custody.is_initialized() {
-        custody.create(derivation_data, ctx, payer.key, Exempt)?;
+    token::verify_mint_extensions(mint.info())?;
 
-        let init_ix = spl_token::instruction::initialize_account(
-            &spl_token::id(),
-            custody.info().key,
-            mint.info().key,
+    // Custody accounts live under the token program of the mint, which also decides their size.
+    let token_program = mint.info().owner;
+    if !custody.is_initialized() {
+        let seeds = custody.self_bumped_seeds(derivation_data, ctx.program_id);
+        token::create_token_account(
+            ctx,
+            payer.key,
+            custody.info(),
+            &seeds,
+            mint.info(),
             custody_signer.key,
         )?;
-        invoke_signed(&init_ix, ctx.accounts, &[])?;
     }
 
-    let trunc_divisor = 10u64.pow(8.max(mint.decimals as u32) - 8);
-    // Truncate to 8 decimals
-    let amount: u64 = raw_amount / trunc_divisor;
-    let fee: u64 = raw_fee / trunc_divisor;
-    // Untruncate the amount to drop the remainder so we don't  "burn" user's funds.
-    let amount_trunc: u64 = amount * trunc_divisor;
+    let trunc_divisor = token::trunc_divisor(mint.decimals)?;
 
     // Transfer tokens
-    let transfer_ix = spl_token::instruction::transfer(
-        &spl_token::id(),
-        from.info().key,
+    let custody_before = token::balance(custody.info())?;
+    let transfer_ix = token::transfer_checked(
+        token_program,
+        from.key,
+        mint.info().key,
         custody.info().key,
         authority_signer.key,
-        &[],
-        amount_trunc,
+        raw_amount,
+        mint.decimals,
     )?;
     invoke_seeded(&transfer_ix, ctx, authority_signer, None)?;
 
+    // Transfer fees of Token-2022 mints are withheld in custody, only the received amount is
+    // bridged. Truncate to 8 decimals.
+    let received = token::balance(custody.info())?
+        .checked_sub(custody_before)
+        .ok_or(AmountOverflow)?;
+    let amount: u64 = received / trunc_divisor;
+    let fee: u64 = raw_fee / trunc_divisor;
+    if fee > amount {
+        return Err(InvalidFee.into());
+    }
+
+    // Refund the remainder truncation dropped so custody only holds what the transfer accounts for.
+    let dust = received % trunc_divisor;
+    let bridged = received - dust;
+    if dust > 0 {
+        let refund_ix = token::transfer_checked(
+            token_program,
+            custody.info().key,
+            mint.info().key,
+            from.key,
+            custody_signer.key,
+            dust,
+            mint.decimals,
+        )?;
+        invoke_seeded(&refund_ix, ctx, custody_signer, None)?;
+    }
+    record_lock(
+        ctx,
+        payer.key,
+        ledger,
+        mint.info().key,
+        custody_before,
+        bridged,
+    )?;
+
     // Pay fee
     let transfer_ix = solana_program::system_instruction::transfer(
         payer.key,
@@ -241,7 +600,7 @@ pub fn verify_and_execute_native_transfers(
     );
     invoke(&transfer_ix, ctx.accounts)?;
 
-    Ok((amount, fee))
+    Ok((amount, fee, bridged))
 }
 
 #[derive(FromAccounts)]
@@ -249,9 +608,12 @@ pub struct TransferWrapped<'b> {
     pub payer: Mut<Signer<AccountInfo<'b>>>,
     pub config: ConfigAccount<'b, { AccountState::Initialized }>,
 
+    #[constraint(accs.from.owner == *accs.from_owner.key, TokenBridgeError::WrongAccountOwner)]
+    #[constraint(accs.from.mint == *accs.mint.info().key, TokenBridgeError::InvalidMint)]
     pub from: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
     pub from_owner: MaybeMut<Signer<Info<'b>>>,
     pub mint: Mut<WrappedMint<'b, { AccountState::Initialized }>>,
+    #[seeds]
     pub wrapped_meta: WrappedTokenMeta<'b, { AccountState::Initialized }>,
 
     pub authority_signer: AuthoritySigner<'b>,
@@ -272,6 +634,9 @@ pub struct TransferWrapped<'b> {
     pub fee_collector: Mut<Info<'b>>,
 
     pub clock: Sysvar<'b, Clock>,
+
+    /// Accounts added after the original layout, found by key: the pause config, see [`find_pause`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 impl<'a> From<&TransferWrapped<'a>> for WrappedDerivationData {
@@ -310,15 +675,16 @@ pub fn transfer_wrapped(
         return Err(InvalidChain.into());
     }
 
-    let derivation_data: WrappedMetaDerivationData = (&*accs).into();
+    let pause = find_pause(ctx, &accs.trailing)?;
+    if pause.outbound_paused(accs.mint.info().key, data.target_chain) {
+        return Err(TransfersPaused.into());
+    }
+
     verify_and_execute_wrapped_transfers(
         ctx,
-        &derivation_data,
         &accs.payer,
         &accs.from,
-        &accs.from_owner,
         &accs.mint,
-        &accs.wrapped_meta,
         &accs.authority_signer,
         &accs.bridge,
         &accs.fee_collector,
@@ -335,31 +701,46 @@ pub fn transfer_wrapped(
         to_chain: data.target_chain,
         fee: U256::from(data.fee),
     };
-    let params = (
+    let post_message = CpiContext::new(
+        accs.config.wormhole_bridge,
+        PostMessageCpi {
+            bridge: accs.bridge.info().clone(),
+            message: accs.message.info().clone(),
+            emitter: accs.emitter.info().clone(),
+            sequence: accs.sequence.info().clone(),
+            payer: accs.payer.info().clone(),
+            fee_collector: accs.fee_collector.info().clone(),
+            clock: accs.clock.info().clone(),
+        },
+    )
+    .remaining(vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
+    ]);
+    post_message.invoke_seeded(
+        ctx,
         bridge::instruction::Instruction::PostMessage,
         PostMessageData {
             nonce: data.nonce,
             payload: payload.try_to_vec()?,
             consistency_level: ConsistencyLevel::Finalized,
         },
-    );
+        &accs.emitter,
+        None,
+    )?;
 
-    let ix = Instruction::new_with_bytes(
-        accs.config.wormhole_bridge,
-        params.try_to_vec()?.as_slice(),
-        vec![
-            AccountMeta::new(*accs.bridge.info().key, false),
-            AccountMeta::new(*accs.message.key, true),
-            AccountMeta::new_readonly(*accs.emitter.key, true),
-            AccountMeta::new(*accs.sequence.key, false),
-            AccountMeta::new(*accs.payer.key, true),
-            AccountMeta::new(*accs.fee_collector.key, false),
-            AccountMeta::new_readonly(*accs.clock.info().key, false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-            AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
-        ],
-    );
-    invoke_seeded(&ix, ctx, &accs.emitter, None)?;
+    emit(&TokenBridgeEvent::TransferOut {
+        message: *accs.message.key,
+        mint: *accs.mint.info().key,
+        token_chain: payload.token_chain,
+        token_address: payload.token_address,
+        amount: payload.amount.as_u64(),
+        fee: payload.fee.as_u64(),
+        bridged_amount: data.amount,
+        target_chain: payload.to_chain,
+        target_address: payload.to,
+        with_payload: false,
+    })?;
 
     Ok(())
 }
@@ -367,36 +748,20 @@ pub fn transfer_wrapped(
 #[allow(clippy::too_many_arguments)]
 pub fn verify_and_execute_wrapped_transfers(
     ctx: &ExecutionContext,
-    derivation_data: &WrappedMetaDerivationData,
     payer: &Mut<Signer<AccountInfo>>,
     from: &Mut<Data<SplAccount, { AccountState::Initialized }>>,
-    from_owner: &MaybeMut<Signer<Info>>,
     mint: &Mut<WrappedMint<{ AccountState::Initialized }>>,
-    wrapped_meta: &WrappedTokenMeta<{ AccountState::Initialized }>,
     authority_signer: &AuthoritySigner,
     bridge: &Mut<CoreBridge<{ AccountState::Initialized }>>,
     fee_collector: &Mut<Info>,
     amount: u64,
     fee: u64,
 ) -> Result<()> {
-    // Verify that the from account is owned by the from_owner
-    if &from.owner != from_owner.key {
-        return Err(WrongAccountOwner.into());
-    }
-
-    // Verify mints
-    if mint.info().key != &from.mint {
-        return Err(TokenBridgeError::InvalidMint.into());
-    }
-
     // Fee must be less than amount
     if fee > amount {
         return Err(InvalidFee.into());
     }
 
-    // Verify that meta is correct
-    wrapped_meta.verify_derivation(ctx.program_id, derivation_data)?;
-
     // Burn tokens
     let burn_ix = spl_token::instruction::burn(
         &spl_token::id(),
@@ -7,17 +7,39 @@ use crate::{
         CustodyAccountDerivationData,
         CustodySigner,
         EmitterAccount,
+        PendingTransferDerivationData,
         WrappedDerivationData,
         WrappedMetaDerivationData,
         WrappedMint,
         WrappedTokenMeta,
     },
+    api::{
+        custody::find_ledger,
+        governance::find_pause,
+        governor::{
+            allow_outbound,
+            find_pending,
+            find_transfer_limit,
+            queue_transfer,
+        },
+    },
+    events::{
+        emit,
+        TokenBridgeEvent,
+    },
     messages::PayloadTransferWithPayload,
     types::*,
-    TokenBridgeError::InvalidChain,
+    TokenBridgeError::{
+        self,
+        InvalidChain,
+        TransfersPaused,
+    },
 };
 use bridge::{
-    api::PostMessageData,
+    api::{
+        PostMessageCpi,
+        PostMessageData,
+    },
     types::ConsistencyLevel,
     vaa::SerializePayload,
     CHAIN_ID_SOLANA,
@@ -25,17 +47,11 @@ use bridge::{
 use primitive_types::U256;
 use solana_program::{
     account_info::AccountInfo,
-    instruction::{
-        AccountMeta,
-        Instruction,
-    },
+    instruction::AccountMeta,
     pubkey::Pubkey,
     sysvar::clock::Clock,
 };
-use solitaire::{
-    processors::seeded::invoke_seeded,
-    *,
-};
+use solitaire::*;
 
 use super::{
     verify_and_execute_native_transfers,
@@ -59,6 +75,10 @@ impl<'a, 'b: 'a, 'c> Peel<'a, 'b, 'c> for SenderAccount<'b> {
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         MaybeMut::persist(&self.0, program_id)
     }
+
+    fn describe(name: &str) -> Vec<solitaire::idl::IdlAccount> {
+        MaybeMut::<Signer<Info<'b>>>::describe(name)
+    }
 }
 
 // May or may not be a PDA, so we don't use [`Derive`], instead implement
@@ -127,8 +147,10 @@ pub struct TransferNativeWithPayload<'b> {
     pub payer: Mut<Signer<AccountInfo<'b>>>,
     pub config: ConfigAccount<'b, { AccountState::Initialized }>,
 
+    #[constraint(accs.from.mint == *accs.mint.info().key, TokenBridgeError::InvalidMint)]
     pub from: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
     pub mint: Mut<Data<'b, SplMint, { AccountState::Initialized }>>,
+    #[seeds(&CustodyAccountDerivationData { mint: *accs.mint.info().key })]
     pub custody: Mut<CustodyAccount<'b, { AccountState::MaybeInitialized }>>,
 
     // This could allow someone to race someone else's tx if they do the approval in a separate tx.
@@ -156,6 +178,11 @@ pub struct TransferNativeWithPayload<'b> {
 
     /// See [`derive_sender_address`]
     pub sender: SenderAccount<'b>,
+
+    /// Accounts added after the original layout, found by key: the pause config, the ledger of the
+    /// mint, its transfer limit and the pending account holding the transfer if the governor queues
+    /// it, see [`find_pause`], [`find_ledger`] and [`allow_outbound`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 impl<'a> From<&TransferNativeWithPayload<'a>> for CustodyAccountDerivationData {
@@ -187,14 +214,21 @@ pub fn transfer_native_with_payload(
         return Err(InvalidChain.into());
     }
 
+    let pause = find_pause(ctx, &accs.trailing)?;
+    if pause.outbound_paused(accs.mint.info().key, data.target_chain) {
+        return Err(TransfersPaused.into());
+    }
+
+    let mut ledger = find_ledger(ctx, &accs.trailing, accs.mint.info().key)?;
     let derivation_data: CustodyAccountDerivationData = (&*accs).into();
-    let (amount, _fee) = verify_and_execute_native_transfers(
+    let (amount, _fee, bridged_amount) = verify_and_execute_native_transfers(
         ctx,
         &derivation_data,
         &accs.payer,
-        &accs.from,
+        accs.from.info(),
         &accs.mint,
         &accs.custody,
+        &mut ledger,
         &accs.authority_signer,
         &accs.custody_signer,
         &accs.bridge,
@@ -202,6 +236,7 @@ pub fn transfer_native_with_payload(
         data.amount,
         0,
     )?;
+    Peel::persist(&ledger, ctx.program_id)?;
 
     // Post message
     let payload = PayloadTransferWithPayload {
@@ -213,31 +248,72 @@ pub fn transfer_native_with_payload(
         from_address: accs.sender.derive_sender_address(&data.cpi_program_id)?,
         payload: data.payload,
     };
-    let params = (
+
+    // Tokens are in custody either way, the governor only decides when the message goes out.
+    let mut transfer_limit = find_transfer_limit(ctx, &accs.trailing, accs.mint.info().key)?;
+    if transfer_limit.is_initialized() {
+        let mut pending = find_pending(ctx, &accs.trailing, accs.message.key)?;
+        let allowed = allow_outbound(&mut transfer_limit, &accs.clock, amount);
+        Peel::persist(&transfer_limit, ctx.program_id)?;
+        if !allowed {
+            queue_transfer(
+                ctx,
+                &accs.payer,
+                &mut pending,
+                &PendingTransferDerivationData {
+                    message: *accs.message.key,
+                },
+                &transfer_limit,
+                &accs.clock,
+                data.nonce,
+                data.target_chain,
+                amount,
+                payload.try_to_vec()?,
+            )?;
+            return Peel::persist(&pending, ctx.program_id);
+        }
+    }
+
+    let post_message = CpiContext::new(
+        accs.config.wormhole_bridge,
+        PostMessageCpi {
+            bridge: accs.bridge.info().clone(),
+            message: accs.message.info().clone(),
+            emitter: accs.emitter.info().clone(),
+            sequence: accs.sequence.info().clone(),
+            payer: accs.payer.info().clone(),
+            fee_collector: accs.fee_collector.info().clone(),
+            clock: accs.clock.info().clone(),
+        },
+    )
+    .remaining(vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
+    ]);
+    post_message.invoke_seeded(
+        ctx,
         bridge::instruction::Instruction::PostMessage,
         PostMessageData {
             nonce: data.nonce,
             payload: payload.try_to_vec()?,
             consistency_level: ConsistencyLevel::Finalized,
         },
-    );
+        &accs.emitter,
+        None,
+    )?;
 
-    let ix = Instruction::new_with_bytes(
-        accs.config.wormhole_bridge,
-        params.try_to_vec()?.as_slice(),
-        vec![
-            AccountMeta::new(*accs.bridge.info().key, false),
-            AccountMeta::new(*accs.message.key, true),
-            AccountMeta::new_readonly(*accs.emitter.key, true),
-            AccountMeta::new(*accs.sequence.key, false),
-            AccountMeta::new(*accs.payer.key, true),
-            AccountMeta::new(*accs.fee_collector.key, false),
-            AccountMeta::new_readonly(*accs.clock.info().key, false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-            AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
-        ],
-    );
-    invoke_seeded(&ix, ctx, &accs.emitter, None)?;
+    emit(&TokenBridgeEvent::TransferOut {
+        message: *accs.message.key,
+        mint: *accs.mint.info().key,
+        token_chain: payload.token_chain,
+        token_address: payload.token_address,
+        amount: payload.amount.as_u64(),
+        fee: 0,
+        bridged_amount,
+        target_chain: payload.to_chain,
+        target_address: payload.to,
+        with_payload: true,
+    })?;
 
     Ok(())
 }
@@ -250,9 +326,12 @@ pub struct TransferWrappedWithPayload<'b> {
     pub payer: Mut<Signer<AccountInfo<'b>>>,
     pub config: ConfigAccount<'b, { AccountState::Initialized }>,
 
+    #[constraint(accs.from.owner == *accs.from_owner.key, TokenBridgeError::WrongAccountOwner)]
+    #[constraint(accs.from.mint == *accs.mint.info().key, TokenBridgeError::InvalidMint)]
     pub from: Mut<Data<'b, SplAccount, { AccountState::Initialized }>>,
     pub from_owner: MaybeMut<Signer<Info<'b>>>,
     pub mint: Mut<WrappedMint<'b, { AccountState::Initialized }>>,
+    #[seeds]
     pub wrapped_meta: WrappedTokenMeta<'b, { AccountState::Initialized }>,
 
     pub authority_signer: AuthoritySigner<'b>,
@@ -276,6 +355,9 @@ pub struct TransferWrappedWithPayload<'b> {
 
     /// See [`derive_sender_address`]
     pub sender: SenderAccount<'b>,
+
+    /// Accounts added after the original layout, found by key: the pause config, see [`find_pause`]
+    pub trailing: Remaining<MaybeMut<Info<'b>>>,
 }
 
 impl<'a> From<&TransferWrappedWithPayload<'a>> for WrappedDerivationData {
@@ -316,15 +398,16 @@ pub fn transfer_wrapped_with_payload(
         return Err(InvalidChain.into());
     }
 
-    let derivation_data: WrappedMetaDerivationData = (&*accs).into();
+    let pause = find_pause(ctx, &accs.trailing)?;
+    if pause.outbound_paused(accs.mint.info().key, data.target_chain) {
+        return Err(TransfersPaused.into());
+    }
+
     verify_and_execute_wrapped_transfers(
         ctx,
-        &derivation_data,
         &accs.payer,
         &accs.from,
-        &accs.from_owner,
         &accs.mint,
-        &accs.wrapped_meta,
         &accs.authority_signer,
         &accs.bridge,
         &accs.fee_collector,
@@ -342,31 +425,46 @@ pub fn transfer_wrapped_with_payload(
         from_address: accs.sender.derive_sender_address(&data.cpi_program_id)?,
         payload: data.payload,
     };
-    let params = (
+    let post_message = CpiContext::new(
+        accs.config.wormhole_bridge,
+        PostMessageCpi {
+            bridge: accs.bridge.info().clone(),
+            message: accs.message.info().clone(),
+            emitter: accs.emitter.info().clone(),
+            sequence: accs.sequence.info().clone(),
+            payer: accs.payer.info().clone(),
+            fee_collector: accs.fee_collector.info().clone(),
+            clock: accs.clock.info().clone(),
+        },
+    )
+    .remaining(vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
+    ]);
+    post_message.invoke_seeded(
+        ctx,
         bridge::instruction::Instruction::PostMessage,
         PostMessageData {
             nonce: data.nonce,
             payload: payload.try_to_vec()?,
             consistency_level: ConsistencyLevel::Finalized,
         },
-    );
+        &accs.emitter,
+        None,
+    )?;
 
-    let ix = Instruction::new_with_bytes(
-        accs.config.wormhole_bridge,
-        params.try_to_vec()?.as_slice(),
-        vec![
-            AccountMeta::new(*accs.bridge.info().key, false),
-            AccountMeta::new(*accs.message.key, true),
-            AccountMeta::new_readonly(*accs.emitter.key, true),
-            AccountMeta::new(*accs.sequence.key, false),
-            AccountMeta::new(*accs.payer.key, true),
-            AccountMeta::new(*accs.fee_collector.key, false),
-            AccountMeta::new_readonly(*accs.clock.info().key, false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-            AccountMeta::new_readonly(solana_program::sysvar::rent::ID, false),
-        ],
-    );
-    invoke_seeded(&ix, ctx, &accs.emitter, None)?;
+    emit(&TokenBridgeEvent::TransferOut {
+        message: *accs.message.key,
+        mint: *accs.mint.info().key,
+        token_chain: payload.token_chain,
+        token_address: payload.token_address,
+        amount: payload.amount.as_u64(),
+        fee: 0,
+        bridged_amount: data.amount,
+        target_chain: payload.to_chain,
+        target_address: payload.to,
+        with_payload: true,
+    })?;
 
     Ok(())
 }
@@ -0,0 +1,7 @@
+//! Print the IDL of the program as JSON, see `solitaire::idl`.
+
+fn main() {
+    let idl =
+        token_bridge::instruction::idl().with_errors(token_bridge::TokenBridgeError::ERROR_CODES);
+    println!("{}", idl.to_json());
+}
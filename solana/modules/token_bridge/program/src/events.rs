@@ -0,0 +1,98 @@
+//! Structured event records emitted by the token bridge. Records share the versioned encoding of
+//! the core bridge events, see `bridge::events`.
+
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use solana_program::pubkey::Pubkey;
+
+use crate::types::{
+    Address,
+    ChainID,
+    PauseTarget,
+};
+
+pub use bridge::events::{
+    decode,
+    emit,
+    EVENT_VERSION,
+};
+
+#[cfg(feature = "client")]
+pub use bridge::events::{
+    decode_logs,
+    program_data,
+};
+
+/// Amounts are the normalized (at most 8 decimals) amounts carried in the transfer payload, while
+/// `bridged_amount` is the amount that actually moved on Solana, in units of the local mint.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum TokenBridgeEvent {
+    TransferOut {
+        message: Pubkey,
+        mint: Pubkey,
+        token_chain: ChainID,
+        token_address: Address,
+        amount: u64,
+        fee: u64,
+        bridged_amount: u64,
+        target_chain: ChainID,
+        target_address: Address,
+        with_payload: bool,
+    },
+    TransferCompleted {
+        vaa: Pubkey,
+        mint: Pubkey,
+        recipient: Pubkey,
+        token_chain: ChainID,
+        token_address: Address,
+        amount: u64,
+        fee: u64,
+        bridged_amount: u64,
+    },
+    ChainRegistered {
+        chain: ChainID,
+        endpoint_address: Address,
+    },
+    TransferLimitSet {
+        mint: Pubkey,
+        daily_limit: u64,
+        large_transfer_threshold: u64,
+        release_delay: u64,
+    },
+    TransferQueued {
+        pending: Pubkey,
+        mint: Pubkey,
+        amount: u64,
+        release_after: i64,
+    },
+    TransferReleased {
+        pending: Pubkey,
+        message: Pubkey,
+        mint: Pubkey,
+    },
+    PauseSet {
+        target: PauseTarget,
+        paused: bool,
+    },
+    ChainUpdated {
+        chain: ChainID,
+        previous_address: Address,
+        endpoint_address: Address,
+    },
+    ChainDeregistered {
+        chain: ChainID,
+        endpoint_address: Address,
+    },
+    /// Amounts are in units of the mint.
+    CustodyReconciled {
+        mint: Pubkey,
+        custody_balance: u64,
+        outstanding: u64,
+    },
+}
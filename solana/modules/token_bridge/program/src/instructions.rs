@@ -4,13 +4,24 @@ use crate::{
         ConfigAccount,
         CustodyAccount,
         CustodyAccountDerivationData,
+        CustodyLedgerAccount,
+        CustodyLedgerDerivationData,
         CustodySigner,
         EmitterAccount,
         Endpoint,
         EndpointDerivationData,
         MintSigner,
+        PauseAccount,
+        PendingTransferAccount,
+        PendingTransferDerivationData,
         SplTokenMeta,
         SplTokenMetaDerivationData,
+        TemporaryAccount,
+        TemporaryAccountDerivationData,
+        TransferLimitAccount,
+        TransferLimitDerivationData,
+        WrappedAttestationAccount,
+        WrappedAttestationDerivationData,
         WrappedDerivationData,
         WrappedMetaDerivationData,
         WrappedMint,
@@ -18,23 +29,39 @@ use crate::{
     },
     api::{
         complete_transfer::{
+            CompleteNativeByRecipientData,
             CompleteNativeData,
+            CompleteNativeRelayedData,
+            CompleteNativeSolData,
+            CompleteWrappedByRecipientData,
             CompleteWrappedData,
+            CompleteWrappedRelayedData,
         },
         AttestTokenData,
         CreateWrappedData,
+        DeregisterChainData,
+        ReconcileCustodyData,
         RegisterChainData,
+        ReleasePendingTransferData,
         SenderAccount,
+        SetPauseData,
+        SetTransferLimitData,
         TransferNativeData,
+        TransferNativeSolData,
         TransferWrappedData,
+        UpdateChainData,
         UpgradeContractData,
     },
     messages::{
         PayloadAssetMeta,
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceSetTransferLimit,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
         PayloadTransferWithPayload,
     },
+    token,
     CompleteNativeWithPayloadData,
     CompleteWrappedWithPayloadData,
     TransferNativeWithPayloadData,
@@ -46,6 +73,8 @@ use bridge::{
         Bridge,
         Claim,
         ClaimDerivationData,
+        DenyList,
+        DenyListDerivationData,
         FeeCollector,
         Sequence,
         SequenceDerivationData,
@@ -64,6 +93,7 @@ use solana_program::{
 use solitaire::{
     processors::seeded::Seeded,
     AccountState,
+    ErrorCode,
 };
 
 pub fn initialize(
@@ -98,7 +128,9 @@ pub fn complete_native(
     data: CompleteNativeData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
     let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
         &EndpointDerivationData {
             emitter_chain: vaa.emitter_chain,
@@ -110,7 +142,15 @@ pub fn complete_native(
         &CustodyAccountDerivationData { mint },
         &program_id,
     );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
     let custody_signer_key = CustodySigner::key(None, &program_id);
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
 
     Ok(Instruction {
         program_id,
@@ -119,13 +159,10 @@ pub fn complete_native(
             AccountMeta::new_readonly(config_key, false),
             message_acc,
             claim_acc,
+            deny_list_acc,
             AccountMeta::new_readonly(endpoint, false),
             AccountMeta::new(to, false),
-            if let Some(fee_r) = fee_recipient {
-                AccountMeta::new(fee_r, false)
-            } else {
-                AccountMeta::new(to, false)
-            },
+            AccountMeta::new(fee_recipient.unwrap_or(to), false),
             AccountMeta::new(custody_key, false),
             AccountMeta::new_readonly(mint, false),
             AccountMeta::new_readonly(custody_signer_key, false),
@@ -135,11 +172,237 @@ pub fn complete_native(
             // Program
             AccountMeta::new_readonly(bridge_id, false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new(transfer_limit_key, false),
         ],
         data: (crate::instruction::Instruction::CompleteNative, data).try_to_vec()?,
     })
 }
 
+/// `to` may be an associated token account of `to_owner` that doesn't exist yet, the payer creates
+/// it. The fee goes to the associated token account of `fee_recipient`, the payer by default.
+#[allow(clippy::too_many_arguments)]
+pub fn complete_native_relayed(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    to: Pubkey,
+    to_owner: Pubkey,
+    fee_recipient: Option<Pubkey>,
+    mint: Pubkey,
+    token_program: Pubkey,
+    data: CompleteNativeRelayedData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        &program_id,
+    );
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData { mint },
+        &program_id,
+    );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
+    let custody_signer_key = CustodySigner::key(None, &program_id);
+    let fee_recipient = fee_recipient.unwrap_or(payer);
+    let fee_account = token::associated_address(&fee_recipient, &mint, &token_program);
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new_readonly(pause_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(to_owner, false),
+            AccountMeta::new_readonly(fee_recipient, false),
+            AccountMeta::new(fee_account, false),
+            AccountMeta::new(custody_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(custody_signer_key, false),
+            AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
+            AccountMeta::new(transfer_limit_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: (crate::instruction::Instruction::CompleteNativeRelayed, data).try_to_vec()?,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn complete_native_by_recipient(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    to: Pubkey,
+    to_owner: Pubkey,
+    mint: Pubkey,
+    data: CompleteNativeByRecipientData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        &program_id,
+    );
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData { mint },
+        &program_id,
+    );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
+    let custody_signer_key = CustodySigner::key(None, &program_id);
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new_readonly(pause_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(to_owner, true),
+            AccountMeta::new(custody_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(custody_signer_key, false),
+            AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
+            AccountMeta::new(transfer_limit_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
+        ],
+        data: (
+            crate::instruction::Instruction::CompleteNativeByRecipient,
+            data,
+        )
+            .try_to_vec()?,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn complete_native_sol(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    to: Pubkey,
+    fee_recipient: Option<Pubkey>,
+    data: CompleteNativeSolData,
+) -> solitaire::Result<Instruction> {
+    let mint = spl_token::native_mint::id();
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        &program_id,
+    );
+    let temporary_key = TemporaryAccount::<'_, { AccountState::Uninitialized }>::key(
+        &TemporaryAccountDerivationData {
+            message: message_key,
+        },
+        &program_id,
+    );
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData { mint },
+        &program_id,
+    );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
+    let custody_signer_key = CustodySigner::key(None, &program_id);
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new_readonly(pause_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new(fee_recipient.unwrap_or(payer), false),
+            AccountMeta::new(temporary_key, false),
+            AccountMeta::new(custody_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(custody_signer_key, false),
+            AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
+            AccountMeta::new(transfer_limit_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
+        ],
+        data: (crate::instruction::Instruction::CompleteNativeSol, data).try_to_vec()?,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn complete_native_with_payload(
     program_id: Pubkey,
@@ -154,7 +417,9 @@ pub fn complete_native_with_payload(
     data: CompleteNativeWithPayloadData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
     let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
         &EndpointDerivationData {
             emitter_chain: vaa.emitter_chain,
@@ -166,7 +431,15 @@ pub fn complete_native_with_payload(
         &CustodyAccountDerivationData { mint },
         &program_id,
     );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
     let custody_signer_key = CustodySigner::key(None, &program_id);
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
 
     Ok(Instruction {
         program_id,
@@ -175,6 +448,7 @@ pub fn complete_native_with_payload(
             AccountMeta::new_readonly(config_key, false),
             message_acc,
             claim_acc,
+            deny_list_acc,
             AccountMeta::new_readonly(endpoint, false),
             AccountMeta::new(to, false),
             AccountMeta::new_readonly(to_owner, true),
@@ -192,6 +466,11 @@ pub fn complete_native_with_payload(
             // Program
             AccountMeta::new_readonly(bridge_id, false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new(transfer_limit_key, false),
         ],
         data: (
             crate::instruction::Instruction::CompleteNativeWithPayload,
@@ -201,6 +480,8 @@ pub fn complete_native_with_payload(
     })
 }
 
+/// `to` may be an associated token account of `to_owner` that doesn't exist yet, the payer creates
+/// it.
 #[allow(clippy::too_many_arguments)]
 pub fn complete_wrapped(
     program_id: Pubkey,
@@ -214,7 +495,75 @@ pub fn complete_wrapped(
     data: CompleteWrappedData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        &program_id,
+    );
+    let mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedDerivationData {
+            token_chain: payload.token_chain,
+            token_address: payload.token_address,
+        },
+        &program_id,
+    );
+    let meta_key = WrappedTokenMeta::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedMetaDerivationData { mint_key },
+        &program_id,
+    );
+    let mint_authority_key = MintSigner::key(None, &program_id);
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new(fee_recipient.unwrap_or(to), false),
+            AccountMeta::new(mint_key, false),
+            AccountMeta::new_readonly(meta_key, false),
+            AccountMeta::new_readonly(mint_authority_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
+        ],
+        data: (crate::instruction::Instruction::CompleteWrapped, data).try_to_vec()?,
+    })
+}
+
+/// `to` may be an associated token account of `to_owner` that doesn't exist yet, the payer creates
+/// it. The fee goes to the associated token account of `fee_recipient`, the payer by default.
+#[allow(clippy::too_many_arguments)]
+pub fn complete_wrapped_relayed(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadTransfer,
+    to: Pubkey,
+    to_owner: Pubkey,
+    fee_recipient: Option<Pubkey>,
+    data: CompleteWrappedRelayedData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
     let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
         &EndpointDerivationData {
             emitter_chain: vaa.emitter_chain,
@@ -233,218 +582,574 @@ pub fn complete_wrapped(
         &WrappedMetaDerivationData { mint_key },
         &program_id,
     );
-    let mint_authority_key = MintSigner::key(None, &program_id);
+    let mint_authority_key = MintSigner::key(None, &program_id);
+    let fee_recipient = fee_recipient.unwrap_or(payer);
+    let fee_account = token::associated_address(&fee_recipient, &mint_key, &spl_token::id());
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new_readonly(pause_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(to_owner, false),
+            AccountMeta::new_readonly(fee_recipient, false),
+            AccountMeta::new(fee_account, false),
+            AccountMeta::new(mint_key, false),
+            AccountMeta::new_readonly(meta_key, false),
+            AccountMeta::new_readonly(mint_authority_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: (
+            crate::instruction::Instruction::CompleteWrappedRelayed,
+            data,
+        )
+            .try_to_vec()?,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn complete_wrapped_by_recipient(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadTransfer,
+    to: Pubkey,
+    to_owner: Pubkey,
+    data: CompleteWrappedByRecipientData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        &program_id,
+    );
+    let mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedDerivationData {
+            token_chain: payload.token_chain,
+            token_address: payload.token_address,
+        },
+        &program_id,
+    );
+    let meta_key = WrappedTokenMeta::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedMetaDerivationData { mint_key },
+        &program_id,
+    );
+    let mint_authority_key = MintSigner::key(None, &program_id);
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new_readonly(pause_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(to_owner, true),
+            AccountMeta::new(mint_key, false),
+            AccountMeta::new_readonly(meta_key, false),
+            AccountMeta::new_readonly(mint_authority_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: (
+            crate::instruction::Instruction::CompleteWrappedByRecipient,
+            data,
+        )
+            .try_to_vec()?,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn complete_wrapped_with_payload(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadTransferWithPayload,
+    to: Pubkey,
+    to_owner: Pubkey,
+    fee_recipient: Option<Pubkey>,
+    data: CompleteWrappedWithPayloadData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        &program_id,
+    );
+    let mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedDerivationData {
+            token_chain: payload.token_chain,
+            token_address: payload.token_address,
+        },
+        &program_id,
+    );
+    let meta_key = WrappedTokenMeta::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedMetaDerivationData { mint_key },
+        &program_id,
+    );
+    let mint_authority_key = MintSigner::key(None, &program_id);
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(to_owner, true),
+            if let Some(fee_r) = fee_recipient {
+                AccountMeta::new(fee_r, false)
+            } else {
+                AccountMeta::new(to, false)
+            },
+            AccountMeta::new(mint_key, false),
+            AccountMeta::new_readonly(meta_key, false),
+            AccountMeta::new_readonly(mint_authority_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
+        ],
+        data: (
+            crate::instruction::Instruction::CompleteWrappedWithPayload,
+            data,
+        )
+            .try_to_vec()?,
+    })
+}
+
+pub fn create_wrapped(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadAssetMeta,
+    data: CreateWrappedData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa.clone());
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        &program_id,
+    );
+    let mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedDerivationData {
+            token_chain: payload.token_chain,
+            token_address: payload.token_address,
+        },
+        &program_id,
+    );
+    let mint_meta_key = WrappedTokenMeta::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedMetaDerivationData { mint_key },
+        &program_id,
+    );
+    let attestation_key = WrappedAttestationAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &WrappedAttestationDerivationData { mint: mint_key },
+        &program_id,
+    );
+    let mint_authority_key = MintSigner::key(None, &program_id);
+    // SPL Metadata
+    let spl_metadata = SplTokenMeta::key(
+        &SplTokenMetaDerivationData { mint: mint_key },
+        &spl_token_metadata::id(),
+    );
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new_readonly(endpoint, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            AccountMeta::new(mint_key, false),
+            AccountMeta::new(mint_meta_key, false),
+            AccountMeta::new(spl_metadata, false),
+            AccountMeta::new_readonly(mint_authority_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_metadata::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
+            AccountMeta::new(attestation_key, false),
+        ],
+        data: (crate::instruction::Instruction::CreateWrapped, data).try_to_vec()?,
+    })
+}
+
+pub fn register_chain(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadGovernanceRegisterChain,
+    data: RegisterChainData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: payload.chain,
+            emitter_address: payload.endpoint_address,
+        },
+        &program_id,
+    );
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new(endpoint, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            // Dependencies
+            AccountMeta::new(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+        ],
+        data: (crate::instruction::Instruction::RegisterChain, data).try_to_vec()?,
+    })
+}
+
+/// Register the endpoint replacing the one registered for `payload.chain`, `registered_address` is
+/// the address of the endpoint currently registered, which is closed.
+#[allow(clippy::too_many_arguments)]
+pub fn update_chain(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadGovernanceUpdateChain,
+    registered_address: ForeignAddress,
+    data: UpdateChainData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
+    let registered = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: payload.chain,
+            emitter_address: registered_address,
+        },
+        &program_id,
+    );
+    let endpoint = Endpoint::<'_, { AccountState::Uninitialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: payload.chain,
+            emitter_address: payload.endpoint_address,
+        },
+        &program_id,
+    );
+    let fee_collector_key = FeeCollector::key(None, &bridge_id);
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new(registered, false),
+            AccountMeta::new(endpoint, false),
+            AccountMeta::new(fee_collector_key, false),
+            message_acc,
+            claim_acc,
+            deny_list_acc,
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+        ],
+        data: (crate::instruction::Instruction::UpdateChain, data).try_to_vec()?,
+    })
+}
+
+/// Remove the endpoint registered for `payload.chain` at `payload.endpoint_address`, its rent goes
+/// to the fee collector of the core bridge.
+pub fn deregister_chain(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    vaa: PostVAAData,
+    payload: PayloadGovernanceDeregisterChain,
+    data: DeregisterChainData,
+) -> solitaire::Result<Instruction> {
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
+    let registered = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: payload.chain,
+            emitter_address: payload.endpoint_address,
+        },
+        &program_id,
+    );
+    let fee_collector_key = FeeCollector::key(None, &bridge_id);
 
     Ok(Instruction {
         program_id,
         accounts: vec![
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new(registered, false),
+            AccountMeta::new(fee_collector_key, false),
             message_acc,
             claim_acc,
-            AccountMeta::new_readonly(endpoint, false),
-            AccountMeta::new(to, false),
-            if let Some(fee_r) = fee_recipient {
-                AccountMeta::new(fee_r, false)
-            } else {
-                AccountMeta::new(to, false)
-            },
-            AccountMeta::new(mint_key, false),
-            AccountMeta::new_readonly(meta_key, false),
-            AccountMeta::new_readonly(mint_authority_key, false),
+            deny_list_acc,
             // Dependencies
             AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
             // Program
             AccountMeta::new_readonly(bridge_id, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
         ],
-        data: (crate::instruction::Instruction::CompleteWrapped, data).try_to_vec()?,
+        data: (crate::instruction::Instruction::DeregisterChain, data).try_to_vec()?,
     })
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn complete_wrapped_with_payload(
+pub fn set_transfer_limit(
     program_id: Pubkey,
     bridge_id: Pubkey,
     payer: Pubkey,
     message_key: Pubkey,
     vaa: PostVAAData,
-    payload: PayloadTransferWithPayload,
-    to: Pubkey,
-    to_owner: Pubkey,
-    fee_recipient: Option<Pubkey>,
-    data: CompleteWrappedWithPayloadData,
+    payload: PayloadGovernanceSetTransferLimit,
+    data: SetTransferLimitData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
-    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
-        &EndpointDerivationData {
-            emitter_chain: vaa.emitter_chain,
-            emitter_address: vaa.emitter_address,
-        },
-        &program_id,
-    );
-    let mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
-        &WrappedDerivationData {
-            token_chain: payload.token_chain,
-            token_address: payload.token_address,
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData {
+            mint: Pubkey::new_from_array(payload.mint),
         },
         &program_id,
     );
-    let meta_key = WrappedTokenMeta::<'_, { AccountState::Uninitialized }>::key(
-        &WrappedMetaDerivationData { mint_key },
-        &program_id,
-    );
-    let mint_authority_key = MintSigner::key(None, &program_id);
 
     Ok(Instruction {
         program_id,
         accounts: vec![
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new(transfer_limit_key, false),
             message_acc,
             claim_acc,
-            AccountMeta::new_readonly(endpoint, false),
-            AccountMeta::new(to, false),
-            AccountMeta::new_readonly(to_owner, true),
-            if let Some(fee_r) = fee_recipient {
-                AccountMeta::new(fee_r, false)
-            } else {
-                AccountMeta::new(to, false)
-            },
-            AccountMeta::new(mint_key, false),
-            AccountMeta::new_readonly(meta_key, false),
-            AccountMeta::new_readonly(mint_authority_key, false),
+            deny_list_acc,
             // Dependencies
             AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
             // Program
             AccountMeta::new_readonly(bridge_id, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
         ],
-        data: (
-            crate::instruction::Instruction::CompleteWrappedWithPayload,
-            data,
-        )
-            .try_to_vec()?,
+        data: (crate::instruction::Instruction::SetTransferLimit, data).try_to_vec()?,
     })
 }
 
-pub fn create_wrapped(
+pub fn set_pause(
     program_id: Pubkey,
     bridge_id: Pubkey,
     payer: Pubkey,
     message_key: Pubkey,
     vaa: PostVAAData,
-    payload: PayloadAssetMeta,
-    data: CreateWrappedData,
+    data: SetPauseData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa.clone());
-    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
-        &EndpointDerivationData {
-            emitter_chain: vaa.emitter_chain,
-            emitter_address: vaa.emitter_address,
-        },
-        &program_id,
-    );
-    let mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
-        &WrappedDerivationData {
-            token_chain: payload.token_chain,
-            token_address: payload.token_address,
-        },
-        &program_id,
-    );
-    let mint_meta_key = WrappedTokenMeta::<'_, { AccountState::Uninitialized }>::key(
-        &WrappedMetaDerivationData { mint_key },
-        &program_id,
-    );
-    let mint_authority_key = MintSigner::key(None, &program_id);
-    // SPL Metadata
-    let spl_metadata = SplTokenMeta::key(
-        &SplTokenMetaDerivationData { mint: mint_key },
-        &spl_token_metadata::id(),
-    );
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let fee_collector_key = FeeCollector::key(None, &bridge_id);
+    let (message_acc, claim_acc, deny_list_acc) =
+        claimable_vaa(program_id, bridge_id, message_key, vaa);
 
     Ok(Instruction {
         program_id,
         accounts: vec![
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(config_key, false),
-            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new(pause_key, false),
+            AccountMeta::new(fee_collector_key, false),
             message_acc,
             claim_acc,
-            AccountMeta::new(mint_key, false),
-            AccountMeta::new(mint_meta_key, false),
-            AccountMeta::new(spl_metadata, false),
-            AccountMeta::new_readonly(mint_authority_key, false),
+            deny_list_acc,
             // Dependencies
             AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
             // Program
             AccountMeta::new_readonly(bridge_id, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(spl_token_metadata::id(), false),
         ],
-        data: (crate::instruction::Instruction::CreateWrapped, data).try_to_vec()?,
+        data: (crate::instruction::Instruction::SetPause, data).try_to_vec()?,
     })
 }
 
-pub fn register_chain(
+/// Release a transfer queued by the governor, `queued_message` is the message account the
+/// transfer was sent with and `message_key` the account the released message is posted to.
+#[allow(clippy::too_many_arguments)]
+pub fn release_pending_transfer(
     program_id: Pubkey,
     bridge_id: Pubkey,
     payer: Pubkey,
+    queued_message: Pubkey,
+    rent_payer: Pubkey,
+    mint: Pubkey,
     message_key: Pubkey,
-    vaa: PostVAAData,
-    payload: PayloadGovernanceRegisterChain,
-    data: RegisterChainData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
-    let (message_acc, claim_acc) = claimable_vaa(program_id, message_key, vaa);
-    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
-        &EndpointDerivationData {
-            emitter_chain: payload.chain,
-            emitter_address: payload.endpoint_address,
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let pending_key = PendingTransferAccount::<'_, { AccountState::Initialized }>::key(
+        &PendingTransferDerivationData {
+            message: queued_message,
         },
         &program_id,
     );
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
+    let emitter_key = EmitterAccount::key(None, &program_id);
+
+    // Bridge keys
+    let bridge_config = Bridge::<'_, { AccountState::Uninitialized }>::key(None, &bridge_id);
+    let sequence_key = Sequence::key(
+        &SequenceDerivationData {
+            emitter_key: &emitter_key,
+        },
+        &bridge_id,
+    );
+    let fee_collector_key = FeeCollector::key(None, &bridge_id);
 
     Ok(Instruction {
         program_id,
         accounts: vec![
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(config_key, false),
-            AccountMeta::new(endpoint, false),
-            message_acc,
-            claim_acc,
+            AccountMeta::new_readonly(pause_key, false),
+            AccountMeta::new(pending_key, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new(transfer_limit_key, false),
+            AccountMeta::new(bridge_config, false),
+            AccountMeta::new(message_key, true),
+            AccountMeta::new_readonly(emitter_key, false),
+            AccountMeta::new(sequence_key, false),
+            AccountMeta::new(fee_collector_key, false),
+            AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
             // Dependencies
-            AccountMeta::new(solana_program::sysvar::rent::id(), false),
-            AccountMeta::new(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
             // Program
             AccountMeta::new_readonly(bridge_id, false),
         ],
-        data: (crate::instruction::Instruction::RegisterChain, data).try_to_vec()?,
+        data: (
+            crate::instruction::Instruction::ReleasePendingTransfer,
+            ReleasePendingTransferData {},
+        )
+            .try_to_vec()?,
+    })
+}
+
+/// Compare the custody balance of `mint` with the tokens owed to other chains, fails if custody
+/// holds less. Modifies no accounts, so it can be simulated.
+pub fn reconcile_custody(program_id: Pubkey, mint: Pubkey) -> solitaire::Result<Instruction> {
+    let custody_key = CustodyAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyAccountDerivationData { mint },
+        &program_id,
+    );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(custody_key, false),
+            AccountMeta::new_readonly(ledger_key, false),
+        ],
+        data: (
+            crate::instruction::Instruction::ReconcileCustody,
+            ReconcileCustodyData {},
+        )
+            .try_to_vec()?,
     })
 }
 
 fn claimable_vaa(
+    program_id: Pubkey,
     bridge_id: Pubkey,
     message_key: Pubkey,
     vaa: PostVAAData,
-) -> (AccountMeta, AccountMeta) {
+) -> (AccountMeta, AccountMeta, AccountMeta) {
     let claim_key = Claim::<'_, { AccountState::Initialized }>::key(
         &ClaimDerivationData {
             emitter_address: vaa.emitter_address,
             emitter_chain: vaa.emitter_chain,
             sequence: vaa.sequence,
         },
+        &program_id,
+    );
+    let deny_list_key = DenyList::<'_, { AccountState::MaybeInitialized }>::key(
+        &DenyListDerivationData,
         &bridge_id,
     );
 
     (
         AccountMeta::new_readonly(message_key, false),
         AccountMeta::new(claim_key, false),
+        AccountMeta::new_readonly(deny_list_key, false),
     )
 }
 
@@ -464,10 +1169,15 @@ fn claimable_vaa(
 /// | emitter          | PDA(program_id, \["emitter"\])                                    | false  |
 /// | sequence         | PDA(bridge_id,  \["Sequence", emitter\])                          | false  |
 /// | fee_collector    | PDA(bridge_id,  \["fee_collector"\])                              | false  |
+/// | clock            | clock sysvar                                                      | false  |
 /// | rent             | rent sysvar                                                       | false  |
 /// | system_program   | system program                                                    | false  |
 /// | bridge_id        | bridge_id program                                                 | false  |
 /// | spl_token        | spl_token program                                                 | false  |
+/// | pause            | PDA(program_id, \["pause"\])                                      | false  |
+/// | ledger           | PDA(program_id, \["ledger", mint\])                               | false  |
+/// | transfer_limit   | PDA(program_id, \["governor", mint\])                             | false  |
+/// | pending          | PDA(program_id, \["pending", message\])                           | false  |
 pub fn transfer_native(
     program_id: Pubkey,
     bridge_id: Pubkey,
@@ -478,10 +1188,15 @@ pub fn transfer_native(
     data: TransferNativeData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
     let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
         &CustodyAccountDerivationData { mint },
         &program_id,
     );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
 
     let authority_signer_key = AuthoritySigner::key(None, &program_id);
     let custody_signer_key = CustodySigner::key(None, &program_id);
@@ -497,6 +1212,18 @@ pub fn transfer_native(
     );
     let fee_collector_key = FeeCollector::key(None, &bridge_id);
 
+    // Governor keys
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
+    let pending_key = PendingTransferAccount::<'_, { AccountState::Uninitialized }>::key(
+        &PendingTransferDerivationData {
+            message: message_key,
+        },
+        &program_id,
+    );
+
     let instruction = crate::instruction::Instruction::TransferNative;
 
     Ok(Instruction {
@@ -521,6 +1248,122 @@ pub fn transfer_native(
             // Program
             AccountMeta::new_readonly(bridge_id, false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new(transfer_limit_key, false),
+            AccountMeta::new(pending_key, false),
+        ],
+        data: (instruction, data).try_to_vec()?,
+    })
+}
+
+/// Required accounts
+///
+/// | name             | account                                                           | signer |
+/// |------------------+-------------------------------------------------------------------+--------|
+/// | payer            | Pubkey                                                            | true   |
+/// | config           | PDA(program_id, \["config"\])                                     | false  |
+/// | pause            | PDA(program_id, \["pause"\])                                      | false  |
+/// | temporary        | PDA(program_id, \["temporary", message\])                         | false  |
+/// | mint             | native mint                                                       | false  |
+/// | custody          | PDA(program_id, \[mint\])                                         | false  |
+/// | ledger           | PDA(program_id, \["ledger", mint\])                               | false  |
+/// | authority_signer | PDA(program_id, \["authority_signer"\])                           | false  |
+/// | custody_signer   | PDA(program_id, \["custody_signer"\])                             | false  |
+/// | bridge_config    | PDA(bridge_id,  \["Bridge"\])                                     | false  |
+/// | message          | Pubkey                                                            | true   |
+/// | emitter          | PDA(program_id, \["emitter"\])                                    | false  |
+/// | sequence         | PDA(bridge_id,  \["Sequence", emitter\])                          | false  |
+/// | fee_collector    | PDA(bridge_id,  \["fee_collector"\])                              | false  |
+/// | clock            | clock sysvar                                                      | false  |
+/// | transfer_limit   | PDA(program_id, \["governor", mint\])                             | false  |
+/// | pending          | PDA(program_id, \["pending", message\])                           | false  |
+/// | rent             | rent sysvar                                                       | false  |
+/// | system_program   | system program                                                    | false  |
+/// | bridge_id        | bridge_id program                                                 | false  |
+/// | spl_token        | spl_token program                                                 | false  |
+pub fn transfer_native_sol(
+    program_id: Pubkey,
+    bridge_id: Pubkey,
+    payer: Pubkey,
+    message_key: Pubkey,
+    data: TransferNativeSolData,
+) -> solitaire::Result<Instruction> {
+    let mint = spl_token::native_mint::id();
+    let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
+    let temporary_key = TemporaryAccount::<'_, { AccountState::Uninitialized }>::key(
+        &TemporaryAccountDerivationData {
+            message: message_key,
+        },
+        &program_id,
+    );
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData { mint },
+        &program_id,
+    );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
+
+    let authority_signer_key = AuthoritySigner::key(None, &program_id);
+    let custody_signer_key = CustodySigner::key(None, &program_id);
+    let emitter_key = EmitterAccount::key(None, &program_id);
+
+    // Bridge keys
+    let bridge_config = Bridge::<'_, { AccountState::Uninitialized }>::key(None, &bridge_id);
+    let sequence_key = Sequence::key(
+        &SequenceDerivationData {
+            emitter_key: &emitter_key,
+        },
+        &bridge_id,
+    );
+    let fee_collector_key = FeeCollector::key(None, &bridge_id);
+
+    // Governor keys
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
+    let pending_key = PendingTransferAccount::<'_, { AccountState::Uninitialized }>::key(
+        &PendingTransferDerivationData {
+            message: message_key,
+        },
+        &program_id,
+    );
+
+    let instruction = crate::instruction::Instruction::TransferNativeSol;
+
+    Ok(Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(config_key, false),
+            AccountMeta::new_readonly(pause_key, false),
+            AccountMeta::new(temporary_key, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(custody_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new_readonly(authority_signer_key, false),
+            AccountMeta::new_readonly(custody_signer_key, false),
+            AccountMeta::new(bridge_config, false),
+            AccountMeta::new(message_key, true),
+            AccountMeta::new_readonly(emitter_key, false),
+            AccountMeta::new(sequence_key, false),
+            AccountMeta::new(fee_collector_key, false),
+            AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
+            AccountMeta::new(transfer_limit_key, false),
+            AccountMeta::new(pending_key, false),
+            // Dependencies
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            // Program
+            AccountMeta::new_readonly(bridge_id, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
         ],
         data: (instruction, data).try_to_vec()?,
     })
@@ -548,6 +1391,10 @@ pub fn transfer_native(
 /// | system_program   | system program                                                         | false  |
 /// | bridge_id        | bridge_id program                                                      | false  |
 /// | spl_token        | spl_token program                                                      | false  |
+/// | pause            | PDA(program_id, \["pause"\])                                           | false  |
+/// | ledger           | PDA(program_id, \["ledger", mint\])                                    | false  |
+/// | transfer_limit   | PDA(program_id, \["governor", mint\])                                  | false  |
+/// | pending          | PDA(program_id, \["pending", message\])                                | false  |
 pub fn transfer_native_with_payload(
     program_id: Pubkey,
     bridge_id: Pubkey,
@@ -558,10 +1405,15 @@ pub fn transfer_native_with_payload(
     data: TransferNativeWithPayloadData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
     let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
         &CustodyAccountDerivationData { mint },
         &program_id,
     );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &program_id,
+    );
 
     let authority_signer_key = AuthoritySigner::key(None, &program_id);
     let custody_signer_key = CustodySigner::key(None, &program_id);
@@ -582,6 +1434,18 @@ pub fn transfer_native_with_payload(
         None => payer,
     };
 
+    // Governor keys
+    let transfer_limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData { mint },
+        &program_id,
+    );
+    let pending_key = PendingTransferAccount::<'_, { AccountState::Uninitialized }>::key(
+        &PendingTransferDerivationData {
+            message: message_key,
+        },
+        &program_id,
+    );
+
     let instruction = crate::instruction::Instruction::TransferNativeWithPayload;
 
     Ok(Instruction {
@@ -607,6 +1471,12 @@ pub fn transfer_native_with_payload(
             // Program
             AccountMeta::new_readonly(bridge_id, false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_token_2022::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
+            AccountMeta::new(ledger_key, false),
+            AccountMeta::new(transfer_limit_key, false),
+            AccountMeta::new(pending_key, false),
         ],
         data: (instruction, data).try_to_vec()?,
     })
@@ -633,6 +1503,7 @@ pub fn transfer_native_with_payload(
 /// | system_program   | system program                                                         | false  |
 /// | bridge_id        | bridge_id program                                                      | false  |
 /// | spl_token        | spl_token program                                                      | false  |
+/// | pause            | PDA(program_id, \["pause"\])                                           | false  |
 #[allow(clippy::too_many_arguments)]
 pub fn transfer_wrapped(
     program_id: Pubkey,
@@ -646,6 +1517,7 @@ pub fn transfer_wrapped(
     data: TransferWrappedData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
 
     let wrapped_mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
         &WrappedDerivationData {
@@ -698,6 +1570,8 @@ pub fn transfer_wrapped(
             // Program
             AccountMeta::new_readonly(bridge_id, false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
         ],
         data: (instruction, data).try_to_vec()?,
     })
@@ -725,6 +1599,7 @@ pub fn transfer_wrapped(
 /// | system_program   | system program                                                         | false  |
 /// | bridge_id        | bridge_id program                                                      | false  |
 /// | spl_token        | spl_token program                                                      | false  |
+/// | pause            | PDA(program_id, \["pause"\])                                           | false  |
 #[allow(clippy::too_many_arguments)]
 pub fn transfer_wrapped_with_payload(
     program_id: Pubkey,
@@ -738,6 +1613,7 @@ pub fn transfer_wrapped_with_payload(
     data: TransferWrappedWithPayloadData,
 ) -> solitaire::Result<Instruction> {
     let config_key = ConfigAccount::<'_, { AccountState::Uninitialized }>::key(None, &program_id);
+    let pause_key = PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &program_id);
 
     let wrapped_mint_key = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
         &WrappedDerivationData {
@@ -796,6 +1672,8 @@ pub fn transfer_wrapped_with_payload(
             // Program
             AccountMeta::new_readonly(bridge_id, false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            // Found by key
+            AccountMeta::new_readonly(pause_key, false),
         ],
         data: (instruction, data).try_to_vec()?,
     })
@@ -863,8 +1741,10 @@ pub fn attest(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn upgrade_contract(
     program_id: Pubkey,
+    bridge_id: Pubkey,
     payer: Pubkey,
     payload_message: Pubkey,
     emitter: Pubkey,
@@ -880,6 +1760,10 @@ pub fn upgrade_contract(
         },
         &program_id,
     );
+    let deny_list = DenyList::<'_, { AccountState::MaybeInitialized }>::key(
+        &DenyListDerivationData,
+        &bridge_id,
+    );
 
     let (upgrade_authority, _) = Pubkey::find_program_address(&["upgrade".as_bytes()], &program_id);
 
@@ -895,6 +1779,7 @@ pub fn upgrade_contract(
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(payload_message, false),
             AccountMeta::new(claim, false),
+            AccountMeta::new_readonly(deny_list, false),
             AccountMeta::new_readonly(upgrade_authority, false),
             AccountMeta::new(spill, false),
             AccountMeta::new(new_contract, false),
@@ -914,3 +1799,8 @@ pub fn upgrade_contract(
             .unwrap(),
     }
 }
+
+/// Decode the `ProgramError::Custom` code of a failed token bridge instruction.
+pub fn decode_error(code: u32) -> Option<&'static ErrorCode> {
+    solitaire::decode_error(code, crate::TokenBridgeError::ERROR_CODES)
+}
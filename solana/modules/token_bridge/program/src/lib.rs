@@ -17,54 +17,95 @@ pub mod wasm;
 
 pub mod accounts;
 pub mod api;
+pub mod events;
 pub mod messages;
+#[cfg(feature = "instructions")]
+pub mod reconcile;
+pub mod token;
 pub mod types;
 
 pub use api::{
     attest_token,
     complete_native,
+    complete_native_by_recipient,
+    complete_native_relayed,
+    complete_native_sol,
     complete_native_with_payload,
     complete_wrapped,
+    complete_wrapped_by_recipient,
+    complete_wrapped_relayed,
     complete_wrapped_with_payload,
     create_wrapped,
+    deregister_chain,
     initialize,
+    reconcile_custody,
     register_chain,
+    release_pending_transfer,
+    set_pause,
+    set_transfer_limit,
     transfer_native,
+    transfer_native_sol,
     transfer_native_with_payload,
     transfer_wrapped,
     transfer_wrapped_with_payload,
+    update_chain,
     upgrade_contract,
     AttestToken,
     AttestTokenData,
     CompleteNative,
+    CompleteNativeByRecipient,
+    CompleteNativeByRecipientData,
     CompleteNativeData,
+    CompleteNativeRelayed,
+    CompleteNativeRelayedData,
+    CompleteNativeSol,
+    CompleteNativeSolData,
     CompleteNativeWithPayload,
     CompleteNativeWithPayloadData,
     CompleteWrapped,
+    CompleteWrappedByRecipient,
+    CompleteWrappedByRecipientData,
     CompleteWrappedData,
+    CompleteWrappedRelayed,
+    CompleteWrappedRelayedData,
     CompleteWrappedWithPayload,
     CompleteWrappedWithPayloadData,
     CreateWrapped,
     CreateWrappedData,
+    DeregisterChain,
+    DeregisterChainData,
     Initialize,
     InitializeData,
+    ReconcileCustody,
+    ReconcileCustodyData,
     RegisterChain,
     RegisterChainData,
+    ReleasePendingTransfer,
+    ReleasePendingTransferData,
+    SetPause,
+    SetPauseData,
+    SetTransferLimit,
+    SetTransferLimitData,
     TransferNative,
     TransferNativeData,
+    TransferNativeSol,
+    TransferNativeSolData,
     TransferNativeWithPayload,
     TransferNativeWithPayloadData,
     TransferWrapped,
     TransferWrappedData,
     TransferWrappedWithPayload,
     TransferWrappedWithPayloadData,
+    UpdateChain,
+    UpdateChainData,
     UpgradeContract,
     UpgradeContractData,
 };
 
 use solitaire::*;
 
-// Static list of invalid VAA Message accounts.
+// Static list of invalid VAA Message accounts. Kept as a permanent fallback to the bridge's
+// governed deny list, which is only checked when VAAs are posted.
 pub(crate) static INVALID_VAAS: &[&str; 7] = &[
     "28Tx7c3W8rggVNyUQEAL9Uq6pUng4xJLAeLA6V8nLH1Z",
     "32YEuzLCvSyHoV6NFpaTXfiAB8sHiAnYcvP2BBeLeGWq",
@@ -75,26 +116,60 @@ pub(crate) static INVALID_VAAS: &[&str; 7] = &[
     "GvAarWUV8khMLrTRouzBh3xSr8AeLDXxoKNJ6FgxGyg5",
 ];
 
+/// Errors are reported on chain as `ProgramError::Custom(code)` where the code is the discriminant
+/// below. Codes are part of the public interface: never reorder or reuse them.
+#[derive(SolitaireErrors)]
 pub enum TokenBridgeError {
-    AlreadyExecuted,
-    InvalidChain,
-    InvalidGovernanceKey,
-    InvalidMetadata,
-    InvalidMint,
-    InvalidPayload,
-    InvalidUTF8String,
-    TokenNotNative,
-    UninitializedMint,
-    WrongAccountOwner,
-    InvalidFee,
-    InvalidRecipient,
-    InvalidVAA,
-}
-
-impl From<TokenBridgeError> for SolitaireError {
-    fn from(t: TokenBridgeError) -> SolitaireError {
-        SolitaireError::Custom(t as u64)
-    }
+    /// VAA has already been executed
+    AlreadyExecuted = 0,
+    /// Chain is not supported by this transfer
+    InvalidChain = 1,
+    /// Governance VAA was not emitted by the governance emitter
+    InvalidGovernanceKey = 2,
+    /// Token metadata account is invalid
+    InvalidMetadata = 3,
+    /// Mint does not match the transfer
+    InvalidMint = 4,
+    /// VAA payload is malformed
+    InvalidPayload = 5,
+    /// String is not valid UTF-8
+    InvalidUTF8String = 6,
+    /// Token is not native to Solana
+    TokenNotNative = 7,
+    /// Mint has not been initialized
+    UninitializedMint = 8,
+    /// Token account is owned by the wrong wallet
+    WrongAccountOwner = 9,
+    /// Relayer fee exceeds the transferred amount
+    InvalidFee = 10,
+    /// Recipient does not match the transfer
+    InvalidRecipient = 11,
+    /// VAA is invalid
+    InvalidVAA = 12,
+    /// Transfer exceeds the governor limit of the mint
+    TransferLimitExceeded = 13,
+    /// Pending transfer cannot be released yet
+    TransferNotReleasable = 14,
+    /// Transfers are paused by governance
+    TransfersPaused = 15,
+    /// Mint uses a Token-2022 extension the bridge does not support
+    UnsupportedMintExtension = 16,
+    /// Amount overflows when scaled to the decimals of the mint
+    AmountOverflow = 17,
+    // 18 is reserved for rejecting relayers other than one named by the sender, transfer payloads
+    // cannot name a relayer yet.
+    /// Token account is not the associated token account of its owner
+    InvalidTokenAccount = 19,
+    /// Attestation is older than the metadata of the wrapped mint
+    StaleAttestation = 20,
+    /// Release exceeds the tokens custody owes to other chains
+    CustodyDeficit = 21,
+    // 22 is reserved, refreshes without the attestation account of the mint are accepted unordered
+    // as before it existed.
+    /// Account the instruction requires was not passed
+    MissingAccount = 23,
+    /// Pause action is not newer than the last one applied
+    StalePause = 24,
 }
 
 solitaire! {
@@ -111,4 +186,16 @@ solitaire! {
     CompleteWrappedWithPayload => complete_wrapped_with_payload,
     TransferWrappedWithPayload => transfer_wrapped_with_payload,
     TransferNativeWithPayload => transfer_native_with_payload,
+    SetTransferLimit => set_transfer_limit,
+    ReleasePendingTransfer => release_pending_transfer,
+    SetPause => set_pause,
+    UpdateChain => update_chain,
+    DeregisterChain => deregister_chain,
+    CompleteNativeByRecipient => complete_native_by_recipient,
+    CompleteWrappedByRecipient => complete_wrapped_by_recipient,
+    TransferNativeSol => transfer_native_sol,
+    CompleteNativeSol => complete_native_sol,
+    ReconcileCustody => reconcile_custody,
+    CompleteNativeRelayed => complete_native_relayed,
+    CompleteWrappedRelayed => complete_wrapped_relayed,
 }
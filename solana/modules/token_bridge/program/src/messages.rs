@@ -1,6 +1,7 @@
 use crate::types::{
     Address,
     ChainID,
+    PauseTarget,
 };
 use bridge::{
     vaa::{
@@ -369,13 +370,256 @@ impl SerializeGovernancePayload for GovernancePayloadUpgrade {
 impl DeserializeGovernancePayload for GovernancePayloadUpgrade {
 }
 
+#[derive(PartialEq, Debug)]
+pub struct PayloadGovernanceSetTransferLimit {
+    /// Native mint the limit applies to
+    pub mint: Address,
+    /// Amount allowed to leave or enter custody per direction within 24 hours, 0 for no limit
+    pub daily_limit: u64,
+    /// Outbound transfers of at least this amount are queued, 0 to never queue by size
+    pub large_transfer_threshold: u64,
+    /// Seconds a queued transfer is held before it can be released
+    pub release_delay: u64,
+}
+
+impl SerializeGovernancePayload for PayloadGovernanceSetTransferLimit {
+    const MODULE: &'static str = "TokenBridge";
+    const ACTION: u8 = 3;
+}
+
+impl DeserializeGovernancePayload for PayloadGovernanceSetTransferLimit {
+}
+
+impl DeserializePayload for PayloadGovernanceSetTransferLimit
+where
+    Self: DeserializeGovernancePayload,
+{
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, SolitaireError> {
+        let mut v = Cursor::new(buf);
+        Self::check_governance_header(&mut v)?;
+
+        let mut mint = Address::default();
+        v.read_exact(&mut mint)?;
+        let daily_limit = v.read_u64::<BigEndian>()?;
+        let large_transfer_threshold = v.read_u64::<BigEndian>()?;
+        let release_delay = v.read_u64::<BigEndian>()?;
+
+        if v.position() != v.into_inner().len() as u64 {
+            return Err(InvalidAccountData.into());
+        }
+
+        Ok(PayloadGovernanceSetTransferLimit {
+            mint,
+            daily_limit,
+            large_transfer_threshold,
+            release_delay,
+        })
+    }
+}
+
+impl SerializePayload for PayloadGovernanceSetTransferLimit
+where
+    Self: SerializeGovernancePayload,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SolitaireError> {
+        self.write_governance_header(writer)?;
+        writer.write_all(&self.mint)?;
+        writer.write_u64::<BigEndian>(self.daily_limit)?;
+        writer.write_u64::<BigEndian>(self.large_transfer_threshold)?;
+        writer.write_u64::<BigEndian>(self.release_delay)?;
+
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct PayloadGovernanceSetPause {
+    /// Transfers the action applies to
+    pub target: PauseTarget,
+    /// Whether the target is paused or resumed
+    pub paused: bool,
+}
+
+impl SerializeGovernancePayload for PayloadGovernanceSetPause {
+    const MODULE: &'static str = "TokenBridge";
+    const ACTION: u8 = 4;
+}
+
+impl DeserializeGovernancePayload for PayloadGovernanceSetPause {
+}
+
+impl DeserializePayload for PayloadGovernanceSetPause
+where
+    Self: DeserializeGovernancePayload,
+{
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, SolitaireError> {
+        let mut v = Cursor::new(buf);
+        Self::check_governance_header(&mut v)?;
+
+        let target = match v.read_u8()? {
+            0 => PauseTarget::Outbound,
+            1 => PauseTarget::Inbound,
+            2 => PauseTarget::Chain(v.read_u16::<BigEndian>()?),
+            3 => {
+                let mut mint = Address::default();
+                v.read_exact(&mut mint)?;
+                PauseTarget::Mint(Pubkey::new_from_array(mint))
+            }
+            _ => return Err(InvalidAccountData.into()),
+        };
+        let paused = match v.read_u8()? {
+            0 => false,
+            1 => true,
+            _ => return Err(InvalidAccountData.into()),
+        };
+
+        if v.position() != v.into_inner().len() as u64 {
+            return Err(InvalidAccountData.into());
+        }
+
+        Ok(PayloadGovernanceSetPause { target, paused })
+    }
+}
+
+impl SerializePayload for PayloadGovernanceSetPause
+where
+    Self: SerializeGovernancePayload,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SolitaireError> {
+        self.write_governance_header(writer)?;
+        match &self.target {
+            PauseTarget::Outbound => writer.write_u8(0)?,
+            PauseTarget::Inbound => writer.write_u8(1)?,
+            PauseTarget::Chain(chain) => {
+                writer.write_u8(2)?;
+                writer.write_u16::<BigEndian>(*chain)?;
+            }
+            PauseTarget::Mint(mint) => {
+                writer.write_u8(3)?;
+                writer.write_all(&mint.to_bytes())?;
+            }
+        }
+        writer.write_u8(self.paused as u8)?;
+
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct PayloadGovernanceUpdateChain {
+    /// Chain ID of the registered chain
+    pub chain: ChainID,
+    /// Address of the endpoint replacing the registered one
+    pub endpoint_address: Address,
+}
+
+impl SerializeGovernancePayload for PayloadGovernanceUpdateChain {
+    const MODULE: &'static str = "TokenBridge";
+    const ACTION: u8 = 5;
+}
+
+impl DeserializeGovernancePayload for PayloadGovernanceUpdateChain {
+}
+
+impl DeserializePayload for PayloadGovernanceUpdateChain
+where
+    Self: DeserializeGovernancePayload,
+{
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, SolitaireError> {
+        let mut v = Cursor::new(buf);
+        Self::check_governance_header(&mut v)?;
+
+        let chain = v.read_u16::<BigEndian>()?;
+        let mut endpoint_address = [0u8; 32];
+        v.read_exact(&mut endpoint_address)?;
+
+        if v.position() != v.into_inner().len() as u64 {
+            return Err(InvalidAccountData.into());
+        }
+
+        Ok(PayloadGovernanceUpdateChain {
+            chain,
+            endpoint_address,
+        })
+    }
+}
+
+impl SerializePayload for PayloadGovernanceUpdateChain
+where
+    Self: SerializeGovernancePayload,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SolitaireError> {
+        self.write_governance_header(writer)?;
+        writer.write_u16::<BigEndian>(self.chain)?;
+        writer.write_all(&self.endpoint_address[..])?;
+
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct PayloadGovernanceDeregisterChain {
+    /// Chain ID of the chain to be deregistered
+    pub chain: ChainID,
+    /// Address of the endpoint to remove
+    pub endpoint_address: Address,
+}
+
+impl SerializeGovernancePayload for PayloadGovernanceDeregisterChain {
+    const MODULE: &'static str = "TokenBridge";
+    const ACTION: u8 = 6;
+}
+
+impl DeserializeGovernancePayload for PayloadGovernanceDeregisterChain {
+}
+
+impl DeserializePayload for PayloadGovernanceDeregisterChain
+where
+    Self: DeserializeGovernancePayload,
+{
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, SolitaireError> {
+        let mut v = Cursor::new(buf);
+        Self::check_governance_header(&mut v)?;
+
+        let chain = v.read_u16::<BigEndian>()?;
+        let mut endpoint_address = [0u8; 32];
+        v.read_exact(&mut endpoint_address)?;
+
+        if v.position() != v.into_inner().len() as u64 {
+            return Err(InvalidAccountData.into());
+        }
+
+        Ok(PayloadGovernanceDeregisterChain {
+            chain,
+            endpoint_address,
+        })
+    }
+}
+
+impl SerializePayload for PayloadGovernanceDeregisterChain
+where
+    Self: SerializeGovernancePayload,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SolitaireError> {
+        self.write_governance_header(writer)?;
+        writer.write_u16::<BigEndian>(self.chain)?;
+        writer.write_all(&self.endpoint_address[..])?;
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "no-entrypoint")]
 #[allow(unused_imports)]
 mod tests {
     use crate::messages::{
         GovernancePayloadUpgrade,
         PayloadAssetMeta,
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceSetPause,
+        PayloadGovernanceSetTransferLimit,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
         PayloadTransferWithPayload
     };
@@ -385,6 +629,7 @@ mod tests {
     };
     use primitive_types::U256;
     use rand::RngCore;
+    use crate::types::PauseTarget;
     use solana_program::pubkey::Pubkey;
 
     #[test]
@@ -456,6 +701,76 @@ mod tests {
         assert_eq!(original, deser);
     }
 
+    #[test]
+    pub fn test_serde_gov_set_transfer_limit() {
+        let mut mint = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut mint);
+
+        let original = PayloadGovernanceSetTransferLimit {
+            mint,
+            daily_limit: 1_000_000,
+            large_transfer_threshold: 250_000,
+            release_delay: 86400,
+        };
+
+        let data = original.try_to_vec().unwrap();
+        let deser = PayloadGovernanceSetTransferLimit::deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(original, deser);
+    }
+
+    #[test]
+    pub fn test_serde_gov_set_pause() {
+        for target in vec![
+            PauseTarget::Outbound,
+            PauseTarget::Inbound,
+            PauseTarget::Chain(2),
+            PauseTarget::Mint(Pubkey::new_unique()),
+        ] {
+            let original = PayloadGovernanceSetPause {
+                target,
+                paused: true,
+            };
+
+            let data = original.try_to_vec().unwrap();
+            let deser = PayloadGovernanceSetPause::deserialize(&mut data.as_slice()).unwrap();
+
+            assert_eq!(original, deser);
+        }
+    }
+
+    #[test]
+    pub fn test_serde_gov_update_chain() {
+        let mut endpoint_address = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut endpoint_address);
+
+        let original = PayloadGovernanceUpdateChain {
+            chain: 8,
+            endpoint_address,
+        };
+
+        let data = original.try_to_vec().unwrap();
+        let deser = PayloadGovernanceUpdateChain::deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(original, deser);
+    }
+
+    #[test]
+    pub fn test_serde_gov_deregister_chain() {
+        let mut endpoint_address = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut endpoint_address);
+
+        let original = PayloadGovernanceDeregisterChain {
+            chain: 8,
+            endpoint_address,
+        };
+
+        let data = original.try_to_vec().unwrap();
+        let deser = PayloadGovernanceDeregisterChain::deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(original, deser);
+    }
+
     #[test]
     pub fn test_serde_transfer_with_payload() {
         let mut token_address = [0u8; 32];
@@ -0,0 +1,73 @@
+//! Client side check of a native mint's custody against its ledger: how many tokens custody holds
+//! and how many the bridge owes to other chains.
+
+use borsh::BorshDeserialize;
+use bridge::status::AccountSource;
+use solana_program::pubkey::Pubkey;
+use solitaire::{
+    processors::seeded::Seeded,
+    AccountState,
+    ErrBox,
+};
+
+use crate::{
+    accounts::{
+        CustodyAccount,
+        CustodyAccountDerivationData,
+        CustodyLedgerAccount,
+        CustodyLedgerDerivationData,
+    },
+    types::{
+        CustodyLedger,
+        SplAccount,
+    },
+};
+
+/// Amounts are in units of the mint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustodyReport {
+    pub mint: Pubkey,
+    pub custody_balance: u64,
+    pub outstanding: u64,
+}
+
+impl CustodyReport {
+    /// Whether custody holds at least what is owed. Any excess was sent to custody directly
+    /// rather than bridged.
+    pub fn is_solvent(&self) -> bool {
+        self.custody_balance >= self.outstanding
+    }
+}
+
+/// Look up the custody balance and outstanding amount of `mint`, the same comparison the
+/// `ReconcileCustody` instruction makes on chain. A mint without a ledger owes its whole custody
+/// balance.
+pub fn custody_report<S: AccountSource>(
+    source: &S,
+    program_id: &Pubkey,
+    mint: &Pubkey,
+) -> Result<CustodyReport, ErrBox> {
+    let custody_key = CustodyAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyAccountDerivationData { mint: *mint },
+        program_id,
+    );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData { mint: *mint },
+        program_id,
+    );
+
+    let custody_balance = match source.account_data(&custody_key)? {
+        Some(data) => SplAccount::try_from_slice(&data)?.amount,
+        None => 0,
+    };
+    let outstanding = match source.account_data(&ledger_key)? {
+        Some(data) => CustodyLedger::deserialize(&mut data.as_slice())?.outstanding,
+        None => custody_balance,
+    };
+
+    Ok(CustodyReport {
+        mint: *mint,
+        custody_balance,
+        outstanding,
+    })
+}
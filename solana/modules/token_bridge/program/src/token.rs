@@ -0,0 +1,227 @@
+//! Routing between the SPL token program and Token-2022. Native mints may be owned by either
+//! program, so every CPI touching them goes to the program owning the mint. Wrapped mints are
+//! always created under the SPL token program.
+//!
+//! Also converts between local amounts and the amounts carried in transfers, which are capped at
+//! 8 decimals.
+
+use crate::{
+    types::SplAccount,
+    TokenBridgeError::{
+        AmountOverflow,
+        InvalidTokenAccount,
+        UnsupportedMintExtension,
+    },
+};
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::{
+        AccountMeta,
+        Instruction,
+    },
+    program::{
+        invoke,
+        invoke_signed,
+    },
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+    sysvar,
+};
+use solitaire::{
+    CreationLamports::Exempt,
+    *,
+};
+use spl_token_2022::{
+    extension::{
+        ExtensionType,
+        StateWithExtensions,
+    },
+    state::{
+        Account,
+        Mint,
+    },
+};
+
+/// Factor between a local amount of a mint with `decimals` and its amount in a transfer.
+pub fn trunc_divisor(decimals: u8) -> Result<u64> {
+    10u64
+        .checked_pow(decimals.saturating_sub(8) as u32)
+        .ok_or_else(|| AmountOverflow.into())
+}
+
+/// Local amount of a transfer amount, the inverse of truncating to 8 decimals.
+pub fn denormalize(amount: u64, decimals: u8) -> Result<u64> {
+    amount
+        .checked_mul(trunc_divisor(decimals)?)
+        .ok_or_else(|| AmountOverflow.into())
+}
+
+/// Extensions of the mint, empty for mints of the SPL token program.
+pub fn mint_extensions(mint: &AccountInfo) -> Result<Vec<ExtensionType>> {
+    if *mint.owner != spl_token_2022::id() {
+        return Ok(vec![]);
+    }
+    let data = mint.try_borrow_data()?;
+    Ok(StateWithExtensions::<Mint>::unpack(&data)?.get_extension_types()?)
+}
+
+/// Reject mints the bridge can't hold in custody. Non-transferable tokens could be locked but
+/// never be released again.
+pub fn verify_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if mint_extensions(mint)?.contains(&ExtensionType::NonTransferable) {
+        return Err(UnsupportedMintExtension.into());
+    }
+    Ok(())
+}
+
+/// Size of a token account for `mint`, including the extensions the mint requires of accounts.
+pub fn account_len(mint: &AccountInfo) -> Result<usize> {
+    if *mint.owner != spl_token_2022::id() {
+        return Ok(spl_token::state::Account::LEN);
+    }
+    let extensions = ExtensionType::get_required_init_account_extensions(&mint_extensions(mint)?);
+    Ok(ExtensionType::get_account_len::<Account>(&extensions))
+}
+
+/// Current balance of a token account, read from the account rather than a peeled copy so it
+/// reflects CPIs made earlier in the instruction.
+pub fn balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(SplAccount::try_from_slice(&data)?.amount)
+}
+
+/// Create a token account for `mint` at the program derived address of `seeds`, owned by `owner`.
+pub fn create_token_account(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    account: &AccountInfo,
+    seeds: &[Vec<u8>],
+    mint: &AccountInfo,
+    owner: &Pubkey,
+) -> Result<()> {
+    let s: Vec<&[u8]> = seeds.iter().map(|item| item.as_slice()).collect();
+    create_account(
+        ctx,
+        account,
+        payer,
+        Exempt,
+        account_len(mint)?,
+        mint.owner,
+        IsSigned::SignedWithSeeds(&[s.as_slice()]),
+    )?;
+
+    let init_ix = initialize_account(mint.owner, account.key, mint.key, owner)?;
+    invoke_signed(&init_ix, ctx.accounts, &[])?;
+    Ok(())
+}
+
+pub fn initialize_account(
+    token_program: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> std::result::Result<Instruction, ProgramError> {
+    if *token_program == spl_token_2022::id() {
+        spl_token_2022::instruction::initialize_account(token_program, account, mint, owner)
+    } else {
+        spl_token::instruction::initialize_account(token_program, account, mint, owner)
+    }
+}
+
+pub fn close_account(
+    token_program: &Pubkey,
+    account: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+) -> std::result::Result<Instruction, ProgramError> {
+    if *token_program == spl_token_2022::id() {
+        spl_token_2022::instruction::close_account(token_program, account, destination, owner, &[])
+    } else {
+        spl_token::instruction::close_account(token_program, account, destination, owner, &[])
+    }
+}
+
+/// Token-2022 mints with transfer hooks or fees require the checked variant, so it is used for
+/// both programs.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked(
+    token_program: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> std::result::Result<Instruction, ProgramError> {
+    if *token_program == spl_token_2022::id() {
+        spl_token_2022::instruction::transfer_checked(
+            token_program,
+            source,
+            mint,
+            destination,
+            authority,
+            &[],
+            amount,
+            decimals,
+        )
+    } else {
+        spl_token::instruction::transfer_checked(
+            token_program,
+            source,
+            mint,
+            destination,
+            authority,
+            &[],
+            amount,
+            decimals,
+        )
+    }
+}
+
+/// Associated token account of `wallet` for `mint`, a token account of `token_program`. Derived
+/// here as spl-associated-token-account 1.0.3 only derives accounts of the SPL token program.
+pub fn associated_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &spl_associated_token_account::id(),
+    )
+    .0
+}
+
+/// Create the associated token account of `wallet` for `mint` unless it already exists, paid for
+/// by `payer`. Fails if `account` is not that associated token account.
+pub fn create_associated_account(
+    ctx: &ExecutionContext,
+    payer: &Pubkey,
+    wallet: &Pubkey,
+    mint: &AccountInfo,
+    account: &AccountInfo,
+) -> Result<()> {
+    let token_program = mint.owner;
+    if *account.key != associated_address(wallet, mint.key, token_program) {
+        return Err(InvalidTokenAccount.into());
+    }
+    if !account.data_is_empty() {
+        return Ok(());
+    }
+
+    // Same accounts as `create_associated_token_account`, which always names the SPL token program.
+    let create_ix = Instruction {
+        program_id: spl_associated_token_account::id(),
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*account.key, false),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new_readonly(*mint.key, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: vec![],
+    };
+    invoke(&create_ix, ctx.accounts)?;
+    Ok(())
+}
@@ -85,5 +85,434 @@ impl Owned for WrappedMeta {
     }
 }
 
-pack_type!(SplMint, Mint, AccountOwner::Other(spl_token::id()));
-pack_type!(SplAccount, Account, AccountOwner::Other(spl_token::id()));
+/// Latest attestation applied to a wrapped mint. Kept apart from [`WrappedMeta`] so the metadata
+/// accounts of mints created before refreshes were ordered keep their layout.
+#[derive(Default, Clone, Copy, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct WrappedAttestation {
+    /// Emitter of the attestation the metadata was last set from, sequences are only ordered
+    /// within one emitter
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    /// Sequence of the attestation the metadata was last set from, older ones of the same emitter
+    /// are rejected
+    pub sequence: u64,
+    /// Symbol and name as attested, zero padded. SPL metadata only holds truncated versions.
+    pub original_symbol: [u8; 32],
+    pub original_name: [u8; 32],
+}
+
+#[cfg(not(feature = "cpi"))]
+impl Owned for WrappedAttestation {
+    fn owner(&self) -> AccountOwner {
+        AccountOwner::This
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Owned for WrappedAttestation {
+    fn owner(&self) -> AccountOwner {
+        use std::str::FromStr;
+        AccountOwner::Other(Pubkey::from_str(env!("TOKEN_BRIDGE_ADDRESS")).unwrap())
+    }
+}
+
+/// Number of hourly buckets making up the rolling governor window.
+pub const GOVERNOR_WINDOW_HOURS: usize = 24;
+
+/// Amounts moved through custody, accumulated per hour so that the sum of all buckets covers the
+/// last `GOVERNOR_WINDOW_HOURS` hours.
+#[derive(Default, Clone, Copy, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct TransferFlow {
+    /// Hour (unix timestamp / 3600) of the most recent bucket
+    pub last_hour: u64,
+    /// Amounts per hour, indexed by hour modulo the window length
+    pub buckets: [u64; GOVERNOR_WINDOW_HOURS],
+}
+
+impl TransferFlow {
+    /// Advance the window to `hour`, clearing the buckets of hours that fell out of it.
+    pub fn roll(&mut self, hour: u64) {
+        if hour <= self.last_hour {
+            return;
+        }
+        let stale = (hour - self.last_hour).min(GOVERNOR_WINDOW_HOURS as u64);
+        for h in (hour + 1 - stale)..=hour {
+            self.buckets[(h % GOVERNOR_WINDOW_HOURS as u64) as usize] = 0;
+        }
+        self.last_hour = hour;
+    }
+
+    /// Total amount moved within the window.
+    pub fn total(&self) -> u64 {
+        self.buckets
+            .iter()
+            .fold(0u64, |total, amount| total.saturating_add(*amount))
+    }
+
+    /// Add `amount` to the bucket of the current hour.
+    pub fn record(&mut self, amount: u64) {
+        let bucket = &mut self.buckets[(self.last_hour % GOVERNOR_WINDOW_HOURS as u64) as usize];
+        *bucket = bucket.saturating_add(amount);
+    }
+}
+
+/// Governor limits for a single native mint. Amounts are the normalized (at most 8 decimals)
+/// amounts carried in transfer payloads, a value of zero disables the respective check.
+#[derive(Default, Clone, Copy, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct TransferLimit {
+    pub mint: Pubkey,
+    /// Amount allowed to leave or enter custody, per direction, within the rolling window
+    pub daily_limit: u64,
+    /// Outbound transfers of at least this amount are always queued
+    pub large_transfer_threshold: u64,
+    /// Seconds a queued transfer is held before it can be released
+    pub release_delay: u64,
+    pub outbound: TransferFlow,
+    pub inbound: TransferFlow,
+}
+
+impl TransferLimit {
+    /// Record an outbound transfer at `hour` if it can be posted right away. Transfers that are
+    /// too large, or that would exceed the daily limit, are not recorded and must be queued.
+    pub fn try_outbound(&mut self, hour: u64, amount: u64) -> bool {
+        if self.large_transfer_threshold != 0 && amount >= self.large_transfer_threshold {
+            return false;
+        }
+        Self::try_record(&mut self.outbound, self.daily_limit, hour, amount)
+    }
+
+    /// Record an inbound transfer at `hour` if it stays within the daily limit.
+    pub fn try_inbound(&mut self, hour: u64, amount: u64) -> bool {
+        Self::try_record(&mut self.inbound, self.daily_limit, hour, amount)
+    }
+
+    /// Record a queued outbound transfer being released at `hour`. Its delay already expired, so
+    /// it is always let through, but it uses up capacity like any other outbound transfer.
+    pub fn record_released(&mut self, hour: u64, amount: u64) {
+        self.outbound.roll(hour);
+        self.outbound.record(amount);
+    }
+
+    fn try_record(flow: &mut TransferFlow, limit: u64, hour: u64, amount: u64) -> bool {
+        flow.roll(hour);
+        if limit != 0 {
+            match flow.total().checked_add(amount) {
+                Some(total) if total <= limit => {}
+                _ => return false,
+            }
+        }
+        flow.record(amount);
+        true
+    }
+}
+
+#[cfg(not(feature = "cpi"))]
+impl Owned for TransferLimit {
+    fn owner(&self) -> AccountOwner {
+        AccountOwner::This
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Owned for TransferLimit {
+    fn owner(&self) -> AccountOwner {
+        use std::str::FromStr;
+        AccountOwner::Other(Pubkey::from_str(env!("TOKEN_BRIDGE_ADDRESS")).unwrap())
+    }
+}
+
+/// Native tokens of a mint owed to other chains, in units of the mint. Custody must hold at least
+/// `outstanding` for the bridge to be solvent.
+#[derive(Default, Clone, Copy, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct CustodyLedger {
+    pub mint: Pubkey,
+    pub outstanding: u64,
+}
+
+impl CustodyLedger {
+    /// Record tokens entering custody, `None` on overflow.
+    pub fn lock(&mut self, amount: u64) -> Option<()> {
+        self.outstanding = self.outstanding.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Record tokens leaving custody, `None` if more is released than is owed.
+    pub fn release(&mut self, amount: u64) -> Option<()> {
+        self.outstanding = self.outstanding.checked_sub(amount)?;
+        Some(())
+    }
+}
+
+#[cfg(not(feature = "cpi"))]
+impl Owned for CustodyLedger {
+    fn owner(&self) -> AccountOwner {
+        AccountOwner::This
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Owned for CustodyLedger {
+    fn owner(&self) -> AccountOwner {
+        use std::str::FromStr;
+        AccountOwner::Other(Pubkey::from_str(env!("TOKEN_BRIDGE_ADDRESS")).unwrap())
+    }
+}
+
+/// Outbound transfer held back by the governor until `release_after`.
+#[derive(Default, Clone, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct PendingTransfer {
+    pub mint: Pubkey,
+    /// Unix timestamp from which the transfer can be released
+    pub release_after: i64,
+    pub nonce: u32,
+    /// Chain the transfer is sent to
+    pub target_chain: ChainID,
+    /// Normalized amount, charged to the outbound window of the mint once released
+    pub amount: u64,
+    /// Serialized transfer payload, posted as is once released
+    pub payload: Vec<u8>,
+    /// Receives the rent of this account once the transfer is released
+    pub rent_payer: Pubkey,
+}
+
+#[cfg(not(feature = "cpi"))]
+impl Owned for PendingTransfer {
+    fn owner(&self) -> AccountOwner {
+        AccountOwner::This
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Owned for PendingTransfer {
+    fn owner(&self) -> AccountOwner {
+        use std::str::FromStr;
+        AccountOwner::Other(Pubkey::from_str(env!("TOKEN_BRIDGE_ADDRESS")).unwrap())
+    }
+}
+
+/// What a pause governance action applies to.
+#[derive(PartialEq, Debug, Clone, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub enum PauseTarget {
+    /// All `transfer_*` instructions
+    Outbound,
+    /// All `complete_*` instructions and `create_wrapped`
+    Inbound,
+    /// Transfers to and from a chain
+    Chain(ChainID),
+    /// Transfers of a mint, native or wrapped
+    Mint(Pubkey),
+}
+
+/// Transfers paused by governance, acts as a circuit breaker during incidents.
+#[derive(Default, Clone, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct PauseConfig {
+    /// Governance sequence of the last pause action applied
+    pub sequence: u64,
+    pub outbound: bool,
+    pub inbound: bool,
+    pub chains: Vec<ChainID>,
+    pub mints: Vec<Pubkey>,
+}
+
+impl PauseConfig {
+    /// Whether transfers of `mint` to `chain` are paused.
+    pub fn outbound_paused(&self, mint: &Pubkey, chain: ChainID) -> bool {
+        self.outbound || self.chains.contains(&chain) || self.mints.contains(mint)
+    }
+
+    /// Whether transfers of `mint` from `chain` are paused.
+    pub fn inbound_paused(&self, mint: &Pubkey, chain: ChainID) -> bool {
+        self.inbound || self.chains.contains(&chain) || self.mints.contains(mint)
+    }
+
+    pub fn set(&mut self, target: &PauseTarget, paused: bool) {
+        match target {
+            PauseTarget::Outbound => self.outbound = paused,
+            PauseTarget::Inbound => self.inbound = paused,
+            PauseTarget::Chain(chain) => {
+                self.chains.retain(|c| c != chain);
+                if paused {
+                    self.chains.push(*chain);
+                }
+            }
+            PauseTarget::Mint(mint) => {
+                self.mints.retain(|m| m != mint);
+                if paused {
+                    self.mints.push(*mint);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "cpi"))]
+impl Owned for PauseConfig {
+    fn owner(&self) -> AccountOwner {
+        AccountOwner::This
+    }
+}
+
+#[cfg(feature = "cpi")]
+impl Owned for PauseConfig {
+    fn owner(&self) -> AccountOwner {
+        use std::str::FromStr;
+        AccountOwner::Other(Pubkey::from_str(env!("TOKEN_BRIDGE_ADDRESS")).unwrap())
+    }
+}
+
+/// Like `pack_type!`, but for token state that can live under either token program. Token-2022
+/// accounts carry extensions after the base state, only the base state is kept here and the
+/// extensions are read from the account when needed.
+macro_rules! token_type {
+    ($name:ident, $embed:ty, $state:ty) => {
+        #[repr(transparent)]
+        pub struct $name(pub $embed);
+
+        impl BorshDeserialize for $name {
+            fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+                // Checks the account type of extended layouts so a mint can't pass as an account.
+                spl_token_2022::extension::StateWithExtensions::<$state>::unpack(buf)
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+                let acc = $name(
+                    solana_program::program_pack::Pack::unpack_from_slice(
+                        &buf[..<$embed as solana_program::program_pack::Pack>::LEN],
+                    )
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?,
+                );
+                // We need to clear the buf to show to Borsh that we've read all data
+                *buf = &buf[..0];
+
+                Ok(acc)
+            }
+        }
+
+        impl BorshSerialize for $name {
+            fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                let mut data = [0u8; <$embed as solana_program::program_pack::Pack>::LEN];
+                solana_program::program_pack::Pack::pack_into_slice(&self.0, &mut data);
+                writer.write_all(&data)?;
+
+                Ok(())
+            }
+        }
+
+        impl Owned for $name {
+            fn owner(&self) -> AccountOwner {
+                AccountOwner::OneOf(vec![spl_token::id(), spl_token_2022::id()])
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = $embed;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::default::Default for $name {
+            fn default() -> Self {
+                $name(<$embed>::default())
+            }
+        }
+    };
+}
+
+token_type!(SplMint, Mint, spl_token_2022::state::Mint);
+token_type!(SplAccount, Account, spl_token_2022::state::Account);
+
+// Wrapped assets are always minted by the SPL token program.
+pack_type!(SplWrappedMint, Mint, AccountOwner::Other(spl_token::id()));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrapped_meta_layout() {
+        // Metadata accounts of existing wrapped mints hold exactly these 35 bytes.
+        let mut data = vec![0u8; 35];
+        data[..2].copy_from_slice(&2u16.to_le_bytes());
+        data[2..34].copy_from_slice(&[1u8; 32]);
+        data[34] = 8;
+
+        let meta = WrappedMeta::try_from_slice(&data).unwrap();
+        assert_eq!(meta.chain, 2);
+        assert_eq!(meta.token_address, [1u8; 32]);
+        assert_eq!(meta.original_decimals, 8);
+        assert_eq!(meta.try_to_vec().unwrap(), data);
+    }
+
+    #[test]
+    fn test_transfer_limit_window() {
+        let mut limit = TransferLimit {
+            daily_limit: 100,
+            large_transfer_threshold: 80,
+            ..Default::default()
+        };
+
+        // Large transfers are never posted right away, regardless of the window.
+        assert!(!limit.try_outbound(1000, 80));
+        assert!(limit.try_outbound(1000, 60));
+        assert!(!limit.try_outbound(1010, 50));
+        assert!(limit.try_outbound(1010, 40));
+        assert_eq!(limit.outbound.total(), 100);
+
+        // Inbound transfers are tracked separately.
+        assert!(limit.try_inbound(1010, 100));
+        assert!(!limit.try_inbound(1010, 1));
+
+        // The first transfer leaves the window 24 hours later, the second one stays.
+        assert!(!limit.try_outbound(1023, 10));
+        assert!(limit.try_outbound(1024, 60));
+        assert!(!limit.try_outbound(1024, 1));
+
+        // Both leave the window once it moved on entirely.
+        assert!(limit.try_outbound(2000, 79));
+        assert_eq!(limit.outbound.total(), 79);
+
+        // Released transfers always go out, but count against the window.
+        limit.record_released(2001, 80);
+        assert_eq!(limit.outbound.total(), 159);
+        assert!(!limit.try_outbound(2001, 1));
+    }
+
+    #[test]
+    fn test_custody_ledger() {
+        let mut ledger = CustodyLedger::default();
+        assert_eq!(ledger.lock(100), Some(()));
+        assert_eq!(ledger.release(60), Some(()));
+        assert_eq!(ledger.outstanding, 40);
+
+        // Releasing more than is owed or overflowing leaves the ledger untouched.
+        assert_eq!(ledger.release(41), None);
+        assert_eq!(ledger.lock(u64::MAX), None);
+        assert_eq!(ledger.outstanding, 40);
+    }
+
+    #[test]
+    fn test_pause_config() {
+        let mint = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let mut pause = PauseConfig::default();
+        assert!(!pause.outbound_paused(&mint, 2));
+        assert!(!pause.inbound_paused(&mint, 2));
+
+        pause.set(&PauseTarget::Outbound, true);
+        assert!(pause.outbound_paused(&mint, 2));
+        assert!(!pause.inbound_paused(&mint, 2));
+        pause.set(&PauseTarget::Outbound, false);
+
+        // Chains and mints are paused in both directions, pausing twice keeps a single entry.
+        pause.set(&PauseTarget::Chain(2), true);
+        pause.set(&PauseTarget::Chain(2), true);
+        pause.set(&PauseTarget::Mint(mint), true);
+        assert_eq!(pause.chains, vec![2]);
+        assert!(pause.outbound_paused(&other, 2));
+        assert!(pause.inbound_paused(&mint, 3));
+        assert!(!pause.inbound_paused(&other, 3));
+
+        pause.set(&PauseTarget::Chain(2), false);
+        pause.set(&PauseTarget::Mint(mint), false);
+        assert!(!pause.outbound_paused(&mint, 2));
+    }
+}
@@ -11,8 +11,11 @@ use crate::{
     instructions::{
         attest,
         complete_native,
+        complete_native_relayed,
         complete_wrapped,
+        complete_wrapped_relayed,
         create_wrapped,
+        decode_error,
         register_chain,
         transfer_native,
         transfer_native_with_payload,
@@ -26,12 +29,15 @@ use crate::{
         PayloadGovernanceRegisterChain,
         PayloadTransfer,
     },
+    token,
     types::{
         EndpointRegistration,
         WrappedMeta,
     },
     CompleteNativeData,
+    CompleteNativeRelayedData,
     CompleteWrappedData,
+    CompleteWrappedRelayedData,
     CreateWrappedData,
     RegisterChainData,
     TransferNativeData,
@@ -367,6 +373,158 @@ pub fn complete_transfer_wrapped_ix(
     JsValue::from_serde(&ix).unwrap()
 }
 
+/// Token account and owner to redeem `payload` to. Without an owner the transfer must name an
+/// existing token account, a transfer naming `to_owner` itself goes to its associated token account.
+fn recipient(
+    payload: &PayloadTransfer,
+    to_owner: Option<String>,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, Pubkey) {
+    let to = Pubkey::new(&payload.to[..]);
+    match to_owner {
+        Some(owner) => {
+            let owner = Pubkey::from_str(owner.as_str()).unwrap();
+            if owner == to {
+                (
+                    token::associated_address(&owner, mint, token_program),
+                    owner,
+                )
+            } else {
+                (to, owner)
+            }
+        }
+        None => (to, to),
+    }
+}
+
+/// `token_program` is the program owning the native mint, spl_token or spl_token_2022.
+#[wasm_bindgen]
+pub fn complete_transfer_native_relayed_ix(
+    program_id: String,
+    bridge_id: String,
+    payer: String,
+    vaa: Vec<u8>,
+    fee_recipient: Option<String>,
+    to_owner: Option<String>,
+    token_program: String,
+) -> JsValue {
+    let program_id = Pubkey::from_str(program_id.as_str()).unwrap();
+    let bridge_id = Pubkey::from_str(bridge_id.as_str()).unwrap();
+    let payer = Pubkey::from_str(payer.as_str()).unwrap();
+    let token_program = Pubkey::from_str(token_program.as_str()).unwrap();
+    let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
+    let payload = PayloadTransfer::deserialize(&mut vaa.payload.as_slice()).unwrap();
+    let message_key = bridge::accounts::PostedVAA::<'_, { AccountState::Uninitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: hash_vaa(&vaa.clone().into()).to_vec(),
+        },
+        &bridge_id,
+    );
+    let post_vaa_data = PostVAAData {
+        version: vaa.version,
+        guardian_set_index: vaa.guardian_set_index,
+        timestamp: vaa.timestamp,
+        nonce: vaa.nonce,
+        emitter_chain: vaa.emitter_chain,
+        emitter_address: vaa.emitter_address,
+        sequence: vaa.sequence,
+        consistency_level: vaa.consistency_level,
+        payload: vaa.payload,
+    };
+
+    let (to, to_owner) = recipient(
+        &payload,
+        to_owner,
+        &Pubkey::new(&payload.token_address),
+        &token_program,
+    );
+
+    let ix = complete_native_relayed(
+        program_id,
+        bridge_id,
+        payer,
+        message_key,
+        post_vaa_data,
+        to,
+        to_owner,
+        if let Some(fee_r) = fee_recipient {
+            Some(Pubkey::from_str(fee_r.as_str()).unwrap())
+        } else {
+            None
+        },
+        Pubkey::new(&payload.token_address),
+        token_program,
+        CompleteNativeRelayedData {},
+    )
+    .unwrap();
+
+    JsValue::from_serde(&ix).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn complete_transfer_wrapped_relayed_ix(
+    program_id: String,
+    bridge_id: String,
+    payer: String,
+    vaa: Vec<u8>,
+    fee_recipient: Option<String>,
+    to_owner: Option<String>,
+) -> JsValue {
+    let program_id = Pubkey::from_str(program_id.as_str()).unwrap();
+    let bridge_id = Pubkey::from_str(bridge_id.as_str()).unwrap();
+    let payer = Pubkey::from_str(payer.as_str()).unwrap();
+    let vaa = VAA::deserialize(vaa.as_slice()).unwrap();
+    let payload = PayloadTransfer::deserialize(&mut vaa.payload.as_slice()).unwrap();
+    let message_key = bridge::accounts::PostedVAA::<'_, { AccountState::Uninitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: hash_vaa(&vaa.clone().into()).to_vec(),
+        },
+        &bridge_id,
+    );
+    let post_vaa_data = PostVAAData {
+        version: vaa.version,
+        guardian_set_index: vaa.guardian_set_index,
+        timestamp: vaa.timestamp,
+        nonce: vaa.nonce,
+        emitter_chain: vaa.emitter_chain,
+        emitter_address: vaa.emitter_address,
+        sequence: vaa.sequence,
+        consistency_level: vaa.consistency_level,
+        payload: vaa.payload,
+    };
+
+    let mint = WrappedMint::<'_, { AccountState::Uninitialized }>::key(
+        &WrappedDerivationData {
+            token_chain: payload.token_chain,
+            token_address: payload.token_address,
+        },
+        &program_id,
+    );
+    // Wrapped mints are always created by the bridge under spl_token.
+    let (to, to_owner) = recipient(&payload, to_owner, &mint, &spl_token::id());
+
+    let ix = complete_wrapped_relayed(
+        program_id,
+        bridge_id,
+        payer,
+        message_key,
+        post_vaa_data,
+        payload.clone(),
+        to,
+        to_owner,
+        if let Some(fee_r) = fee_recipient {
+            Some(Pubkey::from_str(fee_r.as_str()).unwrap())
+        } else {
+            None
+        },
+        CompleteWrappedRelayedData {},
+    )
+    .unwrap();
+
+    JsValue::from_serde(&ix).unwrap()
+}
+
 #[wasm_bindgen]
 pub fn create_wrapped_ix(
     program_id: String,
@@ -432,6 +590,7 @@ pub fn upgrade_contract_ix(
     );
     let ix = upgrade_contract(
         program_id,
+        bridge_id,
         Pubkey::from_str(payer.as_str()).unwrap(),
         message_key,
         Pubkey::new(&vaa.emitter_address),
@@ -547,3 +706,11 @@ pub fn parse_wrapped_meta(data: Vec<u8>) -> JsValue {
 pub fn parse_endpoint_registration(data: Vec<u8>) -> JsValue {
     JsValue::from_serde(&EndpointRegistration::try_from_slice(data.as_slice()).unwrap()).unwrap()
 }
+
+/// Decode the custom error code of a failed token bridge instruction into `[code, name, message]`, or
+/// `null` if the code is unknown.
+#[wasm_bindgen]
+pub fn decode_error_code(code: u32) -> JsValue {
+    let error = decode_error(code).map(|e| (e.code, e.name, e.message));
+    JsValue::from_serde(&error).unwrap()
+}
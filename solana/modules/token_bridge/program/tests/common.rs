@@ -40,6 +40,7 @@ use std::{
         Cursor,
         Write,
     },
+    path::Path,
     time::SystemTime,
 };
 
@@ -50,6 +51,7 @@ use token_bridge::{
 
 use solitaire::{
     processors::seeded::Seeded,
+    profile::StageCost,
 };
 
 pub use helpers::*;
@@ -68,6 +70,7 @@ pub async fn execute<T: Signers>(
     client
         .process_transaction_with_commitment(transaction, commitment_level)
         .await
+        .map_err(Into::into)
 }
 
 mod helpers {
@@ -80,19 +83,28 @@ mod helpers {
         PostVAAData,
     };
     use solana_program_test::processor;
+    use spl_token_2022::extension::ExtensionType;
     use token_bridge::{
+        CompleteNativeByRecipientData,
         CompleteNativeData,
         CompleteWrappedData,
         CompleteNativeWithPayloadData,
         CreateWrappedData,
+        DeregisterChainData,
         RegisterChainData,
+        SetPauseData,
+        SetTransferLimitData,
         TransferNativeData,
         TransferWrappedData,
+        UpdateChainData,
     };
 
     use token_bridge::messages::{
         PayloadAssetMeta,
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceSetTransferLimit,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
         PayloadTransferWithPayload
     };
@@ -150,6 +162,11 @@ mod helpers {
             token_program,
             processor!(token_bridge::solitaire),
         );
+        builder.add_program(
+            "spl_token_2022",
+            spl_token_2022::id(),
+            processor!(spl_token_2022::processor::Processor::process),
+        );
 
         // Some instructions go over the limit when tracing is enabled but we need that for better
         // logging.  We don't really care about the limit during these tests anyway.
@@ -159,6 +176,25 @@ mod helpers {
         (client, payer, program, token_program)
     }
 
+    /// Write program logs to `path` instead of stderr. Programs built with the `profile` feature
+    /// log compute unit checkpoints that `profile` then reads back. Must be called after `setup`,
+    /// which installs its own logger.
+    #[allow(dead_code)]
+    pub fn capture_logs(path: &Path) {
+        let _ = std::fs::remove_file(path);
+        solana_logger::setup_file_with_default(
+            path.to_str().unwrap(),
+            "solana_runtime::message_processor=debug",
+        );
+    }
+
+    /// Compute units spent in each stage of the instructions executed since `capture_logs`.
+    #[allow(dead_code)]
+    pub fn profile(path: &Path) -> Vec<StageCost> {
+        let logs = std::fs::read_to_string(path).unwrap();
+        solitaire::profile::stage_costs(logs.lines())
+    }
+
     /// Wait for a single transaction to fully finalize, guaranteeing chain state has been
     /// confirmed. Useful for consistently fetching data during state checks.
     #[allow(dead_code)]
@@ -435,6 +471,178 @@ mod helpers {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_chain(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        message_acc: Pubkey,
+        vaa: PostVAAData,
+        payload: PayloadGovernanceUpdateChain,
+        registered_address: Address,
+        payer: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::update_chain(
+            program,
+            bridge,
+            payer.pubkey(),
+            message_acc,
+            vaa,
+            payload,
+            registered_address,
+            UpdateChainData {},
+        )
+        .expect("Could not create Update Chain instruction");
+
+        for account in instruction.accounts.iter().enumerate() {
+            println!("{}: {}", account.0, account.1.pubkey);
+        }
+
+        execute(
+            client,
+            payer,
+            &[payer],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
+    pub async fn deregister_chain(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        message_acc: Pubkey,
+        vaa: PostVAAData,
+        payload: PayloadGovernanceDeregisterChain,
+        payer: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::deregister_chain(
+            program,
+            bridge,
+            payer.pubkey(),
+            message_acc,
+            vaa,
+            payload,
+            DeregisterChainData {},
+        )
+        .expect("Could not create Deregister Chain instruction");
+
+        for account in instruction.accounts.iter().enumerate() {
+            println!("{}: {}", account.0, account.1.pubkey);
+        }
+
+        execute(
+            client,
+            payer,
+            &[payer],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
+    pub async fn set_transfer_limit(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        message_acc: Pubkey,
+        vaa: PostVAAData,
+        payload: PayloadGovernanceSetTransferLimit,
+        payer: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::set_transfer_limit(
+            program,
+            bridge,
+            payer.pubkey(),
+            message_acc,
+            vaa,
+            payload,
+            SetTransferLimitData {},
+        )
+        .expect("Could not create Set Transfer Limit instruction");
+
+        for account in instruction.accounts.iter().enumerate() {
+            println!("{}: {}", account.0, account.1.pubkey);
+        }
+
+        execute(
+            client,
+            payer,
+            &[payer],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
+    pub async fn set_pause(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        message_acc: Pubkey,
+        vaa: PostVAAData,
+        payer: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::set_pause(
+            program,
+            bridge,
+            payer.pubkey(),
+            message_acc,
+            vaa,
+            SetPauseData {},
+        )
+        .expect("Could not create Set Pause instruction");
+
+        for account in instruction.accounts.iter().enumerate() {
+            println!("{}: {}", account.0, account.1.pubkey);
+        }
+
+        execute(
+            client,
+            payer,
+            &[payer],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn release_pending_transfer(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        payer: &Keypair,
+        queued_message: Pubkey,
+        mint: Pubkey,
+        message: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::release_pending_transfer(
+            program,
+            bridge,
+            payer.pubkey(),
+            queued_message,
+            payer.pubkey(),
+            mint,
+            message.pubkey(),
+        )
+        .expect("Could not create Release Pending Transfer instruction");
+
+        for account in instruction.accounts.iter().enumerate() {
+            println!("{}: {}", account.0, account.1.pubkey);
+        }
+
+        execute(
+            client,
+            payer,
+            &[payer, message],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
     pub async fn complete_native(
         client: &mut BanksClient,
         program: Pubkey,
@@ -471,6 +679,40 @@ mod helpers {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn complete_native_by_recipient(
+        client: &mut BanksClient,
+        program: Pubkey,
+        bridge: Pubkey,
+        message_acc: Pubkey,
+        vaa: PostVAAData,
+        payload: PayloadTransfer,
+        payer: &Keypair,
+        to_owner: &Keypair,
+    ) -> Result<(), TransportError> {
+        let instruction = instructions::complete_native_by_recipient(
+            program,
+            bridge,
+            payer.pubkey(),
+            message_acc,
+            vaa,
+            Pubkey::new(&payload.to[..]),
+            to_owner.pubkey(),
+            Pubkey::new(&payload.token_address[..]),
+            CompleteNativeByRecipientData {},
+        )
+        .expect("Could not create Complete Native By Recipient instruction");
+
+        execute(
+            client,
+            payer,
+            &[payer, to_owner],
+            &[instruction],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
     pub async fn complete_transfer_wrapped(
         client: &mut BanksClient,
         program: Pubkey,
@@ -509,6 +751,7 @@ mod helpers {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn complete_native_with_payload(
         client: &mut BanksClient,
         program: Pubkey,
@@ -587,6 +830,7 @@ mod helpers {
         payer: &Keypair,
         mint_authority: &Pubkey,
         mint: &Keypair,
+        decimals: u8,
     ) -> Result<(), TransportError> {
         let mint_key = mint.pubkey();
         execute(
@@ -606,7 +850,7 @@ mod helpers {
                     &mint_key,
                     mint_authority,
                     None,
-                    0,
+                    decimals,
                 )
                 .unwrap(),
             ],
@@ -709,6 +953,109 @@ mod helpers {
         .await
     }
 
+    /// Create a Token-2022 mint, `init_extensions` must initialize each of `extensions`.
+    pub async fn create_mint_2022(
+        client: &mut BanksClient,
+        payer: &Keypair,
+        mint_authority: &Pubkey,
+        mint: &Keypair,
+        extensions: &[ExtensionType],
+        init_extensions: Vec<Instruction>,
+    ) -> Result<(), TransportError> {
+        let mint_key = mint.pubkey();
+        let space = ExtensionType::get_account_len::<spl_token_2022::state::Mint>(extensions);
+        let mut instructions = vec![solana_sdk::system_instruction::create_account(
+            &payer.pubkey(),
+            &mint_key,
+            Rent::default().minimum_balance(space),
+            space as u64,
+            &spl_token_2022::id(),
+        )];
+        instructions.extend(init_extensions);
+        instructions.push(
+            spl_token_2022::instruction::initialize_mint(
+                &spl_token_2022::id(),
+                &mint_key,
+                mint_authority,
+                None,
+                0,
+            )
+            .unwrap(),
+        );
+        execute(
+            client,
+            payer,
+            &[payer, mint],
+            &instructions,
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
+    /// Create a Token-2022 account sized for the account extensions required by the mint.
+    pub async fn create_token_account_2022(
+        client: &mut BanksClient,
+        payer: &Keypair,
+        token_acc: &Keypair,
+        token_authority: &Pubkey,
+        mint: &Pubkey,
+        mint_extensions: &[ExtensionType],
+    ) -> Result<(), TransportError> {
+        let token_key = token_acc.pubkey();
+        let space = ExtensionType::get_account_len::<spl_token_2022::state::Account>(
+            &ExtensionType::get_required_init_account_extensions(mint_extensions),
+        );
+        execute(
+            client,
+            payer,
+            &[payer, token_acc],
+            &[
+                solana_sdk::system_instruction::create_account(
+                    &payer.pubkey(),
+                    &token_key,
+                    Rent::default().minimum_balance(space),
+                    space as u64,
+                    &spl_token_2022::id(),
+                ),
+                spl_token_2022::instruction::initialize_account(
+                    &spl_token_2022::id(),
+                    &token_key,
+                    mint,
+                    token_authority,
+                )
+                .unwrap(),
+            ],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
+    pub async fn mint_tokens_2022(
+        client: &mut BanksClient,
+        payer: &Keypair,
+        mint_authority: &Keypair,
+        mint: &Keypair,
+        token_account: &Pubkey,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        execute(
+            client,
+            payer,
+            &[payer, mint_authority],
+            &[spl_token_2022::instruction::mint_to(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                token_account,
+                &mint_authority.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap()],
+            CommitmentLevel::Processed,
+        )
+        .await
+    }
+
     /// Utility function for generating VAA's from message data.
     pub fn generate_vaa<T: Into<Vec<u8>>>(
         emitter: Address,
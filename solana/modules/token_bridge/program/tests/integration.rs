@@ -1,9 +1,11 @@
 #![allow(dead_code)]
 use bridge::{
     accounts::{
+        FeeCollector,
         PostedVAA,
         PostedVAADerivationData,
     },
+    PostVAAData,
     SerializePayload,
 };
 use libsecp256k1::SecretKey;
@@ -15,34 +17,80 @@ use solana_program_test::{
     BanksClient,
 };
 use solana_sdk::{
+    commitment_config::CommitmentLevel,
+    instruction::InstructionError,
     signature::{
         Keypair,
         Signer,
     },
+    transaction::TransactionError,
     transport::TransportError,
 };
 use solitaire::{
     processors::seeded::Seeded,
     AccountState,
+    ErrBox,
 };
 
+use spl_token_2022::extension::{
+    transfer_fee::instruction::initialize_transfer_fee_config,
+    ExtensionType,
+};
 use std::{
     collections::HashMap,
     str::FromStr,
 };
 use token_bridge::{
     accounts::{
+        AuthoritySigner,
         ConfigAccount,
+        CustodyAccount,
+        CustodyAccountDerivationData,
+        CustodyLedgerAccount,
+        CustodyLedgerDerivationData,
+        Endpoint,
+        EndpointDerivationData,
+        PauseAccount,
+        PendingTransferAccount,
+        PendingTransferDerivationData,
+        TemporaryAccount,
+        TemporaryAccountDerivationData,
+        TransferLimitAccount,
+        TransferLimitDerivationData,
+        WrappedAttestationAccount,
+        WrappedAttestationDerivationData,
         WrappedDerivationData,
         WrappedMint,
     },
     messages::{
         PayloadAssetMeta,
+        PayloadGovernanceDeregisterChain,
         PayloadGovernanceRegisterChain,
+        PayloadGovernanceSetPause,
+        PayloadGovernanceSetTransferLimit,
+        PayloadGovernanceUpdateChain,
         PayloadTransfer,
         PayloadTransferWithPayload
     },
-    types::Config,
+    reconcile::custody_report,
+    types::{
+        Config,
+        CustodyLedger,
+        PauseConfig,
+        PauseTarget,
+        PendingTransfer,
+        SplAccount,
+        TransferLimit,
+        WrappedAttestation,
+    },
+    CompleteNativeData,
+    CompleteNativeRelayedData,
+    CompleteNativeSolData,
+    CreateWrappedData,
+    TokenBridgeError,
+    TransferNativeData,
+    TransferNativeSolData,
+    TransferNativeWithPayloadData,
 };
 
 mod common;
@@ -166,6 +214,7 @@ async fn set_up() -> Result<Context, TransportError> {
         &context.payer,
         &context.mint_authority.pubkey(),
         &context.mint,
+        0,
     )
     .await?;
 
@@ -357,6 +406,355 @@ async fn transfer_native() {
     .unwrap();
 }
 
+#[tokio::test]
+async fn transfer_native_rejects_foreign_mint() {
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint_authority,
+        ref token_account,
+        ref token_authority,
+        ..
+    } = set_up().await.unwrap();
+
+    // The token account holds the context mint, passing any other mint must be rejected before
+    // the handler runs.
+    let other_mint = Keypair::new();
+    common::create_mint(client, payer, &mint_authority.pubkey(), &other_mint, 0)
+        .await
+        .unwrap();
+
+    let message = &Keypair::new();
+    let err = common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        message,
+        token_account,
+        token_authority,
+        other_mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap_err();
+
+    match err {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(code, TokenBridgeError::InvalidMint.code()),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn transfer_native_refunds_dust() {
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint_authority,
+        ref token_authority,
+        ..
+    } = set_up().await.unwrap();
+
+    // With 10 decimals the last two digits of an amount can't be bridged.
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    common::create_mint(client, payer, &mint_authority.pubkey(), &mint, 10)
+        .await
+        .unwrap();
+    common::create_token_account(
+        client,
+        payer,
+        &token_account,
+        &token_authority.pubkey(),
+        &mint.pubkey(),
+    )
+    .await
+    .unwrap();
+    common::mint_tokens(
+        client,
+        payer,
+        mint_authority,
+        &mint,
+        &token_account.pubkey(),
+        1000,
+    )
+    .await
+    .unwrap();
+
+    let message = &Keypair::new();
+    common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        message,
+        &token_account,
+        token_authority,
+        mint.pubkey(),
+        555,
+    )
+    .await
+    .unwrap();
+
+    // Only the bridged 500 left the account, the dust of 55 was refunded.
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData {
+            mint: mint.pubkey(),
+        },
+        &token_bridge,
+    );
+    let custody: SplAccount = common::get_account_data(client, custody_key).await.unwrap();
+    assert_eq!(custody.amount, 500);
+    let from: SplAccount = common::get_account_data(client, token_account.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(from.amount, 500);
+}
+
+#[tokio::test]
+async fn transfer_native_decimals_overflow() {
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint_authority,
+        ref token_authority,
+        ..
+    } = set_up().await.unwrap();
+
+    // 10^20 does not fit a u64, so amounts of this mint can't be converted.
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    common::create_mint(client, payer, &mint_authority.pubkey(), &mint, 28)
+        .await
+        .unwrap();
+    common::create_token_account(
+        client,
+        payer,
+        &token_account,
+        &token_authority.pubkey(),
+        &mint.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let message = &Keypair::new();
+    let err = common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        message,
+        &token_account,
+        token_authority,
+        mint.pubkey(),
+        0,
+    )
+    .await
+    .unwrap_err();
+
+    match err {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(code, TokenBridgeError::AmountOverflow.code()),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+/// Token-2022 accounts are approved through Token-2022, so the transfer is built here rather than
+/// through `common::transfer_native`.
+async fn transfer_native_2022(
+    context: &mut Context,
+    mint: &Keypair,
+    from: &Keypair,
+    amount: u64,
+) -> Result<(), TransportError> {
+    let message = &Keypair::new();
+    let instruction = token_bridge::instructions::transfer_native(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message.pubkey(),
+        from.pubkey(),
+        mint.pubkey(),
+        TransferNativeData {
+            nonce: 0,
+            amount,
+            fee: 0,
+            target_address: [0u8; 32],
+            target_chain: CHAIN_ID_ETH,
+        },
+    )
+    .unwrap();
+
+    common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer, &context.token_authority, message],
+        &[
+            spl_token_2022::instruction::approve(
+                &spl_token_2022::id(),
+                &from.pubkey(),
+                &token_bridge::accounts::AuthoritySigner::key(None, &context.token_bridge),
+                &context.token_authority.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap(),
+            instruction,
+        ],
+        CommitmentLevel::Processed,
+    )
+    .await
+}
+
+fn assert_unsupported_mint_extension(err: TransportError) {
+    match err {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(code, TokenBridgeError::UnsupportedMintExtension.code()),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn transfer_native_token_2022_transfer_fee() {
+    let mut context = set_up().await.unwrap();
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    let extensions = [ExtensionType::TransferFeeConfig];
+
+    // 1% transfer fee, withheld on every transfer including the one into custody.
+    common::create_mint_2022(
+        &mut context.client,
+        &context.payer,
+        &context.mint_authority.pubkey(),
+        &mint,
+        &extensions,
+        vec![initialize_transfer_fee_config(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            None,
+            None,
+            100,
+            u64::MAX,
+        )
+        .unwrap()],
+    )
+    .await
+    .unwrap();
+    common::create_token_account_2022(
+        &mut context.client,
+        &context.payer,
+        &token_account,
+        &context.token_authority.pubkey(),
+        &mint.pubkey(),
+        &extensions,
+    )
+    .await
+    .unwrap();
+    common::mint_tokens_2022(
+        &mut context.client,
+        &context.payer,
+        &context.mint_authority,
+        &mint,
+        &token_account.pubkey(),
+        1000,
+    )
+    .await
+    .unwrap();
+
+    transfer_native_2022(&mut context, &mint, &token_account, 500)
+        .await
+        .unwrap();
+
+    // Custody only received the amount left after the transfer fee.
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData {
+            mint: mint.pubkey(),
+        },
+        &context.token_bridge,
+    );
+    let custody: SplAccount = common::get_account_data(&mut context.client, custody_key)
+        .await
+        .unwrap();
+    assert_eq!(custody.amount, 495);
+}
+
+#[tokio::test]
+async fn token_2022_non_transferable_rejected() {
+    let mut context = set_up().await.unwrap();
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    let extensions = [ExtensionType::NonTransferable];
+
+    common::create_mint_2022(
+        &mut context.client,
+        &context.payer,
+        &context.mint_authority.pubkey(),
+        &mint,
+        &extensions,
+        vec![spl_token_2022::instruction::initialize_non_transferable_mint(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+        )
+        .unwrap()],
+    )
+    .await
+    .unwrap();
+    common::create_token_account_2022(
+        &mut context.client,
+        &context.payer,
+        &token_account,
+        &context.token_authority.pubkey(),
+        &mint.pubkey(),
+        &extensions,
+    )
+    .await
+    .unwrap();
+    common::mint_tokens_2022(
+        &mut context.client,
+        &context.payer,
+        &context.mint_authority,
+        &mint,
+        &token_account.pubkey(),
+        1000,
+    )
+    .await
+    .unwrap();
+
+    // Tokens locked in custody could never be released again, so neither attesting nor
+    // transferring the mint is allowed.
+    let message = &Keypair::new();
+    let err = common::attest(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        mint.pubkey(),
+        0,
+    )
+    .await
+    .unwrap_err();
+    assert_unsupported_mint_extension(err);
+
+    let err = transfer_native_2022(&mut context, &mint, &token_account, 500)
+        .await
+        .unwrap_err();
+    assert_unsupported_mint_extension(err);
+}
+
 async fn register_chain(context: &mut Context) {
     let Context {
         ref payer,
@@ -618,3 +1016,2133 @@ async fn transfer_native_with_payload_in() {
     .await
     .unwrap();
 }
+
+async fn update_chain(
+    context: &mut Context,
+    endpoint_address: [u8; 32],
+    sequence: u64,
+) -> Result<(), TransportError> {
+    let Context {
+        ref payer,
+        ref mut client,
+        ref bridge,
+        ref token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let payload = PayloadGovernanceUpdateChain {
+        chain: 2,
+        endpoint_address,
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) =
+        common::generate_vaa(emitter.pubkey().to_bytes(), 1, message, nonce, sequence);
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+
+    let msg_derivation_data = &PostedVAADerivationData {
+        payload_hash: body.to_vec(),
+    };
+    let message_key =
+        PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(msg_derivation_data, bridge);
+
+    // Chain 2 is registered with the zero address by `register_chain`.
+    common::update_chain(
+        client,
+        *token_bridge,
+        *bridge,
+        message_key,
+        vaa,
+        payload,
+        [0u8; 32],
+        payer,
+    )
+    .await
+}
+
+async fn deregister_chain(context: &mut Context, endpoint_address: [u8; 32], sequence: u64) {
+    let Context {
+        ref payer,
+        ref mut client,
+        ref bridge,
+        ref token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let payload = PayloadGovernanceDeregisterChain {
+        chain: 2,
+        endpoint_address,
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) =
+        common::generate_vaa(emitter.pubkey().to_bytes(), 1, message, nonce, sequence);
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+
+    let msg_derivation_data = &PostedVAADerivationData {
+        payload_hash: body.to_vec(),
+    };
+    let message_key =
+        PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(msg_derivation_data, bridge);
+
+    common::deregister_chain(
+        client,
+        *token_bridge,
+        *bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap();
+}
+
+/// Fund custody with 100 native tokens, so redemptions have something to release.
+async fn fund_custody(context: &mut Context) {
+    let message = &Keypair::new();
+    common::transfer_native(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        &context.token_account,
+        &context.token_authority,
+        context.mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+}
+
+/// Redeem a native transfer of 10 tokens emitted by `emitter_address` on chain 2.
+async fn redeem_native_from(
+    context: &mut Context,
+    emitter_address: [u8; 32],
+) -> Result<(), TransportError> {
+    let Context {
+        ref payer,
+        ref mut client,
+        ref bridge,
+        ref token_bridge,
+        ref mint,
+        ref token_account,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadTransfer {
+        amount: U256::from(10u128),
+        token_address: mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) = common::generate_vaa(
+        emitter_address,
+        2,
+        message,
+        nonce,
+        rand::thread_rng().gen(),
+    );
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        bridge,
+    );
+
+    common::complete_native(
+        client,
+        *token_bridge,
+        *bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+}
+
+/// Post a transfer emitted by the endpoint registered for chain 2, returns the VAA along with the
+/// account it was posted to.
+async fn post_transfer(context: &mut Context, payload: &PayloadTransfer) -> (PostVAAData, Pubkey) {
+    let Context {
+        ref payer,
+        ref mut client,
+        ref bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let message = payload.try_to_vec().unwrap();
+    let (vaa, body, _) =
+        common::generate_vaa([0u8; 32], 2, message, nonce, rand::thread_rng().gen());
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        bridge,
+    );
+    (vaa, message_key)
+}
+
+#[tokio::test]
+async fn complete_native_relayed_pays_fee_to_associated_account() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: context.token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(10u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+
+    // The fee recipient has no token account yet, the payer creates its associated one.
+    let fee_recipient = Keypair::new().pubkey();
+    let instruction = token_bridge::instructions::complete_native_relayed(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        context.token_account.pubkey(),
+        context.token_authority.pubkey(),
+        Some(fee_recipient),
+        context.mint.pubkey(),
+        spl_token::id(),
+        CompleteNativeRelayedData {},
+    )
+    .unwrap();
+    common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap();
+
+    let fee_account = spl_associated_token_account::get_associated_token_address(
+        &fee_recipient,
+        &context.mint.pubkey(),
+    );
+    let fees: SplAccount = common::get_account_data(&mut context.client, fee_account)
+        .await
+        .unwrap();
+    assert_eq!(fees.amount, 10);
+    let to: SplAccount =
+        common::get_account_data(&mut context.client, context.token_account.pubkey())
+            .await
+            .unwrap();
+    assert_eq!(to.amount, 940);
+}
+
+#[tokio::test]
+async fn complete_native_by_recipient_keeps_fee() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: context.token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(10u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+
+    // Nobody relayed the transfer, so the recipient keeps the fee.
+    common::complete_native_by_recipient(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        message_key,
+        vaa,
+        payload,
+        &context.payer,
+        &context.token_authority,
+    )
+    .await
+    .unwrap();
+
+    let to: SplAccount =
+        common::get_account_data(&mut context.client, context.token_account.pubkey())
+            .await
+            .unwrap();
+    assert_eq!(to.amount, 950);
+}
+
+#[tokio::test]
+async fn complete_native_to_owner_requires_associated_account() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    // Transfers naming the wallet are only paid out to its associated token account.
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: context.token_authority.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+    let instruction = token_bridge::instructions::complete_native(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        context.token_account.pubkey(),
+        None,
+        context.mint.pubkey(),
+        CompleteNativeData {},
+    )
+    .unwrap();
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_error(err, TokenBridgeError::InvalidTokenAccount);
+}
+
+#[tokio::test]
+async fn complete_native_without_pause() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: context.token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+
+    // Leaving the pause config out must not dodge it.
+    let mut instruction = token_bridge::instructions::complete_native(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        context.token_account.pubkey(),
+        None,
+        context.mint.pubkey(),
+        CompleteNativeData {},
+    )
+    .unwrap();
+    let pause_key =
+        PauseAccount::<'_, { AccountState::MaybeInitialized }>::key(None, &context.token_bridge);
+    instruction
+        .accounts
+        .retain(|account| account.pubkey != pause_key);
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_error(err, TokenBridgeError::MissingAccount);
+}
+
+#[tokio::test]
+async fn complete_native_without_ledger() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: context.token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+
+    // Leaving the ledger out must not skip recording the release.
+    let mut instruction = token_bridge::instructions::complete_native(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        context.token_account.pubkey(),
+        None,
+        context.mint.pubkey(),
+        CompleteNativeData {},
+    )
+    .unwrap();
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &CustodyLedgerDerivationData {
+            mint: context.mint.pubkey(),
+        },
+        &context.token_bridge,
+    );
+    instruction
+        .accounts
+        .retain(|account| account.pubkey != ledger_key);
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_error(err, TokenBridgeError::MissingAccount);
+}
+
+#[tokio::test]
+async fn complete_native_relayed_creates_recipient_account() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    // The transfer names a wallet that has no token account for the mint yet.
+    let wallet = Keypair::new().pubkey();
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: wallet.to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+
+    let to =
+        spl_associated_token_account::get_associated_token_address(&wallet, &context.mint.pubkey());
+    let instruction = token_bridge::instructions::complete_native_relayed(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        to,
+        wallet,
+        None,
+        context.mint.pubkey(),
+        spl_token::id(),
+        CompleteNativeRelayedData {},
+    )
+    .unwrap();
+    common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap();
+
+    let to: SplAccount = common::get_account_data(&mut context.client, to)
+        .await
+        .unwrap();
+    assert_eq!(to.owner, wallet);
+    assert_eq!(to.amount, 50);
+}
+
+#[tokio::test]
+async fn native_sol_round_trip() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    let native_mint = spl_token::native_mint::id();
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData { mint: native_mint },
+        &context.token_bridge,
+    );
+
+    // 5 lamports can't be represented with 8 decimals and stay with the sender.
+    let message = &Keypair::new();
+    let instruction = token_bridge::instructions::transfer_native_sol(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message.pubkey(),
+        TransferNativeSolData {
+            nonce: 0,
+            amount: 1_000_000_005,
+            fee: 0,
+            target_address: [0u8; 32],
+            target_chain: 2,
+        },
+    )
+    .unwrap();
+    common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer, message],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap();
+
+    let custody: SplAccount = common::get_account_data(&mut context.client, custody_key)
+        .await
+        .unwrap();
+    assert_eq!(custody.amount, 1_000_000_000);
+    let temporary_key = TemporaryAccount::<'_, { AccountState::Uninitialized }>::key(
+        &TemporaryAccountDerivationData {
+            message: message.pubkey(),
+        },
+        &context.token_bridge,
+    );
+    assert!(context
+        .client
+        .get_account(temporary_key)
+        .await
+        .unwrap()
+        .is_none());
+
+    let to = Keypair::new().pubkey();
+    let payload = PayloadTransfer {
+        amount: U256::from(50_000_000u128),
+        token_address: native_mint.to_bytes(),
+        token_chain: 1,
+        to: to.to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+    let instruction = token_bridge::instructions::complete_native_sol(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        to,
+        None,
+        CompleteNativeSolData {},
+    )
+    .unwrap();
+    common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        common::get_account_balance(&mut context.client, to).await,
+        500_000_000
+    );
+    let custody: SplAccount = common::get_account_data(&mut context.client, custody_key)
+        .await
+        .unwrap();
+    assert_eq!(custody.amount, 500_000_000);
+
+    // SOL is only paid out to wallets, token accounts are owned by the token program.
+    let payload = PayloadTransfer {
+        to: custody_key.to_bytes(),
+        ..payload
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+    let instruction = token_bridge::instructions::complete_native_sol(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        custody_key,
+        None,
+        CompleteNativeSolData {},
+    )
+    .unwrap();
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_error(err, TokenBridgeError::InvalidRecipient);
+}
+
+#[tokio::test]
+async fn custody_ledger_tracks_outstanding() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    let mint = context.mint.pubkey();
+    let custody_key = CustodyAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyAccountDerivationData { mint },
+        &context.token_bridge,
+    );
+    let ledger_key = CustodyLedgerAccount::<'_, { AccountState::Initialized }>::key(
+        &CustodyLedgerDerivationData { mint },
+        &context.token_bridge,
+    );
+
+    fund_custody(&mut context).await;
+    let ledger: CustodyLedger = common::get_account_data(&mut context.client, ledger_key)
+        .await
+        .unwrap();
+    assert_eq!(ledger.mint, mint);
+    assert_eq!(ledger.outstanding, 100);
+
+    redeem_native_from(&mut context, [0u8; 32]).await.unwrap();
+    let ledger: CustodyLedger = common::get_account_data(&mut context.client, ledger_key)
+        .await
+        .unwrap();
+    assert_eq!(ledger.outstanding, 90);
+
+    let instruction =
+        token_bridge::instructions::reconcile_custody(context.token_bridge, mint).unwrap();
+    common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap();
+
+    let mut accounts = HashMap::new();
+    for key in [custody_key, ledger_key] {
+        let account = context.client.get_account(key).await.unwrap().unwrap();
+        accounts.insert(key, account.data);
+    }
+    let source =
+        |key: &Pubkey| -> Result<Option<Vec<u8>>, ErrBox> { Ok(accounts.get(key).cloned()) };
+    let report = custody_report(&source, &context.token_bridge, &mint).unwrap();
+    assert_eq!(report.custody_balance, 90);
+    assert_eq!(report.outstanding, 90);
+    assert!(report.is_solvent());
+}
+
+#[tokio::test]
+async fn update_chain_closes_previous_endpoint() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    update_chain(&mut context, [9u8; 32], 1).await.unwrap();
+
+    // Only transfers from the new endpoint can be redeemed.
+    assert!(redeem_native_from(&mut context, [0u8; 32]).await.is_err());
+    redeem_native_from(&mut context, [9u8; 32]).await.unwrap();
+    let previous = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: 2,
+            emitter_address: [0u8; 32],
+        },
+        &context.token_bridge,
+    );
+    assert!(context
+        .client
+        .get_account(previous)
+        .await
+        .unwrap()
+        .is_none());
+
+    // The governance VAA is claimed and cannot be replayed.
+    assert!(update_chain(&mut context, [9u8; 32], 1).await.is_err());
+}
+
+#[tokio::test]
+async fn deregister_chain_rejects_transfers() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+
+    redeem_native_from(&mut context, [0u8; 32]).await.unwrap();
+    let endpoint = Endpoint::<'_, { AccountState::Initialized }>::key(
+        &EndpointDerivationData {
+            emitter_chain: 2,
+            emitter_address: [0u8; 32],
+        },
+        &context.token_bridge,
+    );
+    let rent = context
+        .client
+        .get_account(endpoint)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let fee_collector = FeeCollector::key(None, &context.bridge);
+    let collected = context.client.get_balance(fee_collector).await.unwrap();
+
+    deregister_chain(&mut context, [0u8; 32], 1).await;
+    assert!(redeem_native_from(&mut context, [0u8; 32]).await.is_err());
+
+    // The rent goes to the fee collector, not the payer.
+    assert!(context
+        .client
+        .get_account(endpoint)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        context.client.get_balance(fee_collector).await.unwrap(),
+        collected + rent
+    );
+}
+
+async fn set_transfer_limit(
+    context: &mut Context,
+    daily_limit: u64,
+    large_transfer_threshold: u64,
+    release_delay: u64,
+    sequence: u64,
+) {
+    let Context {
+        ref payer,
+        ref mut client,
+        ref bridge,
+        ref token_bridge,
+        ref guardian_keys,
+        ref mint,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let payload = PayloadGovernanceSetTransferLimit {
+        mint: mint.pubkey().to_bytes(),
+        daily_limit,
+        large_transfer_threshold,
+        release_delay,
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) =
+        common::generate_vaa(emitter.pubkey().to_bytes(), 1, message, nonce, sequence);
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+
+    let msg_derivation_data = &PostedVAADerivationData {
+        payload_hash: body.to_vec(),
+    };
+    let message_key =
+        PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(msg_derivation_data, bridge);
+
+    common::set_transfer_limit(
+        client,
+        *token_bridge,
+        *bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn transfer_native_queued_by_governor() {
+    let mut context = set_up().await.unwrap();
+    set_transfer_limit(&mut context, 1000, 50, 0, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint,
+        ref token_account,
+        ref token_authority,
+        ..
+    } = context;
+
+    // Transfers below the threshold go out right away.
+    let message = &Keypair::new();
+    common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        message,
+        token_account,
+        token_authority,
+        mint.pubkey(),
+        10,
+    )
+    .await
+    .unwrap();
+
+    // Large transfers are locked in custody, but held in a pending account.
+    let queued = &Keypair::new();
+    common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        queued,
+        token_account,
+        token_authority,
+        mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let pending_key = PendingTransferAccount::<'_, { AccountState::Initialized }>::key(
+        &PendingTransferDerivationData {
+            message: queued.pubkey(),
+        },
+        &token_bridge,
+    );
+    let pending: PendingTransfer = common::get_account_data(client, pending_key)
+        .await
+        .unwrap();
+    assert_eq!(pending.mint, mint.pubkey());
+    assert_eq!(pending.rent_payer, payer.pubkey());
+    assert!(client.get_account(queued.pubkey()).await.unwrap().is_none());
+
+    assert_eq!(pending.amount, 100);
+
+    // Without a delay the transfer can be released immediately, which closes the pending account.
+    let message = &Keypair::new();
+    common::release_pending_transfer(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        queued.pubkey(),
+        mint.pubkey(),
+        message,
+    )
+    .await
+    .unwrap();
+    assert!(client.get_account(pending_key).await.unwrap().is_none());
+    assert!(client.get_account(message.pubkey()).await.unwrap().is_some());
+
+    // The released amount uses up capacity like a direct transfer.
+    let limit_key = TransferLimitAccount::<'_, { AccountState::Initialized }>::key(
+        &TransferLimitDerivationData {
+            mint: mint.pubkey(),
+        },
+        &token_bridge,
+    );
+    let limit: TransferLimit = common::get_account_data(client, limit_key).await.unwrap();
+    assert_eq!(limit.outbound.total(), 110);
+}
+
+#[tokio::test]
+async fn transfer_native_without_governor_accounts() {
+    let mut context = set_up().await.unwrap();
+    set_transfer_limit(&mut context, 1000, 50, 0, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint,
+        ref token_account,
+        ref token_authority,
+        ..
+    } = context;
+
+    let limit_key = TransferLimitAccount::<'_, { AccountState::MaybeInitialized }>::key(
+        &TransferLimitDerivationData {
+            mint: mint.pubkey(),
+        },
+        &token_bridge,
+    );
+    let message = &Keypair::new();
+    let pending_key = PendingTransferAccount::<'_, { AccountState::Uninitialized }>::key(
+        &PendingTransferDerivationData {
+            message: message.pubkey(),
+        },
+        &token_bridge,
+    );
+
+    // Leaving either governor account out must not dodge the limit.
+    for left_out in [limit_key, pending_key] {
+        let mut instruction = token_bridge::instructions::transfer_native(
+            token_bridge,
+            bridge,
+            payer.pubkey(),
+            message.pubkey(),
+            token_account.pubkey(),
+            mint.pubkey(),
+            TransferNativeData {
+                nonce: 0,
+                amount: 100,
+                fee: 0,
+                target_address: [0u8; 32],
+                target_chain: 2,
+            },
+        )
+        .unwrap();
+        instruction
+            .accounts
+            .retain(|account| account.pubkey != left_out);
+        let err = common::execute(
+            client,
+            payer,
+            &[payer, token_authority, message],
+            &[
+                spl_token::instruction::approve(
+                    &spl_token::id(),
+                    &token_account.pubkey(),
+                    &AuthoritySigner::key(None, &token_bridge),
+                    &token_authority.pubkey(),
+                    &[],
+                    100,
+                )
+                .unwrap(),
+                instruction,
+            ],
+            CommitmentLevel::Processed,
+        )
+        .await
+        .unwrap_err();
+        assert_error(err, TokenBridgeError::MissingAccount);
+    }
+}
+
+#[tokio::test]
+async fn released_transfer_consumes_capacity() {
+    let mut context = set_up().await.unwrap();
+    set_transfer_limit(&mut context, 100, 60, 0, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint,
+        ref token_account,
+        ref token_authority,
+        ..
+    } = context;
+
+    let queued = &Keypair::new();
+    common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        queued,
+        token_account,
+        token_authority,
+        mint.pubkey(),
+        80,
+    )
+    .await
+    .unwrap();
+    common::release_pending_transfer(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        queued.pubkey(),
+        mint.pubkey(),
+        &Keypair::new(),
+    )
+    .await
+    .unwrap();
+
+    // Only 20 of the daily 100 are left, a transfer of 30 is queued instead of posted.
+    let message = &Keypair::new();
+    common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        message,
+        token_account,
+        token_authority,
+        mint.pubkey(),
+        30,
+    )
+    .await
+    .unwrap();
+    assert!(client
+        .get_account(message.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+    let pending_key = PendingTransferAccount::<'_, { AccountState::Initialized }>::key(
+        &PendingTransferDerivationData {
+            message: message.pubkey(),
+        },
+        &token_bridge,
+    );
+    assert!(client.get_account(pending_key).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn release_pending_transfer_before_delay() {
+    let mut context = set_up().await.unwrap();
+    set_transfer_limit(&mut context, 50, 0, 86400, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint,
+        ref token_account,
+        ref token_authority,
+        ..
+    } = context;
+
+    // Exceeding the daily limit queues the transfer just like a large transfer.
+    let queued = &Keypair::new();
+    common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        queued,
+        token_account,
+        token_authority,
+        mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let err = common::release_pending_transfer(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        queued.pubkey(),
+        mint.pubkey(),
+        &Keypair::new(),
+    )
+    .await
+    .unwrap_err();
+
+    match err {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(code, TokenBridgeError::TransferNotReleasable.code()),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn complete_native_over_transfer_limit() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+
+    // Fund custody before the limit is in place.
+    let message = &Keypair::new();
+    common::transfer_native(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        &context.token_account,
+        &context.token_authority,
+        context.mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    set_transfer_limit(&mut context, 50, 0, 0, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint,
+        ref token_account,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadTransfer {
+        amount: U256::from(100u128),
+        token_address: mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) = common::generate_vaa([0u8; 32], 2, message, nonce, 1);
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    let err = common::complete_native(
+        client,
+        token_bridge,
+        bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap_err();
+
+    match err {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(code, TokenBridgeError::TransferLimitExceeded.code()),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+async fn set_pause(context: &mut Context, target: PauseTarget, paused: bool, sequence: u64) {
+    try_set_pause(context, target, paused, sequence)
+        .await
+        .unwrap();
+}
+
+async fn try_set_pause(
+    context: &mut Context,
+    target: PauseTarget,
+    paused: bool,
+    sequence: u64,
+) -> Result<(), TransportError> {
+    let Context {
+        ref payer,
+        ref mut client,
+        ref bridge,
+        ref token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let emitter = Keypair::from_bytes(&GOVERNANCE_KEY).unwrap();
+    let payload = PayloadGovernanceSetPause { target, paused };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) =
+        common::generate_vaa(emitter.pubkey().to_bytes(), 1, message, nonce, sequence);
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+
+    let msg_derivation_data = &PostedVAADerivationData {
+        payload_hash: body.to_vec(),
+    };
+    let message_key =
+        PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(msg_derivation_data, bridge);
+
+    common::set_pause(client, *token_bridge, *bridge, message_key, vaa, payer).await
+}
+
+fn assert_error(err: TransportError, expected: TokenBridgeError) {
+    match err {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(code, expected.code()),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+fn assert_paused(err: TransportError) {
+    assert_error(err, TokenBridgeError::TransfersPaused);
+}
+
+#[tokio::test]
+async fn set_pause_in_sequence_order() {
+    let mut context = set_up().await.unwrap();
+    let mint = context.mint.pubkey();
+    let pause_key =
+        PauseAccount::<'_, { AccountState::Initialized }>::key(None, &context.token_bridge);
+    set_pause(&mut context, PauseTarget::Mint(mint), true, 2).await;
+
+    // An older action would undo the pause.
+    let err = try_set_pause(&mut context, PauseTarget::Mint(mint), false, 1)
+        .await
+        .unwrap_err();
+    assert_error(err, TokenBridgeError::StalePause);
+    let pause: PauseConfig = common::get_account_data(&mut context.client, pause_key)
+        .await
+        .unwrap();
+    assert_eq!(pause.sequence, 2);
+    assert_eq!(pause.mints, vec![mint]);
+
+    // Rent freed by resuming goes back to the bridge rather than to the submitter.
+    let fee_collector = FeeCollector::key(None, &context.bridge);
+    let collected = common::get_account_balance(&mut context.client, fee_collector).await;
+    set_pause(&mut context, PauseTarget::Mint(mint), false, 3).await;
+    assert!(common::get_account_balance(&mut context.client, fee_collector).await > collected);
+    let pause: PauseConfig = common::get_account_data(&mut context.client, pause_key)
+        .await
+        .unwrap();
+    assert_eq!(pause.sequence, 3);
+    assert!(pause.mints.is_empty());
+}
+
+#[tokio::test]
+async fn transfer_native_paused() {
+    let mut context = set_up().await.unwrap();
+    set_pause(&mut context, PauseTarget::Outbound, true, 1).await;
+
+    let message = &Keypair::new();
+    let err = common::transfer_native(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        &context.token_account,
+        &context.token_authority,
+        context.mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+
+    let pause: PauseConfig = common::get_account_data(
+        &mut context.client,
+        PauseAccount::<'_, { AccountState::Initialized }>::key(None, &context.token_bridge),
+    )
+    .await
+    .unwrap();
+    assert!(pause.outbound);
+
+    // Resuming lets transfers through again.
+    set_pause(&mut context, PauseTarget::Outbound, false, 2).await;
+    let message = &Keypair::new();
+    common::transfer_native(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        &context.token_account,
+        &context.token_authority,
+        context.mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn transfer_wrapped_paused_by_mint() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    let to = create_wrapped_account(&mut context).await.unwrap();
+    let wrapped = WrappedMint::<'_, { AccountState::Initialized }>::key(
+        &WrappedDerivationData {
+            token_chain: 2,
+            token_address: [1u8; 32],
+        },
+        &context.token_bridge,
+    );
+
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadTransfer {
+        amount: U256::from(100000000),
+        token_address: [1u8; 32],
+        token_chain: 2,
+        to: to.to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) =
+        common::generate_vaa([0u8; 32], 2, message, nonce, rand::thread_rng().gen());
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    common::complete_transfer_wrapped(
+        client,
+        token_bridge,
+        bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap();
+
+    set_pause(&mut context, PauseTarget::Mint(wrapped), true, 1).await;
+
+    let message = &Keypair::new();
+    let err = common::transfer_wrapped(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        to,
+        &context.token_authority,
+        2,
+        [1u8; 32],
+        10000000,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn release_pending_transfer_paused_by_chain() {
+    let mut context = set_up().await.unwrap();
+    set_transfer_limit(&mut context, 1000, 50, 0, 1).await;
+
+    let queued = &Keypair::new();
+    common::transfer_native(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        queued,
+        &context.token_account,
+        &context.token_authority,
+        context.mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    // Transfers queued before a pause are held until it is lifted.
+    set_pause(&mut context, PauseTarget::Chain(CHAIN_ID_ETH), true, 2).await;
+    let err = common::release_pending_transfer(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        queued.pubkey(),
+        context.mint.pubkey(),
+        &Keypair::new(),
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn complete_native_paused() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+
+    // Fund custody before the pause is in place.
+    let message = &Keypair::new();
+    common::transfer_native(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        &context.token_account,
+        &context.token_authority,
+        context.mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    set_pause(&mut context, PauseTarget::Inbound, true, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint,
+        ref token_account,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadTransfer {
+        amount: U256::from(100u128),
+        token_address: mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) = common::generate_vaa([0u8; 32], 2, message, nonce, 1);
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    let err = common::complete_native(
+        client,
+        token_bridge,
+        bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn complete_wrapped_paused_by_chain() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    let to = create_wrapped_account(&mut context).await.unwrap();
+    set_pause(&mut context, PauseTarget::Chain(CHAIN_ID_ETH), true, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadTransfer {
+        amount: U256::from(100000000),
+        token_address: [1u8; 32],
+        token_chain: 2,
+        to: to.to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) =
+        common::generate_vaa([0u8; 32], 2, message, nonce, rand::thread_rng().gen());
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    let err = common::complete_transfer_wrapped(
+        client,
+        token_bridge,
+        bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn complete_native_with_payload_paused_by_mint() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+
+    // Fund custody before the pause is in place.
+    let message = &Keypair::new();
+    common::transfer_native(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        &context.payer,
+        message,
+        &context.token_account,
+        &context.token_authority,
+        context.mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let mint = context.mint.pubkey();
+    set_pause(&mut context, PauseTarget::Mint(mint), true, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref token_account,
+        ref token_authority,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadTransferWithPayload {
+        amount: U256::from(100u128),
+        token_address: mint.to_bytes(),
+        token_chain: CHAIN_ID_SOLANA,
+        to: token_authority.pubkey().to_bytes(),
+        to_chain: CHAIN_ID_SOLANA,
+        from_address: Keypair::new().pubkey().to_bytes(),
+        payload: vec![1, 2, 3],
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) = common::generate_vaa([0u8; 32], CHAIN_ID_ETH, message, nonce, 1);
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    let err = common::complete_native_with_payload(
+        client,
+        token_bridge,
+        bridge,
+        message_key,
+        vaa,
+        payload,
+        token_account.pubkey(),
+        token_authority,
+        payer,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn create_wrapped_paused() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    set_pause(&mut context, PauseTarget::Inbound, true, 1).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadAssetMeta {
+        token_address: [1u8; 32],
+        token_chain: 2,
+        decimals: 7,
+        symbol: "".to_string(),
+        name: "".to_string(),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) = common::generate_vaa([0u8; 32], 2, message, nonce, 2);
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    let err = common::create_wrapped(
+        client,
+        token_bridge,
+        bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn transfer_native_with_payload_paused() {
+    let mut context = set_up().await.unwrap();
+    set_pause(&mut context, PauseTarget::Outbound, true, 1).await;
+
+    let message = &Keypair::new();
+    let instruction = token_bridge::instructions::transfer_native_with_payload(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message.pubkey(),
+        context.token_account.pubkey(),
+        context.mint.pubkey(),
+        TransferNativeWithPayloadData {
+            nonce: 0,
+            amount: 100,
+            target_address: [0u8; 32],
+            target_chain: CHAIN_ID_ETH,
+            payload: vec![1, 2, 3],
+            cpi_program_id: None,
+        },
+    )
+    .unwrap();
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer, &context.token_authority, message],
+        &[
+            spl_token::instruction::approve(
+                &spl_token::id(),
+                &context.token_account.pubkey(),
+                &AuthoritySigner::key(None, &context.token_bridge),
+                &context.token_authority.pubkey(),
+                &[],
+                100,
+            )
+            .unwrap(),
+            instruction,
+        ],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn complete_native_relayed_paused() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+    set_pause(&mut context, PauseTarget::Inbound, true, 1).await;
+
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: context.token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(10u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+    let instruction = token_bridge::instructions::complete_native_relayed(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        context.token_account.pubkey(),
+        context.token_authority.pubkey(),
+        None,
+        context.mint.pubkey(),
+        spl_token::id(),
+        CompleteNativeRelayedData {},
+    )
+    .unwrap();
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn complete_native_by_recipient_paused_by_chain() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    fund_custody(&mut context).await;
+    set_pause(&mut context, PauseTarget::Chain(CHAIN_ID_ETH), true, 1).await;
+
+    let payload = PayloadTransfer {
+        amount: U256::from(50u128),
+        token_address: context.mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: context.token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+    let err = common::complete_native_by_recipient(
+        &mut context.client,
+        context.token_bridge,
+        context.bridge,
+        message_key,
+        vaa,
+        payload,
+        &context.payer,
+        &context.token_authority,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+#[tokio::test]
+async fn native_sol_paused() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    let native_mint = spl_token::native_mint::id();
+    let (token_bridge, bridge, payer) =
+        (context.token_bridge, context.bridge, context.payer.pubkey());
+    let transfer_sol = move |message: Pubkey| {
+        token_bridge::instructions::transfer_native_sol(
+            token_bridge,
+            bridge,
+            payer,
+            message,
+            TransferNativeSolData {
+                nonce: 0,
+                amount: 1_000_000_000,
+                fee: 0,
+                target_address: [0u8; 32],
+                target_chain: CHAIN_ID_ETH,
+            },
+        )
+        .unwrap()
+    };
+
+    // Fund custody before the pause is in place.
+    let message = &Keypair::new();
+    let instruction = transfer_sol(message.pubkey());
+    common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer, message],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap();
+
+    set_pause(&mut context, PauseTarget::Mint(native_mint), true, 1).await;
+    let message = &Keypair::new();
+    let instruction = transfer_sol(message.pubkey());
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer, message],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+
+    let to = Keypair::new().pubkey();
+    let payload = PayloadTransfer {
+        amount: U256::from(50_000_000u128),
+        token_address: native_mint.to_bytes(),
+        token_chain: 1,
+        to: to.to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let (vaa, message_key) = post_transfer(&mut context, &payload).await;
+    let instruction = token_bridge::instructions::complete_native_sol(
+        context.token_bridge,
+        context.bridge,
+        context.payer.pubkey(),
+        message_key,
+        vaa,
+        to,
+        None,
+        CompleteNativeSolData {},
+    )
+    .unwrap();
+    let err = common::execute(
+        &mut context.client,
+        &context.payer,
+        &[&context.payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap_err();
+    assert_paused(err);
+}
+
+/// Attest the wrapped token of `create_wrapped` again under a new name, from `emitter` on chain 2.
+async fn refresh_wrapped(
+    context: &mut Context,
+    emitter: [u8; 32],
+    name: &str,
+    sequence: u64,
+) -> Result<(), TransportError> {
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let payload = PayloadAssetMeta {
+        token_address: [1u8; 32],
+        token_chain: 2,
+        decimals: 7,
+        symbol: "".to_string(),
+        name: name.to_string(),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let nonce = rand::thread_rng().gen();
+    let (vaa, body, _) = common::generate_vaa(emitter, 2, message, nonce, sequence);
+    let signature_set = common::verify_signatures(client, bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, *bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        bridge,
+    );
+
+    common::create_wrapped(
+        client,
+        *token_bridge,
+        *bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+}
+
+fn attestation_key(context: &Context, mint: Pubkey) -> Pubkey {
+    WrappedAttestationAccount::<'_, { AccountState::Initialized }>::key(
+        &WrappedAttestationDerivationData { mint },
+        &context.token_bridge,
+    )
+}
+
+#[tokio::test]
+async fn create_wrapped_refreshes_metadata() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    let wrapped = create_wrapped(&mut context).await;
+
+    refresh_wrapped(
+        &mut context,
+        [0u8; 32],
+        "A token with a name longer than fits",
+        5,
+    )
+        .await
+        .unwrap();
+    let attestation_key = attestation_key(&context, wrapped);
+    let attestation: WrappedAttestation =
+        common::get_account_data(&mut context.client, attestation_key)
+            .await
+            .unwrap();
+    assert_eq!(attestation.sequence, 5);
+    assert_eq!(
+        &attestation.original_name,
+        b"A token with a name longer than "
+    );
+
+    // Older attestations would roll the metadata back.
+    let err = refresh_wrapped(&mut context, [0u8; 32], "Stale", 3)
+        .await
+        .unwrap_err();
+    assert_error(err, TokenBridgeError::StaleAttestation);
+
+    // Sequences of a new emitter start over.
+    update_chain(&mut context, [9u8; 32], 1).await.unwrap();
+    refresh_wrapped(&mut context, [9u8; 32], "Renamed", 1)
+        .await
+        .unwrap();
+    let attestation: WrappedAttestation =
+        common::get_account_data(&mut context.client, attestation_key)
+            .await
+            .unwrap();
+    assert_eq!(attestation.emitter_address, [9u8; 32]);
+    assert_eq!(attestation.sequence, 1);
+    assert_eq!(&attestation.original_name[..7], b"Renamed");
+}
+
+#[tokio::test]
+async fn refresh_wrapped_created_with_original_layout() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let payload = PayloadAssetMeta {
+        token_address: [1u8; 32],
+        token_chain: 2,
+        decimals: 7,
+        symbol: "".to_string(),
+        name: "".to_string(),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let nonce = rand::thread_rng().gen();
+    let (vaa, body, _) = common::generate_vaa([0u8; 32], 2, message, nonce, 2);
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    // Mints created before attestations were recorded have no attestation account.
+    let wrapped = WrappedMint::<'_, { AccountState::Initialized }>::key(
+        &WrappedDerivationData {
+            token_chain: 2,
+            token_address: [1u8; 32],
+        },
+        &token_bridge,
+    );
+    let key = WrappedAttestationAccount::<'_, { AccountState::Initialized }>::key(
+        &WrappedAttestationDerivationData { mint: wrapped },
+        &token_bridge,
+    );
+    let mut instruction = token_bridge::instructions::create_wrapped(
+        token_bridge,
+        bridge,
+        payer.pubkey(),
+        message_key,
+        vaa,
+        payload,
+        CreateWrappedData {},
+    )
+    .unwrap();
+    instruction.accounts.retain(|account| account.pubkey != key);
+    common::execute(
+        client,
+        payer,
+        &[payer],
+        &[instruction],
+        CommitmentLevel::Processed,
+    )
+    .await
+    .unwrap();
+    assert!(context.client.get_account(key).await.unwrap().is_none());
+
+    // Their first refresh opens it.
+    refresh_wrapped(&mut context, [0u8; 32], "Renamed", 5).await.unwrap();
+    let attestation: WrappedAttestation = common::get_account_data(&mut context.client, key)
+        .await
+        .unwrap();
+    assert_eq!(attestation.sequence, 5);
+    assert_eq!(&attestation.original_name[..7], b"Renamed");
+}
+
+/// Reports the compute units spent in each stage of redeeming a transfer. Requires the BPF build,
+/// run it on its own so logs of other tests do not mix in:
+/// `cargo test-bpf --features profile,instructions -- --nocapture compute_units`.
+#[cfg(feature = "profile")]
+#[tokio::test]
+async fn compute_units() {
+    let mut context = set_up().await.unwrap();
+    register_chain(&mut context).await;
+    let Context {
+        ref payer,
+        ref mut client,
+        bridge,
+        token_bridge,
+        ref mint,
+        ref token_account,
+        ref token_authority,
+        ref guardian_keys,
+        ..
+    } = context;
+
+    let logs = std::env::temp_dir().join("token-bridge-compute-units.log");
+    common::capture_logs(&logs);
+
+    let message = &Keypair::new();
+    common::transfer_native(
+        client,
+        token_bridge,
+        bridge,
+        payer,
+        message,
+        token_account,
+        token_authority,
+        mint.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let nonce = rand::thread_rng().gen();
+    let payload = PayloadTransfer {
+        amount: U256::from(100u128),
+        token_address: mint.pubkey().to_bytes(),
+        token_chain: 1,
+        to: token_account.pubkey().to_bytes(),
+        to_chain: 1,
+        fee: U256::from(0u128),
+    };
+    let message = payload.try_to_vec().unwrap();
+
+    let (vaa, body, _) = common::generate_vaa([0u8; 32], 2, message, nonce, 1);
+    let signature_set = common::verify_signatures(client, &bridge, payer, body, guardian_keys, 0)
+        .await
+        .unwrap();
+    common::post_vaa(client, bridge, payer, signature_set, vaa.clone())
+        .await
+        .unwrap();
+    let message_key = PostedVAA::<'_, { AccountState::MaybeInitialized }>::key(
+        &PostedVAADerivationData {
+            payload_hash: body.to_vec(),
+        },
+        &bridge,
+    );
+
+    common::complete_native(
+        client,
+        token_bridge,
+        bridge,
+        message_key,
+        vaa,
+        payload,
+        payer,
+    )
+    .await
+    .unwrap();
+
+    let costs = common::profile(&logs);
+    for cost in &costs {
+        println!("{} {}: {} units", cost.instruction, cost.stage, cost.units);
+    }
+    for instruction in &["TransferNative", "CompleteNative"] {
+        assert!(
+            solitaire::profile::instruction_cost(&costs, instruction) > 0,
+            "{} was not profiled",
+            instruction
+        );
+    }
+}
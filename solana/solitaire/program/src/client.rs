@@ -0,0 +1,182 @@
+//! Client side instruction building.
+//!
+//! Instructions are built from the same account structs the program peels, so account order,
+//! writability and signer flags can not drift from the on-chain definition. Accounts derived from
+//! a static seed and sysvars are filled in automatically, every other account is passed through
+//! the setters `#[derive(FromAccounts)]` generates on `<Name>Builder`.
+
+use std::collections::HashMap;
+
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{
+        AccountMeta,
+        Instruction,
+    },
+    pubkey::Pubkey,
+};
+
+use crate::{
+    idl::{
+        self,
+        IdlAccount,
+        IdlMutability,
+    },
+    processors::seeded::Seeded,
+    versioned,
+    ErrBox,
+    Peel,
+    Versioned,
+};
+
+/// Keys set on a field of a generated builder: a single account key, or the builder of a nested
+/// accounts struct.
+pub trait AccountKeys {
+    fn apply(self, name: &str, builder: InstructionBuilder) -> InstructionBuilder;
+}
+
+impl AccountKeys for Pubkey {
+    fn apply(self, name: &str, builder: InstructionBuilder) -> InstructionBuilder {
+        builder.key(name, self)
+    }
+}
+
+pub struct InstructionBuilder {
+    program_id: Pubkey,
+    accounts: Vec<IdlAccount>,
+    keys: HashMap<String, Pubkey>,
+    writable: Vec<String>,
+    remaining: Vec<AccountMeta>,
+}
+
+impl InstructionBuilder {
+    /// Start building an instruction taking the accounts of `A`. Accounts structs expose a typed
+    /// wrapper of this as `builder` through `#[derive(FromAccounts)]`.
+    pub fn new<'a, 'b: 'a, 'c, A: Peel<'a, 'b, 'c>>(program_id: Pubkey) -> Self {
+        InstructionBuilder {
+            program_id,
+            accounts: A::describe(""),
+            keys: HashMap::new(),
+            writable: Vec::new(),
+            remaining: Vec::new(),
+        }
+    }
+
+    /// Set the key of an account. Fields of nested account structs are named `outer.inner`.
+    pub fn key(mut self, name: &str, key: Pubkey) -> Self {
+        self.keys.insert(name.to_string(), key);
+        self
+    }
+
+    /// Set the key of an account derived at runtime through its `Seeded` implementation.
+    pub fn seeded<S: Seeded<I>, I>(self, name: &str, accs: I) -> Self {
+        let key = S::key(accs, &self.program_id);
+        self.key(name, key)
+    }
+
+    /// Pass an account that accepts either as writable, accounts of this kind are passed readonly
+    /// unless requested.
+    pub fn writable(mut self, name: &str) -> Self {
+        self.writable.push(name.to_string());
+        self
+    }
+
+    /// Append accounts that are not part of the accounts struct, such as programs needed for CPI or
+    /// the accounts of a `Remaining` field.
+    pub fn remaining(mut self, accounts: Vec<AccountMeta>) -> Self {
+        self.remaining.extend(accounts);
+        self
+    }
+
+    /// Append the rent sysvar, which handlers creating accounts expect after their accounts.
+    pub fn with_rent(self) -> Self {
+        self.remaining(vec![AccountMeta::new_readonly(
+            solana_program::sysvar::rent::id(),
+            false,
+        )])
+    }
+
+    /// Append the system program, which handlers creating accounts expect after their accounts.
+    pub fn with_system_program(self) -> Self {
+        self.remaining(vec![AccountMeta::new_readonly(
+            solana_program::system_program::id(),
+            false,
+        )])
+    }
+
+    /// Take over the keys set on the builder of a nested accounts struct, under the field `name`.
+    pub fn nest(mut self, name: &str, nested: InstructionBuilder) -> Self {
+        for (key, value) in nested.keys {
+            self.keys.insert(idl::nested(name, &key), value);
+        }
+        for key in nested.writable {
+            self.writable.push(idl::nested(name, &key));
+        }
+        self.remaining.extend(nested.remaining);
+        self
+    }
+
+    /// Build the instruction. `instruction` is the variant of the program's generated
+    /// `Instruction` enum and `data` its payload.
+    pub fn build<I: BorshSerialize, D: BorshSerialize>(
+        self,
+        instruction: I,
+        data: D,
+    ) -> Result<Instruction, ErrBox> {
+        let data = (instruction, data).try_to_vec()?;
+        self.build_with(data)
+    }
+
+    /// Build an instruction whose data is versioned, see `Versioned`.
+    pub fn build_versioned<I: BorshSerialize, D: Versioned + BorshSerialize>(
+        self,
+        instruction: I,
+        data: D,
+    ) -> Result<Instruction, ErrBox> {
+        let data = versioned::encode(instruction, data)?;
+        self.build_with(data)
+    }
+
+    fn build_with(self, data: Vec<u8>) -> Result<Instruction, ErrBox> {
+        for name in self.keys.keys().chain(self.writable.iter()) {
+            if !self.accounts.iter().any(|a| &a.name == name) {
+                return Err(format!("unknown account {}", name).into());
+            }
+        }
+
+        let mut accounts = Vec::with_capacity(self.accounts.len() + self.remaining.len());
+        for account in self.accounts.iter().filter(|a| !a.remaining) {
+            let key = match (
+                self.keys.get(&account.name),
+                &account.seed,
+                &account.address,
+            ) {
+                (Some(key), _, _) => *key,
+                (None, Some(seed), _) => {
+                    Pubkey::find_program_address(&[seed.as_bytes()], &self.program_id).0
+                }
+                (None, None, Some(address)) => address.parse()?,
+                (None, None, None) if account.optional => Pubkey::new_from_array([0u8; 32]),
+                (None, None, None) => {
+                    return Err(format!("missing account {}", account.name).into())
+                }
+            };
+            let writable = match account.mutability {
+                IdlMutability::Writable => true,
+                IdlMutability::Readonly => false,
+                IdlMutability::Maybe => self.writable.contains(&account.name),
+            };
+            accounts.push(match writable {
+                true => AccountMeta::new(key, account.signer),
+                false => AccountMeta::new_readonly(key, account.signer),
+            });
+        }
+        accounts.extend(self.remaining);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data,
+        })
+    }
+}
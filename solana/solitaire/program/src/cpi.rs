@@ -0,0 +1,222 @@
+//! Cross program invocation of Solitaire programs.
+//!
+//! `#[derive(FromAccounts)]` generates a `<Name>Cpi` struct next to accounts structs marked
+//! `#[cpi]`, holding one `AccountInfo` per field in the order the program peels them. Signer and
+//! mutability flags are taken from the layers of each field, so a caller only names the accounts
+//! and the compiler guarantees none is missing or out of order. Derivations, owners and data are
+//! left to the program being invoked. Fields are forwarded as single accounts, nested accounts
+//! structs are not expanded, and a `Remaining` field forwards a `Vec` of accounts.
+//!
+//! The generated struct can also be embedded as a field of the caller's own accounts struct, in
+//! which case only the mutability of its accounts is checked while peeling.
+
+use std::slice::Iter;
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{
+        next_account_info,
+        AccountInfo,
+    },
+    instruction::{
+        AccountMeta,
+        Instruction,
+    },
+    program::{
+        invoke,
+        invoke_signed,
+    },
+    pubkey::Pubkey,
+};
+
+use crate::{
+    idl::{
+        IdlAccount,
+        IdlMutability,
+    },
+    processors::seeded::Seeded,
+    ExecutionContext,
+    Result,
+    SolitaireError,
+};
+
+/// Accounts of an instruction of another program, implemented by the generated `<Name>Cpi`
+/// structs.
+pub trait CpiAccounts {
+    /// Account metas in the order the invoked program expects them.
+    fn to_account_metas(&self) -> Vec<AccountMeta>;
+}
+
+/// An instruction of another program ready to be invoked.
+pub struct CpiContext<A> {
+    pub program_id: Pubkey,
+    pub accounts: A,
+    remaining: Vec<AccountMeta>,
+}
+
+impl<A: CpiAccounts> CpiContext<A> {
+    pub fn new(program_id: Pubkey, accounts: A) -> Self {
+        CpiContext {
+            program_id,
+            accounts,
+            remaining: Vec::new(),
+        }
+    }
+
+    /// Append accounts that are not part of the accounts struct, such as programs the invoked
+    /// instruction calls in turn.
+    pub fn remaining(mut self, accounts: Vec<AccountMeta>) -> Self {
+        self.remaining.extend(accounts);
+        self
+    }
+
+    /// Build the instruction. `instruction` is the variant of the invoked program's generated
+    /// `Instruction` enum and `data` its payload.
+    pub fn instruction<I: BorshSerialize, D: BorshSerialize>(
+        &self,
+        instruction: I,
+        data: D,
+    ) -> Result<Instruction> {
+        let mut accounts = self.accounts.to_account_metas();
+        accounts.extend(self.remaining.iter().cloned());
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: (instruction, data).try_to_vec()?,
+        })
+    }
+
+    /// Invoke the instruction with the accounts passed to the calling program.
+    pub fn invoke<I: BorshSerialize, D: BorshSerialize>(
+        &self,
+        ctx: &ExecutionContext,
+        instruction: I,
+        data: D,
+    ) -> Result<()> {
+        let ix = self.instruction(instruction, data)?;
+        invoke(&ix, ctx.accounts)?;
+        Ok(())
+    }
+
+    /// Invoke the instruction, signing for `seeded_acc` with the seeds it is derived from.
+    pub fn invoke_seeded<I: BorshSerialize, D: BorshSerialize, S, T: Seeded<S>>(
+        &self,
+        ctx: &ExecutionContext,
+        instruction: I,
+        data: D,
+        seeded_acc: &T,
+        accs: S,
+    ) -> Result<()> {
+        let ix = self.instruction(instruction, data)?;
+        let seeds = seeded_acc.self_bumped_seeds(accs, ctx.program_id);
+        let s: Vec<&[u8]> = seeds.iter().map(|item| item.as_slice()).collect();
+        invoke_signed(&ix, ctx.accounts, &[s.as_slice()])?;
+        Ok(())
+    }
+}
+
+/// Meta of a forwarded account. `writable` is `None` for accounts that accept either, which are
+/// forwarded as they were passed to the caller.
+pub fn account_meta(info: &AccountInfo, writable: Option<bool>, signer: bool) -> AccountMeta {
+    AccountMeta {
+        pubkey: *info.key,
+        is_signer: signer,
+        is_writable: writable.unwrap_or(info.is_writable),
+    }
+}
+
+/// Peel an account to forward, checking only its mutability.
+pub fn next_account<'a, 'b>(
+    iter: &mut Iter<'a, AccountInfo<'b>>,
+    writable: Option<bool>,
+) -> Result<AccountInfo<'b>> {
+    let info = next_account_info(iter)?;
+    match writable {
+        Some(writable) if writable != info.is_writable => Err(SolitaireError::InvalidMutability(
+            *info.key,
+            info.is_writable,
+        )),
+        _ => Ok(info.clone()),
+    }
+}
+
+/// Peel every account left to forward, checking only their mutability.
+pub fn remaining_accounts<'a, 'b>(
+    iter: &mut Iter<'a, AccountInfo<'b>>,
+    writable: Option<bool>,
+) -> Result<Vec<AccountInfo<'b>>> {
+    let mut accounts = Vec::with_capacity(iter.len());
+    while !iter.as_slice().is_empty() {
+        accounts.push(next_account(iter, writable)?);
+    }
+    Ok(accounts)
+}
+
+/// Describe an account to forward for the program IDL.
+pub fn describe(name: &str, writable: Option<bool>) -> IdlAccount {
+    IdlAccount {
+        mutability: match writable {
+            Some(true) => IdlMutability::Writable,
+            Some(false) => IdlMutability::Readonly,
+            None => IdlMutability::Maybe,
+        },
+        ..IdlAccount::new(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as solitaire;
+    use crate::{
+        FromAccounts,
+        Info,
+        Mut,
+        Peel,
+        Remaining,
+        Signer,
+    };
+
+    #[derive(FromAccounts)]
+    #[cpi]
+    struct Batch<'b> {
+        payer: Mut<Signer<Info<'b>>>,
+        signers: Remaining<Signer<Info<'b>>>,
+    }
+
+    #[test]
+    fn test_remaining_field_forwards_every_account() {
+        let program = Pubkey::new_unique();
+        let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut lamports = vec![0u64; 3];
+        let mut data = vec![[0u8; 0]; 3];
+        let infos: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .enumerate()
+            .map(|(i, ((key, lamports), data))| {
+                AccountInfo::new(key, true, i == 0, lamports, data, &program, false, 0)
+            })
+            .collect();
+
+        let batch = <BatchCpi as FromAccounts>::from(&program, &mut infos.iter(), &()).unwrap();
+        assert_eq!(batch.signers.len(), 2);
+        assert_eq!(
+            batch.to_account_metas(),
+            vec![
+                AccountMeta::new(keys[0], true),
+                AccountMeta::new_readonly(keys[1], true),
+                AccountMeta::new_readonly(keys[2], true),
+            ]
+        );
+
+        let described = <BatchCpi as Peel>::describe("");
+        assert!(!described[0].remaining);
+        assert!(described[1].remaining);
+        assert_eq!(described[1].mutability, IdlMutability::Readonly);
+
+        // Forwarded accounts keep the mutability the invoked program expects.
+        assert!(<BatchCpi as FromAccounts>::from(&program, &mut infos[1..].iter(), &()).is_err());
+    }
+}
@@ -49,7 +49,14 @@ pub enum SolitaireError {
     /// An instruction that wasn't recognised was sent.
     UnknownInstruction(u8),
 
-    Custom(u64),
+    /// A constraint declared on the account was not met.
+    ConstraintViolated(Pubkey),
+
+    /// The instruction payload was sent with a version the program does not know.
+    UnsupportedVersion(u8),
+
+    /// Error of the program itself, reported as is. Codes must stay below `SOLITAIRE_ERROR_BASE`.
+    Custom(u32),
 }
 
 impl From<ProgramError> for SolitaireError {
@@ -64,11 +71,127 @@ impl From<std::io::Error> for SolitaireError {
     }
 }
 
+/// Custom error codes below this value belong to the program, Solitaire's own errors are reported
+/// starting from here so the two never collide on chain.
+pub const SOLITAIRE_ERROR_BASE: u32 = 1000;
+
+impl SolitaireError {
+    /// Stable numeric code reported as `ProgramError::Custom` when the error leaves the program.
+    /// Wrapped `ProgramError`s are forwarded as is and have no code of their own.
+    pub fn code(&self) -> Option<u32> {
+        let offset = match self {
+            SolitaireError::Custom(code) => return Some(*code),
+            SolitaireError::ProgramError(_) => return None,
+            SolitaireError::InvalidMutability(..) => 0,
+            SolitaireError::InvalidSigner(_) => 1,
+            SolitaireError::InvalidSysvar(_) => 2,
+            SolitaireError::InvalidDerive(..) => 3,
+            SolitaireError::InvalidOwner(_) => 4,
+            SolitaireError::NonWriteableAccount(_) => 5,
+            SolitaireError::InstructionDeserializeFailed(_) => 6,
+            SolitaireError::IoError(_) => 7,
+            SolitaireError::AmbiguousOwner => 8,
+            SolitaireError::AlreadyInitialized(_) => 9,
+            SolitaireError::UnknownInstruction(_) => 10,
+            SolitaireError::ConstraintViolated(_) => 11,
+            SolitaireError::UnsupportedVersion(_) => 12,
+        };
+        Some(SOLITAIRE_ERROR_BASE + offset)
+    }
+}
+
 impl From<SolitaireError> for ProgramError {
     fn from(err: SolitaireError) -> ProgramError {
         match err {
             SolitaireError::ProgramError(e) => e,
-            _ => ProgramError::Custom(0),
+            e => ProgramError::Custom(e.code().unwrap_or_default()),
         }
     }
 }
+
+/// Entry of an error decoding table, mapping a `ProgramError::Custom` code back to the error that
+/// caused it.
+#[derive(Debug)]
+pub struct ErrorCode {
+    pub code: u32,
+    pub name: &'static str,
+    pub message: &'static str,
+}
+
+/// Decoding table for the errors raised by Solitaire itself.
+pub static SOLITAIRE_ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE,
+        name: "InvalidMutability",
+        message: "Account mutability does not match the instruction",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 1,
+        name: "InvalidSigner",
+        message: "Account was expected to sign the transaction",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 2,
+        name: "InvalidSysvar",
+        message: "Account is not the expected sysvar",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 3,
+        name: "InvalidDerive",
+        message: "Account does not match its expected derivation",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 4,
+        name: "InvalidOwner",
+        message: "Account is owned by the wrong program",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 5,
+        name: "NonWriteableAccount",
+        message: "Account was expected to be writeable",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 6,
+        name: "InstructionDeserializeFailed",
+        message: "Instruction data could not be deserialized",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 7,
+        name: "IoError",
+        message: "Account data could not be read or written",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 8,
+        name: "AmbiguousOwner",
+        message: "Owner of the account is ambiguous",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 9,
+        name: "AlreadyInitialized",
+        message: "Account has already been initialized",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 10,
+        name: "UnknownInstruction",
+        message: "Instruction is not known to the program",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 11,
+        name: "ConstraintViolated",
+        message: "Account does not meet a constraint of the instruction",
+    },
+    ErrorCode {
+        code: SOLITAIRE_ERROR_BASE + 12,
+        name: "UnsupportedVersion",
+        message: "Instruction data version is not supported",
+    },
+];
+
+/// Look up a `ProgramError::Custom` code, first in the program's own table and then in the one
+/// for errors raised by Solitaire.
+pub fn decode_error(code: u32, program_errors: &'static [ErrorCode]) -> Option<&'static ErrorCode> {
+    program_errors
+        .iter()
+        .chain(SOLITAIRE_ERROR_CODES.iter())
+        .find(|e| e.code == code)
+}
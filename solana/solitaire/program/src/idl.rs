@@ -0,0 +1,176 @@
+//! Interface description of a Solitaire program.
+//!
+//! The account layers already encode every constraint an instruction places on its accounts, so
+//! each layer also describes itself through `Peel::describe`. The `solitaire!` macro collects
+//! these descriptions into an `Idl` listing every instruction in dispatch order, which clients can
+//! use instead of hand-writing account orders. With the `idl` feature the description can be
+//! written out as JSON.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    error::SOLITAIRE_ERROR_CODES,
+    ErrorCode,
+    ExecutionContext,
+    Peel,
+    Result,
+    Versioned,
+};
+
+#[cfg(feature = "idl")]
+use serde::Serialize;
+
+/// Whether an instruction writes to an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "idl", derive(Serialize))]
+#[cfg_attr(feature = "idl", serde(rename_all = "snake_case"))]
+pub enum IdlMutability {
+    Readonly,
+    Writable,
+    /// Either is accepted, see `MaybeMut`.
+    Maybe,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "idl", derive(Serialize))]
+pub struct IdlAccount {
+    /// Field name in the accounts struct, nested structs are joined with a `.`.
+    pub name: String,
+    pub mutability: IdlMutability,
+    pub signer: bool,
+
+    /// A zero key may be passed instead of the account.
+    pub optional: bool,
+
+    /// Static seed the account is derived from. Accounts derived from instruction data are
+    /// verified by the handler and carry no seed here.
+    pub seed: Option<String>,
+
+    /// Fixed address of the account, such as a sysvar or the system program.
+    pub address: Option<String>,
+
+    /// Type of the account data.
+    pub data: Option<String>,
+
+    /// Expected initialization state of the account data.
+    pub state: Option<String>,
+
+    /// The account is repeated for every account left at the end of the instruction.
+    pub remaining: bool,
+}
+
+impl IdlAccount {
+    pub fn new(name: &str) -> Self {
+        IdlAccount {
+            name: name.to_string(),
+            mutability: IdlMutability::Readonly,
+            signer: false,
+            optional: false,
+            seed: None,
+            address: None,
+            data: None,
+            state: None,
+            remaining: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "idl", derive(Serialize))]
+pub struct IdlInstruction {
+    pub name: String,
+
+    /// First byte of the instruction data.
+    pub discriminant: u8,
+
+    /// Accounts in the order they must be passed.
+    pub accounts: Vec<IdlAccount>,
+
+    /// Type of the Borsh encoded data following the discriminant.
+    pub args: String,
+
+    /// Version of the data layout, 0 if the instruction is not versioned.
+    pub version: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "idl", derive(Serialize))]
+pub struct IdlError {
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "idl", derive(Serialize))]
+pub struct Idl {
+    pub name: String,
+    pub version: String,
+    pub instructions: Vec<IdlInstruction>,
+    pub errors: Vec<IdlError>,
+}
+
+impl Idl {
+    /// Attach the program's error table, followed by the errors raised by Solitaire itself.
+    pub fn with_errors(mut self, program_errors: &[ErrorCode]) -> Self {
+        self.errors = program_errors
+            .iter()
+            .chain(SOLITAIRE_ERROR_CODES.iter())
+            .map(|e| IdlError {
+                code: e.code,
+                name: e.name.to_string(),
+                message: e.message.to_string(),
+            })
+            .collect();
+        self
+    }
+
+    pub fn instruction(&self, name: &str) -> Option<&IdlInstruction> {
+        self.instructions.iter().find(|i| i.name == name)
+    }
+
+    #[cfg(feature = "idl")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+/// Name of a field of a nested accounts struct.
+pub fn nested(prefix: &str, name: &str) -> String {
+    match prefix {
+        "" => name.to_string(),
+        _ => format!("{}.{}", prefix, name),
+    }
+}
+
+pub fn address(key: &Pubkey) -> Option<String> {
+    Some(key.to_string())
+}
+
+/// Describe the instruction dispatched to `handler`. Only the handler's type is used, to find the
+/// accounts struct and instruction data it takes.
+pub fn instruction<'a, 'b: 'a, 'c, A: Peel<'a, 'b, 'c>, D>(
+    name: &str,
+    discriminant: u8,
+    _handler: fn(&ExecutionContext<'a, 'b>, &mut A, D) -> Result<()>,
+) -> IdlInstruction {
+    IdlInstruction {
+        name: name.to_string(),
+        discriminant,
+        accounts: A::describe(""),
+        args: std::any::type_name::<D>().to_string(),
+        version: 0,
+    }
+}
+
+/// Describe a versioned instruction, see `instruction`.
+pub fn versioned_instruction<'a, 'b: 'a, 'c, A: Peel<'a, 'b, 'c>, D: Versioned>(
+    name: &str,
+    discriminant: u8,
+    handler: fn(&ExecutionContext<'a, 'b>, &mut A, D) -> Result<()>,
+) -> IdlInstruction {
+    IdlInstruction {
+        version: D::VERSION,
+        ..instruction(name, discriminant, handler)
+    }
+}
@@ -5,7 +5,6 @@ pub use rocksalt::*;
 
 // Lacking:
 //
-// - Error is a lacking as its just a basic enum, maybe use errorcode.
 // - Client generation incomplete.
 
 // We need a few Solana things in scope in order to properly abstract Solana.
@@ -23,24 +22,38 @@ pub use borsh::{
 };
 
 // Expose all submodules for consumption.
+#[cfg(feature = "client")]
+pub mod client;
+pub mod cpi;
 pub mod error;
+pub mod idl;
 pub mod macros;
 pub mod processors;
+pub mod profile;
 pub mod types;
+pub mod versioned;
 
 // We can also re-export a set of types at module scope, this defines the intended API we expect
 // people to be able to use from top-level.
 pub use crate::{
+    cpi::{
+        CpiAccounts,
+        CpiContext,
+    },
     error::{
+        decode_error,
         ErrBox,
+        ErrorCode,
         Result,
         SolitaireError,
     },
     macros::*,
     processors::{
+        closable::Closable,
         keyed::Keyed,
         peel::Peel,
         persist::Persist,
+        resizable::Resizable,
         seeded::{
             invoke_seeded,
             AccountOwner,
@@ -51,6 +64,7 @@ pub use crate::{
         },
     },
     types::*,
+    versioned::Versioned,
 };
 
 /// Library name and version to print in entrypoint. Must be evaluated in this crate in order to do the right thing
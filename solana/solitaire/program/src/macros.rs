@@ -17,16 +17,41 @@ macro_rules! trace_impl {
     ( $($arg:tt)* ) => {};
 }
 
+/// Logs a named checkpoint followed by the compute units remaining, a no-op unless the `profile`
+/// feature is enabled. See `profile` for reading the checkpoints back out of program logs.
+#[macro_export]
+macro_rules! profile {
+    ( $label:expr ) => { $crate::profile_impl!( $label ) };
+}
+
+#[cfg(feature = "profile")]
+#[macro_export]
+macro_rules! profile_impl {
+    ( $label:expr ) => {
+        solana_program::msg!("{}{}", $crate::profile::CHECKPOINT, $label);
+        solana_program::log::sol_log_compute_units();
+    };
+}
+
+#[cfg(not(feature = "profile"))]
+#[macro_export]
+macro_rules! profile_impl {
+    ( $label:expr ) => {};
+}
+
 /// This is our main codegen macro. It takes as input a list of enum-like variants mapping field
 /// types to function calls. The generated code produces:
 ///
 /// - An `Instruction` enum with the enum variants passed in.
 /// - A set of functions which take as arguments the enum fields.
 /// - A Dispatcher that deserializes bytes into the enum and dispatches the function call.
+///   With the `profile` feature each stage of the call logs a compute unit checkpoint.
+///   Rows marked `(versioned)` decode their data through `Versioned`, see `versioned`.
+/// - An `idl` function describing each instruction's accounts and data.
 /// - A set of client calls scoped to the module `api` that can generate instructions.
 #[macro_export]
 macro_rules! solitaire {
-    { $($row:ident => $fn:ident),+ $(,)* } => {
+    { $($row:ident => $fn:ident $(($versioned:ident))?),+ $(,)* } => {
         pub mod instruction {
             use super::*;
             use borsh::{
@@ -60,13 +85,22 @@ macro_rules! solitaire {
                     use super::*;
 
                     #[inline(never)]
-                    pub fn execute<'a, 'b: 'a, 'c>(p: &Pubkey, a: &'c [AccountInfo<'b>], d: &[u8]) -> Result<()> {
-                        let ix_data = BorshDeserialize::try_from_slice(d).map_err(|e| SolitaireError::InstructionDeserializeFailed(e))?;
+                    pub fn execute<'a, 'b: 'a, 'c>(p: &Pubkey, a: &'c [AccountInfo<'b>], v: u8, d: &[u8]) -> Result<()> {
+                        solitaire::profile!(concat!(stringify!($row), " start"));
+                        let ix_data = solitaire::instruction_data!(decode, v, d $(, $versioned)?)?;
+                        solitaire::profile!(concat!(stringify!($row), " deserialize"));
                         let mut accounts = FromAccounts::from(p, &mut a.iter(), &())?;
+                        solitaire::profile!(concat!(stringify!($row), " peel"));
                         $fn(&ExecutionContext{program_id: p, accounts: a}, &mut accounts, ix_data)?;
+                        solitaire::profile!(concat!(stringify!($row), " handler"));
                         Persist::persist(&accounts, p)?;
+                        solitaire::profile!(concat!(stringify!($row), " persist"));
                         Ok(())
                     }
+
+                    pub fn describe() -> solitaire::idl::IdlInstruction {
+                        solitaire::instruction_data!(describe, $row, $fn $(, $versioned)?)
+                    }
                 }
             )*
 
@@ -82,9 +116,10 @@ macro_rules! solitaire {
             /// This entrypoint is generated from the enum above, it deserializes incoming bytes
             /// and automatically dispatches to the correct method.
             pub fn dispatch<'a, 'b: 'a, 'c>(p: &Pubkey, a: &'c [AccountInfo<'b>], d: &[u8]) -> Result<()> {
-                match d[0] {
+                let (discriminant, version, d) = solitaire::versioned::split(d)?;
+                match discriminant {
                     $(
-                        n if n == Instruction::$row as u8 => $row::execute(p, a, &d[1..]),
+                        n if n == Instruction::$row as u8 => $row::execute(p, a, version, d),
                     )*
 
                     other => {
@@ -93,6 +128,18 @@ macro_rules! solitaire {
                 }
             }
 
+            /// Generated:
+            /// Describes every instruction with the accounts and data it expects, in dispatch
+            /// order. Error tables can be attached with `Idl::with_errors`.
+            pub fn idl() -> solitaire::idl::Idl {
+                solitaire::idl::Idl {
+                    name: env!("CARGO_PKG_NAME").to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                    instructions: vec![$($row::describe(),)*],
+                    errors: vec![],
+                }
+            }
+
             pub fn solitaire(p: &Pubkey, a: &[AccountInfo], d: &[u8]) -> ProgramResult {
                 trace!("{} {} built with {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), solitaire::PKG_NAME_VERSION);
                 if let Err(err) = dispatch(p, a, d) {
@@ -1,4 +1,6 @@
+pub mod closable;
 pub mod keyed;
 pub mod peel;
 pub mod persist;
+pub mod resizable;
 pub mod seeded;
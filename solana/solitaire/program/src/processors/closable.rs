@@ -0,0 +1,84 @@
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    processors::keyed::Keyed,
+    trace,
+    Info,
+    Result,
+};
+
+/// Accounts owned by the program that can be closed to reclaim their rent.
+pub trait Closable<'a, 'b: 'a> {
+    /// Transfer all lamports to `recipient` and clear the account data. The runtime removes the
+    /// account at the end of the transaction, and closed `Data` accounts are not persisted.
+    fn close(&'a self, recipient: &Info) -> Result<()>;
+}
+
+impl<'a, 'b: 'a, T: Keyed<'a, 'b>> Closable<'a, 'b> for T {
+    fn close(&'a self, recipient: &Info) -> Result<()> {
+        let account = self.info();
+        trace!("Closing {}, refunding {}", account.key, recipient.key);
+
+        let lamports = recipient
+            .lamports()
+            .checked_add(account.lamports())
+            .ok_or(ProgramError::InvalidArgument)?;
+        **recipient.lamports.borrow_mut() = lamports;
+        **account.lamports.borrow_mut() = 0;
+
+        account.try_borrow_mut_data()?.fill(0);
+        account.realloc(0, false)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::{
+        account_info::AccountInfo,
+        pubkey::Pubkey,
+    };
+
+    #[test]
+    fn test_close_moves_lamports_and_clears_data() {
+        let program = Pubkey::new_unique();
+        let (key, recipient_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        // `realloc` stores the new length in front of the data, as in a program's serialized input.
+        let mut buffer = vec![0u8; 8 + 4];
+        buffer[8..].copy_from_slice(&[1, 2, 3, 4]);
+        let (mut lamports, mut recipient_lamports) = (10, 1);
+        let mut recipient_data = [0u8; 0];
+        {
+            let account = AccountInfo::new(
+                &key,
+                false,
+                true,
+                &mut lamports,
+                &mut buffer[8..],
+                &program,
+                false,
+                0,
+            );
+            let recipient = AccountInfo::new(
+                &recipient_key,
+                false,
+                true,
+                &mut recipient_lamports,
+                &mut recipient_data,
+                &program,
+                false,
+                0,
+            );
+
+            account.close(&recipient).unwrap();
+            assert_eq!(account.data_len(), 0);
+        }
+
+        assert_eq!(lamports, 0);
+        assert_eq!(recipient_lamports, 11);
+        assert_eq!(&buffer[8..], &[0u8; 4]);
+    }
+}
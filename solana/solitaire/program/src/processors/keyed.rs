@@ -1,3 +1,4 @@
+use bytemuck::Pod;
 use solana_program::sysvar::Sysvar as SolanaSysvar;
 
 use crate::{
@@ -10,6 +11,7 @@ use crate::{
     Signer,
     System,
     Sysvar,
+    ZeroCopy,
 };
 
 pub trait Keyed<'a, 'b: 'a> {
@@ -24,6 +26,14 @@ impl<'a, 'b: 'a, T: Owned + Default, const IS_INITIALIZED: AccountState> Keyed<'
     }
 }
 
+impl<'a, 'b: 'a, T: Pod + Owned, const IS_INITIALIZED: AccountState> Keyed<'a, 'b>
+    for ZeroCopy<'b, T, IS_INITIALIZED>
+{
+    fn info(&'a self) -> &'a Info<'b> {
+        &self.0
+    }
+}
+
 impl<'a, 'b: 'a, T> Keyed<'a, 'b> for Signer<T>
 where
     T: Keyed<'a, 'b>,
@@ -5,12 +5,20 @@
 //! the peel trait, which defines a set of types that recursively construct the desired type.
 
 use borsh::BorshDeserialize;
+use bytemuck::Pod;
 use solana_program::{
     pubkey::Pubkey,
     sysvar::Sysvar as SolanaSysvar,
 };
 
+use std::marker::PhantomData;
+
 use crate::{
+    idl::{
+        self,
+        IdlAccount,
+        IdlMutability,
+    },
     processors::seeded::{
         AccountOwner,
         Owned,
@@ -31,6 +39,15 @@ pub trait Peel<'a, 'b: 'a, 'c> {
         Self: Sized;
 
     fn persist(&self, program_id: &Pubkey) -> Result<()>;
+
+    /// Describe the accounts this layer consumes for the program IDL. Wrapping layers annotate the
+    /// description of the layer below, the default is a single unconstrained account.
+    fn describe(name: &str) -> Vec<IdlAccount>
+    where
+        Self: Sized,
+    {
+        vec![IdlAccount::new(name)]
+    }
 }
 
 /// Peel a nullable value (0-account means None)
@@ -59,6 +76,58 @@ impl<'a, 'b: 'a, 'c, T: Peel<'a, 'b, 'c>> Peel<'a, 'b, 'c> for Option<T> {
             Ok(())
         }
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut accounts = T::describe(name);
+        accounts.iter_mut().for_each(|a| a.optional = true);
+        accounts
+    }
+}
+
+/// Peel every remaining account. Each account gets a fresh context, so `T` can be any peelable
+/// type including nested accounts structs.
+impl<'a, 'b: 'a, 'c, T> Peel<'a, 'b, 'c> for Remaining<T>
+where
+    T: for<'d> Peel<'a, 'b, 'd>,
+{
+    fn peel<I>(ctx: &'c mut Context<'a, 'b, 'c, I>) -> Result<Self> {
+        let mut items = Vec::with_capacity(ctx.iter.len());
+        while ctx.iter.len() > 0 {
+            items.push(T::peel(&mut Context::new(ctx.this, ctx.iter, ctx.data))?);
+        }
+        Ok(Remaining(items))
+    }
+
+    fn persist(&self, program_id: &Pubkey) -> Result<()> {
+        for item in self.iter() {
+            T::persist(item, program_id)?;
+        }
+        Ok(())
+    }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut accounts = T::describe(name);
+        accounts.iter_mut().for_each(|a| a.remaining = true);
+        accounts
+    }
+}
+
+impl<'b> Remaining<MaybeMut<Info<'b>>> {
+    /// Peel the remaining account with `key` as a `T`, or `None` if it was not passed. Looking
+    /// accounts up by key lets an instruction take new optional accounts at the end without
+    /// breaking clients built against the old layout, whatever those pass after it.
+    pub fn find<'a, T>(&'a self, program_id: &'a Pubkey, key: &Pubkey) -> Result<Option<T>>
+    where
+        T: for<'c> Peel<'a, 'b, 'c>,
+    {
+        match self.iter().find(|account| account.key == key) {
+            Some(account) => {
+                let mut iter = std::slice::from_ref(&account.0).iter();
+                T::peel(&mut Context::new(program_id, &mut iter, &())).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 /// Peel a Derived Key
@@ -77,6 +146,14 @@ impl<'a, 'b: 'a, 'c, T: Peel<'a, 'b, 'c>, const SEED: &'static str> Peel<'a, 'b,
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         T::persist(self, program_id)
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut accounts = T::describe(name);
+        accounts
+            .iter_mut()
+            .for_each(|a| a.seed = Some(SEED.to_string()));
+        accounts
+    }
 }
 
 /// Peel a Mutable key.
@@ -94,6 +171,14 @@ impl<'a, 'b: 'a, 'c, T: Peel<'a, 'b, 'c>> Peel<'a, 'b, 'c> for Mut<T> {
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         T::persist(self, program_id)
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut accounts = T::describe(name);
+        accounts
+            .iter_mut()
+            .for_each(|a| a.mutability = IdlMutability::Writable);
+        accounts
+    }
 }
 
 impl<'a, 'b: 'a, 'c, T: Peel<'a, 'b, 'c>> Peel<'a, 'b, 'c> for MaybeMut<T> {
@@ -105,6 +190,14 @@ impl<'a, 'b: 'a, 'c, T: Peel<'a, 'b, 'c>> Peel<'a, 'b, 'c> for MaybeMut<T> {
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         T::persist(self, program_id)
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut accounts = T::describe(name);
+        accounts
+            .iter_mut()
+            .for_each(|a| a.mutability = IdlMutability::Maybe);
+        accounts
+    }
 }
 
 /// Peel a Signer.
@@ -119,6 +212,12 @@ impl<'a, 'b: 'a, 'c, T: Peel<'a, 'b, 'c>> Peel<'a, 'b, 'c> for Signer<T> {
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         T::persist(self, program_id)
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut accounts = T::describe(name);
+        accounts.iter_mut().for_each(|a| a.signer = true);
+        accounts
+    }
 }
 
 /// Expicitly depend upon the System account.
@@ -133,6 +232,14 @@ impl<'a, 'b: 'a, 'c, T: Peel<'a, 'b, 'c>> Peel<'a, 'b, 'c> for System<T> {
     fn persist(&self, program_id: &Pubkey) -> Result<()> {
         T::persist(self, program_id)
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut accounts = T::describe(name);
+        accounts
+            .iter_mut()
+            .for_each(|a| a.address = idl::address(&solana_program::system_program::id()));
+        accounts
+    }
 }
 
 /// Peel a Sysvar
@@ -153,6 +260,12 @@ where
     fn persist(&self, _program_id: &Pubkey) -> Result<()> {
         Ok(())
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut account = IdlAccount::new(name);
+        account.address = idl::address(&Var::id());
+        vec![account]
+    }
 }
 
 /// This is our structural recursion base case, the trait system will stop generating new nested
@@ -222,6 +335,11 @@ impl<
                         return Err(SolitaireError::InvalidOwner(*ctx.info().owner));
                     }
                 }
+                AccountOwner::OneOf(v) => {
+                    if !v.contains(ctx.info().owner) {
+                        return Err(SolitaireError::InvalidOwner(*ctx.info().owner));
+                    }
+                }
                 AccountOwner::Any => {}
             };
         }
@@ -240,8 +358,324 @@ impl<
             return Ok(());
         }
 
+        // Closed accounts have no data left to write to.
+        if self.0.lamports() == 0 {
+            return Ok(());
+        }
+
         self.1.serialize(&mut *self.0.data.borrow_mut())?;
 
         Ok(())
     }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut account = IdlAccount::new(name);
+        account.data = Some(std::any::type_name::<T>().to_string());
+        account.state = Some(format!("{:?}", IS_INITIALIZED));
+        vec![account]
+    }
+}
+
+/// Zero-copy counterpart of `Data`, the account bytes are only checked here and mapped on access.
+impl<'a, 'b: 'a, 'c, T: Pod + Owned, const IS_INITIALIZED: AccountState> Peel<'a, 'b, 'c>
+    for ZeroCopy<'b, T, IS_INITIALIZED>
+{
+    fn peel<I>(ctx: &'c mut Context<'a, 'b, 'c, I>) -> Result<Self> {
+        if ctx.immutable && ctx.info().is_writable {
+            return Err(
+                SolitaireError::InvalidMutability(*ctx.info().key, ctx.info().is_writable),
+            );
+        }
+
+        let initialized = match IS_INITIALIZED {
+            AccountState::Uninitialized => {
+                if !ctx.info().data.borrow().is_empty() {
+                    return Err(SolitaireError::AlreadyInitialized(*ctx.info().key));
+                }
+                false
+            }
+            AccountState::Initialized => true,
+            AccountState::MaybeInitialized => !ctx.info().data.borrow().is_empty(),
+        };
+
+        let account = ZeroCopy(Box::new(ctx.info().clone()), PhantomData);
+        if initialized {
+            check_zero_copy::<T>(&ctx.info().data.borrow())?;
+            match account.owner() {
+                AccountOwner::This => {
+                    if ctx.info().owner != ctx.this {
+                        return Err(SolitaireError::InvalidOwner(*ctx.info().owner));
+                    }
+                }
+                AccountOwner::Other(v) => {
+                    if *ctx.info().owner != v {
+                        return Err(SolitaireError::InvalidOwner(*ctx.info().owner));
+                    }
+                }
+                AccountOwner::OneOf(v) => {
+                    if !v.contains(ctx.info().owner) {
+                        return Err(SolitaireError::InvalidOwner(*ctx.info().owner));
+                    }
+                }
+                AccountOwner::Any => {}
+            };
+        }
+
+        Ok(account)
+    }
+
+    fn persist(&self, _program_id: &Pubkey) -> Result<()> {
+        // Writes through `load_mut` already went to the account.
+        Ok(())
+    }
+
+    fn describe(name: &str) -> Vec<IdlAccount> {
+        let mut account = IdlAccount::new(name);
+        account.data = Some(std::any::type_name::<T>().to_string());
+        account.state = Some(format!("{:?}", IS_INITIALIZED));
+        vec![account]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+    };
+
+    #[derive(BorshDeserialize, BorshSerialize, Default)]
+    struct Counter {
+        count: u64,
+    }
+
+    impl Owned for Counter {
+        fn owner(&self) -> AccountOwner {
+            AccountOwner::This
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct PodCounter {
+        count: u64,
+    }
+
+    unsafe impl bytemuck::Zeroable for PodCounter {
+    }
+    unsafe impl Pod for PodCounter {
+    }
+
+    impl Owned for PodCounter {
+        fn owner(&self) -> AccountOwner {
+            AccountOwner::This
+        }
+    }
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+            TestAccount {
+                key: Pubkey::new_unique(),
+                owner,
+                lamports: 1,
+                data,
+            }
+        }
+
+        fn info(&mut self, is_signer: bool, is_writable: bool) -> AccountInfo {
+            AccountInfo::new(
+                &self.key,
+                is_signer,
+                is_writable,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    #[test]
+    fn test_remaining_peels_every_account() {
+        let program = Pubkey::new_unique();
+        let mut accounts: Vec<TestAccount> =
+            (0..3).map(|_| TestAccount::new(program, vec![])).collect();
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(|a| a.info(true, false)).collect();
+
+        let mut iter = infos.iter();
+        let first: Info = Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        let rest: Remaining<Signer<Info>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+
+        assert_eq!(first.key, infos[0].key);
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[0].key, infos[1].key);
+        assert_eq!(rest[1].key, infos[2].key);
+    }
+
+    #[test]
+    fn test_remaining_validates_every_account() {
+        let program = Pubkey::new_unique();
+        let mut signer = TestAccount::new(program, vec![]);
+        let mut other = TestAccount::new(program, vec![]);
+        let infos = vec![signer.info(true, false), other.info(false, false)];
+
+        let mut iter = infos.iter();
+        let result: Result<Remaining<Signer<Info>>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &()));
+        assert!(matches!(result, Err(SolitaireError::InvalidSigner(key)) if key == *infos[1].key));
+    }
+
+    #[test]
+    fn test_remaining_persists_every_account() {
+        let program = Pubkey::new_unique();
+        let data = Counter { count: 1 }.try_to_vec().unwrap();
+        let mut accounts: Vec<TestAccount> = (0..2)
+            .map(|_| TestAccount::new(program, data.clone()))
+            .collect();
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(|a| a.info(false, true)).collect();
+
+        let mut iter = infos.iter();
+        let mut counters: Remaining<Mut<Data<Counter, { AccountState::Initialized }>>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        for counter in counters.iter_mut() {
+            counter.count += 1;
+        }
+        Peel::persist(&counters, &program).unwrap();
+
+        // Serializing advances the data slices of the infos, read the accounts themselves.
+        for account in &accounts {
+            let counter = Counter::try_from_slice(&account.data).unwrap();
+            assert_eq!(counter.count, 2);
+        }
+    }
+
+    #[test]
+    fn test_remaining_find_by_key() {
+        let program = Pubkey::new_unique();
+        let data = Counter { count: 1 }.try_to_vec().unwrap();
+        let mut other = TestAccount::new(Pubkey::new_unique(), vec![]);
+        let mut counter = TestAccount::new(program, data);
+        let key = counter.key;
+        let infos = vec![other.info(false, false), counter.info(false, true)];
+
+        let mut iter = infos.iter();
+        let rest: Remaining<MaybeMut<Info>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+
+        let found: Option<Mut<Data<Counter, { AccountState::Initialized }>>> =
+            rest.find(&program, &key).unwrap();
+        assert_eq!(found.unwrap().count, 1);
+
+        let missing: Option<Data<Counter, { AccountState::Initialized }>> =
+            rest.find(&program, &Pubkey::new_unique()).unwrap();
+        assert!(missing.is_none());
+
+        // Found accounts are still checked as the requested type.
+        let result: Result<Option<Data<Counter, { AccountState::Initialized }>>> =
+            rest.find(&program, &key);
+        assert!(matches!(result, Err(SolitaireError::InvalidMutability(..))));
+    }
+
+    #[test]
+    fn test_zero_copy_rejects_wrong_owner() {
+        let program = Pubkey::new_unique();
+        let mut account = TestAccount::new(Pubkey::new_unique(), 1u64.to_le_bytes().to_vec());
+        let infos = vec![account.info(false, false)];
+
+        let mut iter = infos.iter();
+        let result: Result<ZeroCopy<PodCounter, { AccountState::Initialized }>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &()));
+        assert!(
+            matches!(result, Err(SolitaireError::InvalidOwner(owner)) if owner == *infos[0].owner)
+        );
+    }
+
+    #[test]
+    fn test_zero_copy_checks_initialization() {
+        let program = Pubkey::new_unique();
+        let mut empty = TestAccount::new(program, vec![]);
+        let mut filled = TestAccount::new(program, 1u64.to_le_bytes().to_vec());
+        let infos = vec![empty.info(false, false), filled.info(false, false)];
+
+        let mut iter = infos.iter();
+        let uninitialized: ZeroCopy<PodCounter, { AccountState::Uninitialized }> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        assert!(!uninitialized.is_initialized());
+        let result: Result<ZeroCopy<PodCounter, { AccountState::Uninitialized }>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &()));
+        assert!(
+            matches!(result, Err(SolitaireError::AlreadyInitialized(key)) if key == *infos[1].key)
+        );
+
+        let mut iter = infos.iter();
+        let result: Result<ZeroCopy<PodCounter, { AccountState::Initialized }>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &()));
+        assert!(result.is_err());
+        let initialized: ZeroCopy<PodCounter, { AccountState::Initialized }> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        assert_eq!(initialized.load().unwrap().count, 1);
+
+        let mut iter = infos.iter();
+        let maybe: ZeroCopy<PodCounter, { AccountState::MaybeInitialized }> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        assert!(!maybe.is_initialized());
+        let maybe: ZeroCopy<PodCounter, { AccountState::MaybeInitialized }> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        assert!(maybe.is_initialized());
+    }
+
+    #[test]
+    fn test_zero_copy_checks_size() {
+        let program = Pubkey::new_unique();
+        let mut short = TestAccount::new(program, vec![1, 0, 0, 0]);
+        let mut long = TestAccount::new(program, vec![2, 0, 0, 0, 0, 0, 0, 0, 0xff]);
+        let infos = vec![short.info(false, false), long.info(false, false)];
+
+        let mut iter = infos.iter();
+        let result: Result<ZeroCopy<PodCounter, { AccountState::Initialized }>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &()));
+        assert!(matches!(
+            result,
+            Err(SolitaireError::ProgramError(
+                ProgramError::AccountDataTooSmall
+            ))
+        ));
+
+        // Trailing bytes are left to the program, only the prefix is mapped.
+        let counter: ZeroCopy<PodCounter, { AccountState::Initialized }> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        assert_eq!(counter.load().unwrap().count, 2);
+    }
+
+    #[test]
+    fn test_zero_copy_load_mut_writes_through() {
+        let program = Pubkey::new_unique();
+        let mut writable = TestAccount::new(program, 1u64.to_le_bytes().to_vec());
+        let mut readonly = TestAccount::new(program, 1u64.to_le_bytes().to_vec());
+        let infos = vec![writable.info(false, true), readonly.info(false, false)];
+
+        let mut iter = infos.iter();
+        let counter: Mut<ZeroCopy<PodCounter, { AccountState::Initialized }>> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        counter.load_mut().unwrap().count += 1;
+        Peel::persist(&counter, &program).unwrap();
+        assert_eq!(&infos[0].data.borrow()[..], &2u64.to_le_bytes()[..]);
+
+        let counter: ZeroCopy<PodCounter, { AccountState::Initialized }> =
+            Peel::peel(&mut Context::new(&program, &mut iter, &())).unwrap();
+        assert!(matches!(
+            counter.load_mut(),
+            Err(SolitaireError::NonWriteableAccount(key)) if key == *infos[1].key
+        ));
+    }
 }
@@ -0,0 +1,189 @@
+use solana_program::{
+    program::invoke,
+    program_error::ProgramError,
+    rent::Rent,
+    system_instruction,
+};
+
+use crate::{
+    processors::{
+        keyed::Keyed,
+        seeded::AccountSize,
+    },
+    trace,
+    ExecutionContext,
+    Info,
+    Result,
+};
+use std::cmp::Ordering;
+
+/// Accounts that can grow or shrink after creation, such as `Data` accounts whose contents change
+/// length. The account stays rent exempt: missing rent is transferred from `payer` and excess rent
+/// is refunded to `recipient`. Pass the account that funded the rent as `recipient` whenever the
+/// payer of this instruction is not necessarily the one who did.
+pub trait Resizable<'a, 'b: 'a> {
+    fn resize(
+        &'a self,
+        ctx: &ExecutionContext,
+        payer: &Info,
+        recipient: &Info,
+        size: usize,
+    ) -> Result<()>;
+
+    /// Resize the account to the size of its current contents, so they can be persisted.
+    fn resize_to_fit(
+        &'a self,
+        ctx: &ExecutionContext,
+        payer: &Info,
+        recipient: &Info,
+    ) -> Result<()>;
+}
+
+impl<'a, 'b: 'a, T: AccountSize + Keyed<'a, 'b>> Resizable<'a, 'b> for T {
+    fn resize(
+        &'a self,
+        ctx: &ExecutionContext,
+        payer: &Info,
+        recipient: &Info,
+        size: usize,
+    ) -> Result<()> {
+        let account = self.info();
+        if account.data_len() == size {
+            return Ok(());
+        }
+
+        let target_rent = Rent::default().minimum_balance(size);
+        match account.lamports().cmp(&target_rent) {
+            Ordering::Less => {
+                let transfer_ix = system_instruction::transfer(
+                    payer.key,
+                    account.key,
+                    target_rent - account.lamports(),
+                );
+                invoke(&transfer_ix, ctx.accounts)?;
+            }
+            Ordering::Greater => {
+                let refund = account.lamports() - target_rent;
+                let lamports = recipient
+                    .lamports()
+                    .checked_add(refund)
+                    .ok_or(ProgramError::InvalidArgument)?;
+                **account.lamports.borrow_mut() -= refund;
+                **recipient.lamports.borrow_mut() = lamports;
+            }
+            Ordering::Equal => {}
+        }
+
+        trace!("Resizing account from {} to {}", account.data_len(), size);
+        account.realloc(size, false)?;
+
+        Ok(())
+    }
+
+    fn resize_to_fit(
+        &'a self,
+        ctx: &ExecutionContext,
+        payer: &Info,
+        recipient: &Info,
+    ) -> Result<()> {
+        self.resize(ctx, payer, recipient, self.size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        processors::seeded::{
+            AccountOwner,
+            Owned,
+        },
+        AccountState,
+        ZeroCopy,
+    };
+    use solana_program::{
+        account_info::AccountInfo,
+        pubkey::Pubkey,
+    };
+    use std::marker::PhantomData;
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Counter {
+        count: u64,
+    }
+
+    unsafe impl bytemuck::Zeroable for Counter {
+    }
+    unsafe impl bytemuck::Pod for Counter {
+    }
+
+    impl Owned for Counter {
+        fn owner(&self) -> AccountOwner {
+            AccountOwner::This
+        }
+    }
+
+    #[test]
+    fn test_shrink_refunds_recipient() {
+        let program = Pubkey::new_unique();
+        let (key, payer_key, recipient_key) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let rent = Rent::default();
+
+        // `realloc` stores the new length in front of the data, as in a program's serialized input.
+        let mut buffer = vec![0u8; 8 + 32];
+        let mut lamports = rent.minimum_balance(32);
+        let (mut payer_lamports, mut recipient_lamports) = (0, 1);
+        let (mut payer_data, mut recipient_data) = ([0u8; 0], [0u8; 0]);
+        let account = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut buffer[8..],
+            &program,
+            false,
+            0,
+        );
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &program,
+            false,
+            0,
+        );
+        let recipient = AccountInfo::new(
+            &recipient_key,
+            false,
+            true,
+            &mut recipient_lamports,
+            &mut recipient_data,
+            &program,
+            false,
+            0,
+        );
+
+        let counter: ZeroCopy<Counter, { AccountState::Initialized }> =
+            ZeroCopy(Box::new(account.clone()), PhantomData);
+        let ctx = ExecutionContext {
+            program_id: &program,
+            accounts: &[],
+        };
+        counter.resize_to_fit(&ctx, &payer, &recipient).unwrap();
+
+        assert_eq!(account.data_len(), 8);
+        assert_eq!(account.lamports(), rent.minimum_balance(8));
+        assert_eq!(
+            recipient.lamports(),
+            1 + rent.minimum_balance(32) - rent.minimum_balance(8)
+        );
+        assert_eq!(payer.lamports(), 0);
+    }
+}
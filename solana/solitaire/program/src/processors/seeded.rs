@@ -9,10 +9,12 @@ use crate::{
     IsSigned::*,
     Result,
     SolitaireError,
+    ZeroCopy,
 };
 use borsh::{
     BorshSerialize,
 };
+use bytemuck::Pod;
 use solana_program::{
     entrypoint::ProgramResult,
     instruction::Instruction,
@@ -27,6 +29,8 @@ pub trait AccountSize {
 pub enum AccountOwner {
     This,
     Other(Pubkey),
+    /// Accounts owned by any one of several programs, such as the token programs.
+    OneOf(Vec<Pubkey>),
     Any,
 }
 
@@ -37,7 +41,7 @@ pub trait Owned {
         match self.owner() {
             AccountOwner::This => Ok(*program_id),
             AccountOwner::Other(v) => Ok(v),
-            AccountOwner::Any => Err(SolitaireError::AmbiguousOwner),
+            AccountOwner::OneOf(_) | AccountOwner::Any => Err(SolitaireError::AmbiguousOwner),
         }
     }
 }
@@ -50,6 +54,15 @@ impl<'a, T: Owned + Default, const IS_INITIALIZED: AccountState> Owned
     }
 }
 
+impl<'a, T: Pod + Owned, const IS_INITIALIZED: AccountState> Owned
+    for ZeroCopy<'a, T, IS_INITIALIZED>
+{
+    fn owner(&self) -> AccountOwner {
+        // The owner is a property of the type, so it can be known before the account exists.
+        T::zeroed().owner()
+    }
+}
+
 pub trait Seeded<I> {
     fn seeds(accs: I) -> Vec<Vec<u8>>;
 
@@ -115,6 +128,14 @@ impl<T: BorshSerialize + Owned + Default, const IS_INITIALIZED: AccountState> Ac
     }
 }
 
+impl<T: Pod + Owned, const IS_INITIALIZED: AccountState> AccountSize
+    for ZeroCopy<'_, T, IS_INITIALIZED>
+{
+    fn size(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+}
+
 impl<'a, 'b: 'a, K, T: AccountSize + Seeded<K> + Keyed<'a, 'b> + Owned> Creatable<'a, K> for T {
     fn create(
         &'a self,
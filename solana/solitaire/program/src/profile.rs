@@ -0,0 +1,142 @@
+//! Compute unit profiling.
+//!
+//! With the `profile` feature every generated instruction logs the compute units it has left when
+//! it starts and after each stage of its execution. This module reads these checkpoints back out
+//! of program logs, so tests can track what each stage costs and catch regressions. Logging a
+//! checkpoint costs compute units itself, so the numbers are only comparable between profiled
+//! builds.
+
+/// Prefix of the log line naming a checkpoint, followed by the instruction and the stage.
+pub const CHECKPOINT: &str = "Profile: ";
+
+/// Logged by `sol_log_compute_units`, followed by the number of units remaining.
+const CONSUMPTION: &str = "Program consumption: ";
+
+/// First checkpoint of an instruction, every later one is named after the stage it ends.
+pub const START: &str = "start";
+
+/// Compute units spent in one stage of an instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageCost {
+    pub instruction: String,
+
+    /// One of `deserialize`, `peel`, `handler` or `persist`.
+    pub stage: String,
+
+    pub units: u64,
+}
+
+/// Collect the cost of every profiled stage from program logs, in the order the stages finished.
+/// Instructions invoked through CPI are reported separately, their cost is also included in the
+/// stage of the caller that invoked them.
+pub fn stage_costs<'a, L: IntoIterator<Item = &'a str>>(logs: L) -> Vec<StageCost> {
+    let mut costs = Vec::new();
+    let mut running: Vec<(String, u64)> = Vec::new();
+    let mut checkpoint: Option<(String, String)> = None;
+
+    for line in logs {
+        if let Some(name) = after(line, CHECKPOINT) {
+            let mut parts = name.trim().splitn(2, ' ');
+            let instruction = parts.next().unwrap_or_default().to_string();
+            let stage = parts.next().unwrap_or_default().to_string();
+            checkpoint = Some((instruction, stage));
+            continue;
+        }
+
+        let remaining = match after(line, CONSUMPTION).and_then(|s| s.split(' ').next()) {
+            Some(units) => units.parse::<u64>().ok(),
+            None => None,
+        };
+        let (instruction, stage, remaining) = match (checkpoint.take(), remaining) {
+            (Some((instruction, stage)), Some(remaining)) => (instruction, stage, remaining),
+            _ => continue,
+        };
+
+        if stage == START {
+            running.push((instruction, remaining));
+            continue;
+        }
+
+        // Instructions invoked through CPI finish before their caller, so the innermost running
+        // instruction of the same name is the one that reached this checkpoint.
+        if let Some(last) = running.iter_mut().rev().find(|(i, _)| *i == instruction) {
+            costs.push(StageCost {
+                instruction,
+                stage,
+                units: last.1.saturating_sub(remaining),
+            });
+            last.1 = remaining;
+        }
+    }
+
+    costs
+}
+
+/// Total cost of every profiled stage of `instruction`, summed over all of its invocations.
+pub fn instruction_cost(costs: &[StageCost], instruction: &str) -> u64 {
+    costs
+        .iter()
+        .filter(|c| c.instruction == instruction)
+        .map(|c| c.units)
+        .sum()
+}
+
+fn after<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    line.find(marker).map(|i| &line[i + marker.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_costs() {
+        let logs = [
+            "Program Bridge1p5gheXUvJ6jGWGeCsgPKgnE3YgdGKRVCMY9o invoke [1]",
+            "Program log: Profile: PostVAA start",
+            "Program consumption: 200000 units remaining",
+            "Program log: Profile: PostVAA deserialize",
+            "Program consumption: 199000 units remaining",
+            "Program log: Profile: PostVAA peel",
+            "Program consumption: 190000 units remaining",
+            "Program log: Something unrelated",
+            "Program log: Profile: PostVAA handler",
+            "Program consumption: 150000 units remaining",
+            "Program log: Profile: PostVAA persist",
+            "Program consumption: 149500 units remaining",
+        ];
+
+        let costs = stage_costs(logs.iter().copied());
+        let stages: Vec<(&str, u64)> = costs.iter().map(|c| (c.stage.as_str(), c.units)).collect();
+        assert_eq!(
+            stages,
+            vec![
+                ("deserialize", 1000),
+                ("peel", 9000),
+                ("handler", 40000),
+                ("persist", 500),
+            ]
+        );
+        assert_eq!(instruction_cost(&costs, "PostVAA"), 50500);
+    }
+
+    #[test]
+    fn test_stage_costs_through_cpi() {
+        let logs = [
+            "[DEBUG] Program log: Profile: TransferNative start",
+            "[DEBUG] Program consumption: 200000 units remaining",
+            "[DEBUG] Program log: Profile: TransferNative peel",
+            "[DEBUG] Program consumption: 180000 units remaining",
+            "[DEBUG] Program log: Profile: PostMessage start",
+            "[DEBUG] Program consumption: 170000 units remaining",
+            "[DEBUG] Program log: Profile: PostMessage handler",
+            "[DEBUG] Program consumption: 160000 units remaining",
+            "[DEBUG] Program log: Profile: TransferNative handler",
+            "[DEBUG] Program consumption: 150000 units remaining",
+        ];
+
+        let costs = stage_costs(logs.iter().copied());
+        assert_eq!(instruction_cost(&costs, "PostMessage"), 10000);
+        assert_eq!(instruction_cost(&costs, "TransferNative"), 50000);
+    }
+}
@@ -5,19 +5,29 @@
 //! types that describe different kinds of accounts to target.
 
 use borsh::BorshSerialize;
+use bytemuck::Pod;
 use solana_program::{
     account_info::AccountInfo,
     program::{
         invoke,
         invoke_signed,
     },
+    program_error::ProgramError,
     pubkey::Pubkey,
     system_instruction,
     sysvar::Sysvar as SolanaSysvar,
 };
-use std::ops::{
-    Deref,
-    DerefMut,
+use std::{
+    cell::{
+        Ref,
+        RefMut,
+    },
+    marker::PhantomData,
+    mem::size_of,
+    ops::{
+        Deref,
+        DerefMut,
+    },
 };
 
 use crate::{
@@ -27,6 +37,7 @@ use crate::{
     ExecutionContext,
     Keyed,
     Result,
+    SolitaireError,
 };
 
 /// A short alias for AccountInfo.
@@ -112,6 +123,55 @@ impl<'r, T: Owned + Default> Data<'r, T, { AccountState::MaybeInitialized }> {
     }
 }
 
+/// An account holding a `#[repr(C)]` plain old data `T` that is accessed in place.
+///
+/// Unlike `Data` nothing is deserialized when the account is peeled or serialized when it is
+/// persisted, `load` and `load_mut` map the account bytes directly. Peeling performs the same
+/// initialization and owner checks as `Data`.
+#[rustfmt::skip]
+pub struct ZeroCopy<'r, T: Pod + Owned, const IS_INITIALIZED: AccountState> (
+    pub Box<Info<'r>>,
+    pub PhantomData<T>,
+);
+
+impl<'r, T: Pod + Owned, const IS_INITIALIZED: AccountState> ZeroCopy<'r, T, IS_INITIALIZED> {
+    /// Borrow the account data.
+    pub fn load(&self) -> Result<Ref<T>> {
+        let data = self.0.try_borrow_data()?;
+        check_zero_copy::<T>(&data)?;
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[..size_of::<T>()])
+        }))
+    }
+
+    /// Borrow the account data mutably, writes go straight to the account.
+    pub fn load_mut(&self) -> Result<RefMut<T>> {
+        if !self.0.is_writable {
+            return Err(SolitaireError::NonWriteableAccount(*self.0.key));
+        }
+        let data = self.0.try_borrow_mut_data()?;
+        check_zero_copy::<T>(&data)?;
+        Ok(RefMut::map(data, |data| {
+            bytemuck::from_bytes_mut(&mut data[..size_of::<T>()])
+        }))
+    }
+
+    /// Is the account already initialized / created
+    pub fn is_initialized(&self) -> bool {
+        !self.0.data.borrow().is_empty()
+    }
+}
+
+/// Check that account data can be viewed as a `T`.
+pub(crate) fn check_zero_copy<T: Pod>(data: &[u8]) -> Result<()> {
+    if data.len() < size_of::<T>() {
+        return Err(ProgramError::AccountDataTooSmall.into());
+    }
+    bytemuck::try_from_bytes::<T>(&data[..size_of::<T>()])
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    Ok(())
+}
+
 pub struct Sysvar<'b, Var: SolanaSysvar>(pub AccountInfo<'b>, pub Var);
 
 impl<'b, Var: SolanaSysvar> Deref for Sysvar<'b, Var> {
@@ -143,8 +203,8 @@ impl<const SEED: &'static str> Derive<AccountInfo<'_>, SEED> {
     }
 }
 
-impl<const SEED: &'static str, T: BorshSerialize + Owned + Default>
-    Derive<Data<'_, T, { AccountState::Uninitialized }>, SEED>
+impl<const SEED: &'static str, T: BorshSerialize + Owned + Default, const STATE: AccountState>
+    Derive<Data<'_, T, { STATE }>, SEED>
 {
     pub fn create(
         &self,
@@ -27,6 +27,11 @@ pub struct System<Next>(pub Next);
 #[repr(transparent)]
 pub struct Derive<Next, const SEED: &'static str>(pub Next);
 
+/// Every account left after the preceding fields, each peeled as `Next`. Must be the last field of
+/// an accounts struct.
+#[repr(transparent)]
+pub struct Remaining<Next>(pub Vec<Next>);
+
 // Several traits are required for types defined here, they cannot be defined in another file due
 // to orphan instance limitations.
 
@@ -94,3 +99,16 @@ impl<T, const SEED: &'static str> DerefMut for Derive<T, SEED> {
         unsafe { std::mem::transmute(&mut self.0) }
     }
 }
+
+impl<T> Deref for Remaining<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Remaining<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
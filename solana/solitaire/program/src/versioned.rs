@@ -0,0 +1,281 @@
+//! Versioned instruction data.
+//!
+//! Instruction data is a discriminant followed by the Borsh encoded payload. To let a payload
+//! change layout without breaking existing clients, the discriminant can carry `VERSIONED`, in
+//! which case a version byte follows it. Payloads sent without the flag are version 0, the layout
+//! the instruction had before it was versioned.
+//!
+//! Instructions opt in by marking their row in `solitaire!` as `(versioned)`, their data type then
+//! implements `Versioned` to migrate payloads of older versions into the current layout.
+
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+
+use crate::{
+    Result,
+    SolitaireError,
+};
+
+/// Set on the discriminant when a version byte follows it.
+pub const VERSIONED: u8 = 0x80;
+
+/// Instruction data that has had several layouts.
+pub trait Versioned: BorshDeserialize {
+    /// Version of the current layout, sent by up to date clients.
+    const VERSION: u8;
+
+    /// Decode a payload of an older `version` and migrate it to the current layout.
+    fn migrate(version: u8, data: &[u8]) -> Result<Self>;
+}
+
+/// Split instruction data into its discriminant, payload version and payload.
+pub fn split(data: &[u8]) -> Result<(u8, u8, &[u8])> {
+    match data {
+        [discriminant, version, payload @ ..] if discriminant & VERSIONED != 0 => {
+            Ok((discriminant & !VERSIONED, *version, payload))
+        }
+        [discriminant, payload @ ..] if discriminant & VERSIONED == 0 => {
+            Ok((*discriminant, 0, payload))
+        }
+        _ => Err(SolitaireError::InstructionDeserializeFailed(
+            std::io::ErrorKind::UnexpectedEof.into(),
+        )),
+    }
+}
+
+/// Decode the payload of an instruction that has not been versioned.
+pub fn decode_unversioned<T: BorshDeserialize>(version: u8, data: &[u8]) -> Result<T> {
+    match version {
+        0 => T::try_from_slice(data).map_err(SolitaireError::InstructionDeserializeFailed),
+        _ => Err(SolitaireError::UnsupportedVersion(version)),
+    }
+}
+
+/// Decode the payload of a versioned instruction, migrating older versions.
+pub fn decode<T: Versioned>(version: u8, data: &[u8]) -> Result<T> {
+    match version {
+        v if v == T::VERSION => {
+            T::try_from_slice(data).map_err(SolitaireError::InstructionDeserializeFailed)
+        }
+        v if v < T::VERSION => T::migrate(v, data),
+        _ => Err(SolitaireError::UnsupportedVersion(version)),
+    }
+}
+
+/// Encode the data of a versioned instruction. `instruction` is the variant of the program's
+/// generated `Instruction` enum and `data` its payload.
+pub fn encode<I: BorshSerialize, D: Versioned + BorshSerialize>(
+    instruction: I,
+    data: D,
+) -> std::io::Result<Vec<u8>> {
+    let mut encoded = instruction.try_to_vec()?;
+    encoded[0] |= VERSIONED;
+    encoded.push(D::VERSION);
+    data.serialize(&mut encoded)?;
+    Ok(encoded)
+}
+
+/// Decodes or describes instruction data depending on whether its row in `solitaire!` is marked
+/// `(versioned)`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! instruction_data {
+    (decode, $version:expr, $data:expr) => {
+        solitaire::versioned::decode_unversioned($version, $data)
+    };
+    (decode, $version:expr, $data:expr, versioned) => {
+        solitaire::versioned::decode($version, $data)
+    };
+    (describe, $row:ident, $fn:ident) => {
+        solitaire::idl::instruction(stringify!($row), Instruction::$row as u8, $fn)
+    };
+    (describe, $row:ident, $fn:ident, versioned) => {
+        solitaire::idl::versioned_instruction(stringify!($row), Instruction::$row as u8, $fn)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    struct TransferV0 {
+        amount: u64,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+    struct Transfer {
+        amount: u64,
+        fee: u64,
+    }
+
+    impl Versioned for Transfer {
+        const VERSION: u8 = 1;
+
+        fn migrate(version: u8, data: &[u8]) -> Result<Self> {
+            match version {
+                0 => {
+                    let old = TransferV0::try_from_slice(data)
+                        .map_err(SolitaireError::InstructionDeserializeFailed)?;
+                    Ok(Transfer {
+                        amount: old.amount,
+                        fee: 0,
+                    })
+                }
+                _ => Err(SolitaireError::UnsupportedVersion(version)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unversioned_payload_is_version_zero() {
+        let data = (3u8, TransferV0 { amount: 10 }).try_to_vec().unwrap();
+        let (discriminant, version, payload) = split(&data).unwrap();
+        assert_eq!((discriminant, version), (3, 0));
+
+        let transfer: Transfer = decode(version, payload).unwrap();
+        assert_eq!(transfer, Transfer { amount: 10, fee: 0 });
+    }
+
+    #[test]
+    fn test_versioned_payload_roundtrip() {
+        let data = encode(3u8, Transfer { amount: 10, fee: 1 }).unwrap();
+        let (discriminant, version, payload) = split(&data).unwrap();
+        assert_eq!((discriminant, version), (3, 1));
+
+        let transfer: Transfer = decode(version, payload).unwrap();
+        assert_eq!(transfer, Transfer { amount: 10, fee: 1 });
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let data = [3 | VERSIONED, 2];
+        let (_, version, payload) = split(&data).unwrap();
+        assert!(matches!(
+            decode::<Transfer>(version, payload),
+            Err(SolitaireError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            decode_unversioned::<TransferV0>(version, payload),
+            Err(SolitaireError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn test_missing_version_byte() {
+        assert!(matches!(
+            split(&[3 | VERSIONED]),
+            Err(SolitaireError::InstructionDeserializeFailed(_))
+        ));
+    }
+
+    /// A program mixing a versioned row with an unversioned one, both taking the same data. The
+    /// generated entrypoint goes unused without a program to register it.
+    #[allow(dead_code, unused_imports)]
+    mod program {
+        use super::*;
+        use crate as solitaire;
+        use crate::{
+            ExecutionContext,
+            FromAccounts,
+            Info,
+            Peel,
+        };
+        use std::cell::Cell;
+
+        thread_local! {
+            pub(super) static DECODED: Cell<Option<(u64, u64)>> = Cell::new(None);
+        }
+
+        #[derive(FromAccounts)]
+        struct Payer<'b> {
+            payer: Info<'b>,
+        }
+
+        fn plain(_ctx: &ExecutionContext, _accs: &mut Payer, data: TransferV0) -> Result<()> {
+            DECODED.with(|decoded| decoded.set(Some((data.amount, 0))));
+            Ok(())
+        }
+
+        fn transfer(_ctx: &ExecutionContext, _accs: &mut Payer, data: Transfer) -> Result<()> {
+            DECODED.with(|decoded| decoded.set(Some((data.amount, data.fee))));
+            Ok(())
+        }
+
+        crate::solitaire! {
+            Plain => plain,
+            Transfer => transfer (versioned),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_mixes_versioned_rows() {
+        use program::instruction::{
+            dispatch,
+            idl,
+            Instruction,
+        };
+        use solana_program::{
+            account_info::AccountInfo,
+            pubkey::Pubkey,
+        };
+
+        let program_id = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = [0u8; 0];
+        let accounts = [AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        )];
+        let run = |data: &[u8]| {
+            program::DECODED.with(|decoded| decoded.set(None));
+            dispatch(&program_id, &accounts, data)
+                .map(|_| program::DECODED.with(|decoded| decoded.get()).unwrap())
+        };
+
+        // Unversioned rows decode as before.
+        let data = (Instruction::Plain, TransferV0 { amount: 10 })
+            .try_to_vec()
+            .unwrap();
+        assert_eq!(run(&data).unwrap(), (10, 0));
+
+        // Versioned rows accept both old clients and the current layout.
+        let data = (Instruction::Transfer, TransferV0 { amount: 10 })
+            .try_to_vec()
+            .unwrap();
+        assert_eq!(run(&data).unwrap(), (10, 0));
+        let data = encode(Instruction::Transfer, Transfer { amount: 10, fee: 1 }).unwrap();
+        assert_eq!(run(&data).unwrap(), (10, 1));
+
+        // Unversioned rows only take version 0, even when it is sent explicitly.
+        let mut data = (Instruction::Plain, TransferV0 { amount: 10 })
+            .try_to_vec()
+            .unwrap();
+        data[0] |= VERSIONED;
+        data.insert(1, 0);
+        assert_eq!(run(&data).unwrap(), (10, 0));
+        data[1] = 1;
+        assert!(matches!(
+            run(&data),
+            Err(SolitaireError::UnsupportedVersion(1))
+        ));
+
+        assert!(matches!(
+            run(&[2]),
+            Err(SolitaireError::UnknownInstruction(2))
+        ));
+
+        let idl = idl();
+        assert_eq!(idl.instruction("Plain").unwrap().version, 0);
+        assert_eq!(idl.instruction("Transfer").unwrap().version, 1);
+    }
+}
@@ -1,19 +1,53 @@
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{
+    quote,
+    ToTokens,
+};
 use syn::{
+    parse::{
+        ParseStream,
+        Parser,
+    },
     parse_macro_input,
     parse_quote,
+    punctuated::Punctuated,
     Data,
     DeriveInput,
+    Expr,
+    ExprLit,
     Fields,
+    GenericArgument,
     GenericParam,
     Generics,
+    Ident,
+    Lit,
+    Meta,
+    PathArguments,
+    Token,
+    Type,
 };
 
 /// Generate a FromAccounts implementation for a product of accounts. Each field is constructed by
 /// a call to the Verify::verify instance of its type.
-#[proc_macro_derive(FromAccounts)]
+///
+/// Fields can carry constraints that are checked once every account has been peeled, before the
+/// handler runs. Expressions can refer to the peeled accounts as `accs` and to `program_id`.
+///
+/// - `#[seeds]` verifies the derivation of the account from `accs.into()`, `#[seeds(expr)]` from
+///   the given derivation data.
+/// - `#[owner = expr]` requires the account to be owned by the given program.
+/// - `#[has_one(field, ...)]` requires the account data's `field` to be the key of the account
+///   named `field`.
+/// - `#[constraint(expr)]` requires `expr` to hold, `#[constraint(expr, error)]` raises `error`
+///   instead of `SolitaireError::ConstraintViolated`.
+///
+/// With the `client` feature, `Name::builder` returns a `<Name>Builder` with a setter per field for
+/// building the instruction client side, see `solitaire::client`.
+///
+/// Marking the struct `#[cpi]` generates a `<Name>Cpi` struct alongside, for invoking the
+/// instruction from other programs, see `solitaire::cpi`.
+#[proc_macro_derive(FromAccounts, attributes(seeds, owner, has_one, constraint, cpi))]
 pub fn derive_from_accounts(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
@@ -50,6 +84,12 @@ pub fn derive_from_accounts(input: TokenStream) -> TokenStream {
 
     let from_method = generate_fields(&name, &input.data);
     let persist_method = generate_persist(&input.data);
+    let describe_method = generate_describe(&input.data);
+    let builder = generate_builder(&name, &input.vis, &input.data, &type_generics);
+    let cpi = match input.attrs.iter().any(|attr| attr.path.is_ident("cpi")) {
+        true => generate_cpi(&name, &input.vis, &input.data),
+        false => quote!(),
+    };
     let expanded = quote! {
         /// Macro generated implementation of FromAccounts by Solitaire.
         impl #combined_impl_g solitaire::FromAccounts #peel_type_g for #name #type_g {
@@ -67,14 +107,22 @@ pub fn derive_from_accounts(input: TokenStream) -> TokenStream {
             fn persist(&self, program_id: &solana_program::pubkey::Pubkey) -> solitaire::Result<()> {
                 solitaire::Persist::persist(self, program_id)
             }
+
+            fn describe(name: &str) -> Vec<solitaire::idl::IdlAccount> {
+                #describe_method
+            }
         }
 
+        #builder
+
         /// Macro generated implementation of Persist by Solitaire.
         impl #type_impl_g solitaire::Persist for #name #type_g {
             fn persist(&self, program_id: &solana_program::pubkey::Pubkey) -> solitaire::Result<()> {
                 #persist_method
             }
         }
+
+        #cpi
     };
 
     // Hand the output tokens back to the compiler
@@ -113,13 +161,20 @@ fn generate_fields(name: &syn::Ident, data: &Data) -> TokenStream2 {
                         quote!(#name)
                     });
 
+                    let constraints = match generate_constraints(fields) {
+                        Ok(constraints) => constraints,
+                        Err(e) => e.to_compile_error(),
+                    };
+
                     // Write out our iterator and return the filled structure.
                     quote! {
                         use solana_program::account_info::next_account_info;
                         use solitaire::trace;
                         trace!("Peeling:");
                         #(#recurse;)*
-                        Ok(#name { #(#names,)* })
+                        let accs = #name { #(#names,)* };
+                        #constraints
+                        Ok(accs)
                     }
                 }
 
@@ -137,6 +192,85 @@ fn generate_fields(name: &syn::Ident, data: &Data) -> TokenStream2 {
     }
 }
 
+/// Generate the checks for the constraint attributes of each field.
+fn generate_constraints(fields: &syn::FieldsNamed) -> syn::Result<TokenStream2> {
+    let mut checks = Vec::new();
+    for f in &fields.named {
+        let name = &f.ident;
+        for attr in &f.attrs {
+            if attr.path.is_ident("seeds") {
+                let derivation = match attr.tokens.is_empty() {
+                    true => quote!(&accs.into()),
+                    false => attr.parse_args::<Expr>()?.into_token_stream(),
+                };
+                checks.push(quote! {
+                    trace!(concat!("Verifying derivation of ", stringify!(#name)));
+                    accs.#name.verify_derivation(program_id, #derivation)?;
+                });
+            } else if attr.path.is_ident("owner") {
+                let owner = (|input: ParseStream| {
+                    input.parse::<Token![=]>()?;
+                    input.parse::<Expr>()
+                })
+                .parse2(attr.tokens.clone())?;
+                checks.push(quote! {
+                    if *accs.#name.info().owner != #owner {
+                        return Err(solitaire::SolitaireError::InvalidOwner(*accs.#name.info().owner));
+                    }
+                });
+            } else if attr.path.is_ident("has_one") {
+                let targets =
+                    attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+                for target in targets {
+                    checks.push(quote! {
+                        if accs.#name.#target != *accs.#target.info().key {
+                            return Err(solitaire::SolitaireError::ConstraintViolated(*accs.#name.info().key));
+                        }
+                    });
+                }
+            } else if attr.path.is_ident("constraint") {
+                let args = attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+                let mut args = args.into_iter();
+                let (condition, error) = match (args.next(), args.next(), args.next()) {
+                    (Some(condition), None, None) => (
+                        condition,
+                        quote!(solitaire::SolitaireError::ConstraintViolated(*accs.#name.info().key)),
+                    ),
+                    (Some(condition), Some(error), None) => (condition, quote!(#error.into())),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "expected #[constraint(condition)] or #[constraint(condition, error)]",
+                        ))
+                    }
+                };
+                checks.push(quote! {
+                    if !(#condition) {
+                        return Err(#error);
+                    }
+                });
+            }
+        }
+    }
+
+    if checks.is_empty() {
+        return Ok(quote!());
+    }
+
+    Ok(quote! {
+        {
+            #[allow(unused_imports)]
+            use solitaire::{
+                processors::seeded::Seeded as _,
+                Keyed as _,
+            };
+            let accs = &accs;
+            let program_id = pid;
+            #(#checks)*
+        }
+    })
+}
+
 /// This function does the heavy lifting of generating the field parsers.
 fn generate_persist(data: &Data) -> TokenStream2 {
     match *data {
@@ -181,3 +315,495 @@ fn generate_persist(data: &Data) -> TokenStream2 {
         Data::Enum(_) | Data::Union(_) => unimplemented!(),
     }
 }
+
+/// Generate the IDL description of the accounts, in the order they are peeled.
+fn generate_describe(data: &Data) -> TokenStream2 {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let recurse = fields.named.iter().map(|f| {
+                    let name = &f.ident;
+                    let ty = &f.ty;
+
+                    quote! {
+                        accounts.extend(<#ty as solitaire::Peel<'a, 'b, 'c>>::describe(
+                            &solitaire::idl::nested(name, stringify!(#name)),
+                        ));
+                    }
+                });
+
+                quote! {
+                    let mut accounts = Vec::new();
+                    #(#recurse)*
+                    accounts
+                }
+            }
+
+            Fields::Unnamed(_) => {
+                unimplemented!()
+            }
+
+            Fields::Unit => {
+                unimplemented!()
+            }
+        },
+
+        Data::Enum(_) | Data::Union(_) => unimplemented!(),
+    }
+}
+
+/// Generate the client side `<Name>Builder`, with a setter per field so that account names are
+/// checked by the compiler. Nested accounts structs are set from their own builder.
+fn generate_builder(
+    name: &Ident,
+    vis: &syn::Visibility,
+    data: &Data,
+    generics: &Generics,
+) -> TokenStream2 {
+    let fields = match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => fields,
+            _ => unimplemented!(),
+        },
+        Data::Enum(_) | Data::Union(_) => unimplemented!(),
+    };
+
+    let (impl_g, type_g, _) = generics.split_for_impl();
+    let builder_name = Ident::new(&format!("{}Builder", name), name.span());
+    let doc = format!(
+        "Macro generated client side builder for instructions taking the accounts of `{}`.",
+        name
+    );
+
+    let setters = fields.named.iter().map(|f| {
+        let field = f.ident.as_ref().unwrap();
+        let ty = &f.ty;
+
+        if innermost_ident(ty) == "Remaining" {
+            return quote! {
+                pub fn #field(mut self, accounts: Vec<solana_program::instruction::AccountMeta>) -> Self {
+                    self.inner = self.inner.remaining(accounts);
+                    self
+                }
+            };
+        }
+
+        let seeded = Ident::new(&format!("{}_seeded", field), field.span());
+        let account = strip_layers(ty);
+        let writable = match account_flags(ty).0 {
+            None => {
+                let method = Ident::new(&format!("{}_writable", field), field.span());
+                quote! {
+                    pub fn #method(mut self) -> Self {
+                        self.inner = self.inner.writable(stringify!(#field));
+                        self
+                    }
+                }
+            }
+            Some(_) => quote!(),
+        };
+
+        quote! {
+            pub fn #field(mut self, key: impl solitaire::client::AccountKeys) -> Self {
+                self.inner = key.apply(stringify!(#field), self.inner);
+                self
+            }
+
+            pub fn #seeded<I>(mut self, accs: I) -> Self
+            where
+                #account: solitaire::processors::seeded::Seeded<I>,
+            {
+                self.inner = self.inner.seeded::<#account, I>(stringify!(#field), accs);
+                self
+            }
+
+            #writable
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        #[cfg(feature = "client")]
+        #vis struct #builder_name #impl_g {
+            inner: solitaire::client::InstructionBuilder,
+            accounts: std::marker::PhantomData<fn() -> #name #type_g>,
+        }
+
+        #[cfg(feature = "client")]
+        impl #impl_g #name #type_g {
+            pub fn builder(program_id: solana_program::pubkey::Pubkey) -> #builder_name #type_g {
+                #builder_name {
+                    inner: solitaire::client::InstructionBuilder::new::<Self>(program_id),
+                    accounts: std::marker::PhantomData,
+                }
+            }
+        }
+
+        #[cfg(feature = "client")]
+        impl #impl_g #builder_name #type_g {
+            #(#setters)*
+
+            /// Append the rent sysvar after the accounts of the struct.
+            pub fn with_rent(mut self) -> Self {
+                self.inner = self.inner.with_rent();
+                self
+            }
+
+            /// Append the system program after the accounts of the struct.
+            pub fn with_system_program(mut self) -> Self {
+                self.inner = self.inner.with_system_program();
+                self
+            }
+
+            /// Append accounts that are not part of the struct, such as programs needed for CPI.
+            pub fn with_remaining(mut self, accounts: Vec<solana_program::instruction::AccountMeta>) -> Self {
+                self.inner = self.inner.remaining(accounts);
+                self
+            }
+
+            pub fn build<I: solitaire::BorshSerialize, D: solitaire::BorshSerialize>(
+                self,
+                instruction: I,
+                data: D,
+            ) -> std::result::Result<solana_program::instruction::Instruction, solitaire::ErrBox> {
+                self.inner.build(instruction, data)
+            }
+
+            pub fn build_versioned<I: solitaire::BorshSerialize, D: solitaire::Versioned + solitaire::BorshSerialize>(
+                self,
+                instruction: I,
+                data: D,
+            ) -> std::result::Result<solana_program::instruction::Instruction, solitaire::ErrBox> {
+                self.inner.build_versioned(instruction, data)
+            }
+        }
+
+        #[cfg(feature = "client")]
+        impl #impl_g solitaire::client::AccountKeys for #builder_name #type_g {
+            fn apply(
+                self,
+                name: &str,
+                builder: solitaire::client::InstructionBuilder,
+            ) -> solitaire::client::InstructionBuilder {
+                builder.nest(name, self.inner)
+            }
+        }
+    }
+}
+
+/// The account type wrapped by `Mut`, `MaybeMut` and `Signer`, which is the type carrying its
+/// `Seeded` implementation.
+fn strip_layers(ty: &Type) -> &Type {
+    let mut ty = ty;
+    while let Type::Path(path) = ty {
+        let segment = match path.path.segments.last() {
+            Some(segment) => segment,
+            None => break,
+        };
+        if !matches!(
+            segment.ident.to_string().as_str(),
+            "Mut" | "MaybeMut" | "Signer"
+        ) {
+            break;
+        }
+        ty = match &segment.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(GenericArgument::Type(inner)) => inner,
+                _ => break,
+            },
+            _ => break,
+        };
+    }
+    ty
+}
+
+/// Name of the outermost type after stripping layers, such as `Remaining`.
+fn innermost_ident(ty: &Type) -> String {
+    match strip_layers(ty) {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+/// Generate the `<Name>Cpi` struct forwarding the accounts of `name` to another program. A
+/// `Remaining` field forwards every account left, as a `Vec`.
+fn generate_cpi(name: &Ident, vis: &syn::Visibility, data: &Data) -> TokenStream2 {
+    let fields = match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => fields,
+            _ => unimplemented!(),
+        },
+        Data::Enum(_) | Data::Union(_) => unimplemented!(),
+    };
+
+    let cpi_name = Ident::new(&format!("{}Cpi", name), name.span());
+    let doc = format!(
+        "Macro generated accounts of `{}` for invoking it from another program.",
+        name
+    );
+
+    let mut decls = Vec::new();
+    let mut metas = Vec::new();
+    let mut peels = Vec::new();
+    let mut describes = Vec::new();
+    for f in fields.named.iter() {
+        let field = &f.ident;
+        let (writable, signer) = account_flags(&f.ty);
+        let writable = match writable {
+            Some(true) => quote!(Some(true)),
+            Some(false) => quote!(Some(false)),
+            None => quote!(None),
+        };
+
+        if innermost_ident(&f.ty) == "Remaining" {
+            decls.push(quote!(pub #field: Vec<solana_program::account_info::AccountInfo<'b>>));
+            metas.push(quote! {
+                metas.extend(self.#field.iter().map(|info| solitaire::cpi::account_meta(info, #writable, #signer)));
+            });
+            peels.push(quote! {
+                let #field = solitaire::cpi::remaining_accounts(iter, #writable)?;
+            });
+            describes.push(quote! {
+                solitaire::idl::IdlAccount {
+                    remaining: true,
+                    ..solitaire::cpi::describe(&solitaire::idl::nested(name, stringify!(#field)), #writable)
+                }
+            });
+        } else {
+            decls.push(quote!(pub #field: solana_program::account_info::AccountInfo<'b>));
+            metas.push(quote! {
+                metas.push(solitaire::cpi::account_meta(&self.#field, #writable, #signer));
+            });
+            peels.push(quote! {
+                let #field = solitaire::cpi::next_account(iter, #writable)?;
+            });
+            describes.push(quote! {
+                solitaire::cpi::describe(&solitaire::idl::nested(name, stringify!(#field)), #writable)
+            });
+        }
+    }
+    let names: Vec<&Option<Ident>> = fields.named.iter().map(|f| &f.ident).collect();
+
+    quote! {
+        #[doc = #doc]
+        #vis struct #cpi_name<'b> {
+            #(#decls,)*
+        }
+
+        impl<'b> solitaire::cpi::CpiAccounts for #cpi_name<'b> {
+            fn to_account_metas(&self) -> Vec<solana_program::instruction::AccountMeta> {
+                let mut metas = Vec::new();
+                #(#metas)*
+                metas
+            }
+        }
+
+        impl<'a, 'b: 'a, 'c> solitaire::FromAccounts<'a, 'b, 'c> for #cpi_name<'b> {
+            fn from<DataType>(_: &'a solana_program::pubkey::Pubkey, iter: &'c mut std::slice::Iter<'a, solana_program::account_info::AccountInfo<'b>>, _: &'a DataType) -> solitaire::Result<Self> {
+                #(#peels)*
+                Ok(#cpi_name { #(#names,)* })
+            }
+        }
+
+        impl<'a, 'b: 'a, 'c> solitaire::Peel<'a, 'b, 'c> for #cpi_name<'b> {
+            fn peel<I>(ctx: &'c mut solitaire::Context<'a, 'b, 'c, I>) -> solitaire::Result<Self> where Self: Sized {
+                solitaire::FromAccounts::from(ctx.this, ctx.iter, ctx.data)
+            }
+
+            fn persist(&self, _: &solana_program::pubkey::Pubkey) -> solitaire::Result<()> {
+                Ok(())
+            }
+
+            fn describe(name: &str) -> Vec<solitaire::idl::IdlAccount> {
+                vec![#(#describes,)*]
+            }
+        }
+    }
+}
+
+/// Mutability and signer flags of an account, read from the layers wrapping it. Mutability is
+/// `None` for `MaybeMut` accounts. Flags of a `Remaining` field are those of each of its accounts.
+fn account_flags(ty: &Type) -> (Option<bool>, bool) {
+    let mut writable = Some(false);
+    let mut signer = false;
+    let mut ty = ty;
+    while let Type::Path(path) = ty {
+        let segment = match path.path.segments.last() {
+            Some(segment) => segment,
+            None => break,
+        };
+        match segment.ident.to_string().as_str() {
+            "Mut" => writable = Some(true),
+            "MaybeMut" if writable == Some(false) => writable = None,
+            "Signer" => signer = true,
+            "MaybeMut" | "Option" | "System" | "Derive" | "Remaining" => {}
+            _ => break,
+        }
+        ty = match &segment.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(GenericArgument::Type(inner)) => inner,
+                _ => break,
+            },
+            _ => break,
+        };
+    }
+    (writable, signer)
+}
+
+/// Generate stable error codes for a fieldless program error enum. Each variant is assigned its
+/// explicit discriminant, or the one following the previous variant, and its doc comment becomes
+/// the message shown by `Display` and client side decoders. Variants are converted into
+/// `SolitaireError::Custom` so they surface on chain as `ProgramError::Custom(code)`.
+/// Reusing a code within the enum is a compile error.
+#[proc_macro_derive(SolitaireErrors)]
+pub fn derive_solitaire_errors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let data = match input.data {
+        Data::Enum(ref data) => data,
+        _ => {
+            return syn::Error::new_spanned(&input, "SolitaireErrors can only be derived for enums")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut next_code: u32 = 0;
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return syn::Error::new_spanned(
+                variant,
+                "SolitaireErrors variants can not have fields",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        let code = match &variant.discriminant {
+            Some((
+                _,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                }),
+            )) => match lit.base10_parse::<u32>() {
+                Ok(code) => code,
+                Err(e) => return e.to_compile_error().into(),
+            },
+            Some((_, expr)) => {
+                return syn::Error::new_spanned(expr, "error codes must be integer literals")
+                    .to_compile_error()
+                    .into()
+            }
+            None => next_code,
+        };
+        next_code = code + 1;
+
+        // Codes are part of the program's interface, two variants sharing one would be
+        // indistinguishable to clients.
+        if let Some((other, _, _)) = variants.iter().find(|(_, other, _)| *other == code) {
+            return syn::Error::new_spanned(
+                variant,
+                format!("error code {} is already used by {}", code, other),
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        let ident = &variant.ident;
+        let message = doc_comment(&variant.attrs).unwrap_or_else(|| ident.to_string());
+        variants.push((ident, code, message));
+    }
+
+    let table = variants.iter().map(|(ident, code, message)| {
+        let variant_name = ident.to_string();
+        quote! {
+            solitaire::ErrorCode {
+                code: #code,
+                name: #variant_name,
+                message: #message,
+            }
+        }
+    });
+    let to_code = variants
+        .iter()
+        .map(|(ident, code, _)| quote!(#name::#ident => #code));
+    let from_code = variants
+        .iter()
+        .map(|(ident, code, _)| quote!(#code => Some(#name::#ident)));
+    let messages = variants
+        .iter()
+        .map(|(ident, _, message)| quote!(#name::#ident => #message));
+    let max_code = variants.iter().map(|(_, code, _)| *code).max().unwrap_or(0);
+
+    let expanded = quote! {
+        // Program codes share the `ProgramError::Custom` space with Solitaire's own errors and must
+        // stay below them.
+        const _: [(); 0] = [(); (#max_code >= solitaire::error::SOLITAIRE_ERROR_BASE) as usize];
+
+        impl #name {
+            /// Decoding table for the error codes of this program, see `solitaire::decode_error`.
+            pub const ERROR_CODES: &'static [solitaire::ErrorCode] = &[#(#table,)*];
+
+            /// The `ProgramError::Custom` code this error is reported as.
+            pub fn code(&self) -> u32 {
+                match self {
+                    #(#to_code,)*
+                }
+            }
+
+            /// Map a `ProgramError::Custom` code back to the error, if it belongs to this program.
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    #(#from_code,)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let message = match self {
+                    #(#messages,)*
+                };
+                f.write_str(message)
+            }
+        }
+
+        impl From<#name> for solitaire::SolitaireError {
+            fn from(e: #name) -> solitaire::SolitaireError {
+                solitaire::trace!("ProgramError: {}", e);
+                solitaire::SolitaireError::Custom(e.code())
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Join the lines of an item's doc comment, if it has one.
+fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(meta)) => match meta.lit {
+                Lit::Str(doc) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}